@@ -0,0 +1,201 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use bug_bounty_platform::cpi::accounts::{AssertActiveVault, ExecutePayout, FundVault, SubmitReport};
+use bug_bounty_platform::program::BugBountyPlatform;
+use bug_bounty_platform::{BugBountyVault, SeverityTier};
+
+declare_id!("7DL55WmCcDuTxcrRuVjNr5EArXNABmkcBJfao4CCRm1w");
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+pub const AGENT_SEED: &[u8] = b"agent";
+
+// ============================================================================
+// PROGRAM LOGIC
+// ============================================================================
+
+/// Minimal aggregator used by the integration tests: a PDA owned by this
+/// program acts as researcher and funder on bug-bounty-platform via CPI.
+#[program]
+pub mod bounty_cpi_tester {
+    use super::*;
+
+    /// Submit a report with the agent PDA as the researcher
+    pub fn submit_via_cpi(
+        ctx: Context<SubmitViaCpi>,
+        severity: SeverityTier,
+        ipfs_hash: [u8; 32],
+    ) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[&[AGENT_SEED, &[ctx.bumps.agent]]];
+        
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.bounty_program.to_account_info(),
+            SubmitReport {
+                researcher: ctx.accounts.agent.to_account_info(),
+                vault: ctx.accounts.vault.to_account_info(),
+                report: ctx.accounts.report.to_account_info(),
+                submission_counter: None,
+                linked_report: None,
+                trusted_researcher: None,
+                researcher_profile: None,
+                external_attestation: None,
+                report_summary: None,
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        bug_bounty_platform::cpi::submit_report(cpi_ctx, severity, ipfs_hash, false, None)
+    }
+
+    /// Fund a vault from the agent PDA's token account
+    pub fn fund_via_cpi(
+        ctx: Context<FundViaCpi>,
+        amount: u64,
+    ) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[&[AGENT_SEED, &[ctx.bumps.agent]]];
+        
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.bounty_program.to_account_info(),
+            FundVault {
+                funder: ctx.accounts.agent.to_account_info(),
+                vault: ctx.accounts.vault.to_account_info(),
+                funder_token_account: ctx.accounts.agent_token_account.to_account_info(),
+                vault_token_account: ctx.accounts.vault_token_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        bug_bounty_platform::cpi::fund_vault(cpi_ctx, amount)
+    }
+
+    /// Collect an approved payout for the agent PDA into its (off-curve) associated token account
+    pub fn payout_via_cpi(
+        ctx: Context<PayoutViaCpi>,
+    ) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[&[AGENT_SEED, &[ctx.bumps.agent]]];
+        
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.bounty_program.to_account_info(),
+            ExecutePayout {
+                researcher: ctx.accounts.agent.to_account_info(),
+                vault: ctx.accounts.vault.to_account_info(),
+                report: ctx.accounts.report.to_account_info(),
+                vault_token_account: ctx.accounts.vault_token_account.to_account_info(),
+                researcher_token_account: ctx.accounts.agent_token_account.to_account_info(),
+                vault_authority: ctx.accounts.vault_authority.to_account_info(),
+                donation_token_account: None,
+                claim_escrow: None,
+                submission_counter: None,
+                researcher_profile: None,
+                firm_member: None,
+                token_program: ctx.accounts.token_program.to_account_info(),
+                report_summary: None,
+            },
+            signer_seeds,
+        );
+        
+        bug_bounty_platform::cpi::execute_payout(cpi_ctx)
+    }
+
+    /// Stand-in for an admin action that requires this program to have a live, funded bug
+    /// bounty: the vault must carry the ownership badge for this very program
+    pub fn guarded_admin_action(
+        ctx: Context<GuardedAdminAction>,
+        min_balance: u64,
+    ) -> Result<()> {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.bounty_program.to_account_info(),
+            AssertActiveVault {
+                vault: ctx.accounts.vault.to_account_info(),
+            },
+        );
+        bug_bounty_platform::cpi::assert_active_vault(cpi_ctx, crate::ID, min_balance)?;
+        
+        msg!("Admin action allowed");
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT CONTEXTS
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SubmitViaCpi<'info> {
+    /// Lamport-only PDA, pays rent for the report it submits
+    #[account(mut, seeds = [AGENT_SEED], bump)]
+    pub agent: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// CHECK: Initialized and validated by bug-bounty-platform
+    #[account(mut)]
+    pub report: UncheckedAccount<'info>,
+    
+    pub bounty_program: Program<'info, BugBountyPlatform>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundViaCpi<'info> {
+    /// Lamport-only PDA owning agent_token_account
+    #[account(mut, seeds = [AGENT_SEED], bump)]
+    pub agent: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = agent_token_account.owner == agent.key())]
+    pub agent_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Validated by bug-bounty-platform
+    #[account(mut)]
+    pub vault_token_account: UncheckedAccount<'info>,
+    
+    pub bounty_program: Program<'info, BugBountyPlatform>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct PayoutViaCpi<'info> {
+    /// Researcher of the report; only signs, never pays
+    #[account(seeds = [AGENT_SEED], bump)]
+    pub agent: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// CHECK: Validated by bug-bounty-platform
+    #[account(mut)]
+    pub report: UncheckedAccount<'info>,
+    
+    /// CHECK: Validated by bug-bounty-platform
+    #[account(mut)]
+    pub vault_token_account: UncheckedAccount<'info>,
+    
+    /// CHECK: The agent's associated token account, validated by bug-bounty-platform
+    #[account(mut)]
+    pub agent_token_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Validated by bug-bounty-platform
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    pub bounty_program: Program<'info, BugBountyPlatform>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct GuardedAdminAction<'info> {
+    /// Must be verified for this program; checked by bug-bounty-platform
+    pub vault: Account<'info, BugBountyVault>,
+    
+    pub bounty_program: Program<'info, BugBountyPlatform>,
+}
@@ -1,5 +1,15 @@
+// Instruction handlers mirror their argument lists into the generated CPI helpers
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Transfer, Token};
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::{get_associated_token_address, AssociatedToken};
+use anchor_spl::token::{self, Mint, Transfer, Token, TokenAccount};
+use solana_sha256_hasher::hash;
 
 declare_id!("7CjDDSGfTDYAydZ3nSamXbahqsaapDY862PQQaVJwiw2");
 
@@ -7,28 +17,568 @@ declare_id!("7CjDDSGfTDYAydZ3nSamXbahqsaapDY862PQQaVJwiw2");
 // CONSTANTS
 // ============================================================================
 
-const VAULT_SEED: &str = "vault";
-const REPORT_SEED: &str = "report";
-const REPUTATION_SEED: &str = "reputation";
+#[constant]
+pub const VAULT_SEED: &[u8] = b"vault";
+#[constant]
+pub const REPORT_SEED: &[u8] = b"report";
+#[constant]
+pub const REPUTATION_SEED: &[u8] = b"reputation";
+#[constant]
+pub const PLATFORM_CONFIG_SEED: &[u8] = b"platform_config";
+#[constant]
+pub const CLAIM_ESCROW_SEED: &[u8] = b"claim_escrow";
+#[constant]
+pub const STAKE_ESCROW_SEED: &[u8] = b"stake_escrow";
+#[constant]
+pub const SUBMISSION_COUNTER_SEED: &[u8] = b"submission_counter";
+#[constant]
+pub const ESCROW_AUTHORITY_SEED: &[u8] = b"escrow_authority";
+#[constant]
+pub const AUDIT_LOG_SEED: &[u8] = b"audit_log";
+#[constant]
+pub const ROLE_SEED: &[u8] = b"role";
+#[constant]
+pub const SNAPSHOT_SEED: &[u8] = b"snapshot";
+#[constant]
+pub const HALL_OF_FAME_SEED: &[u8] = b"hall_of_fame";
+#[constant]
+pub const VAULT_REGISTRY_SEED: &[u8] = b"vault_registry";
+#[constant]
+pub const HANDLE_SEED: &[u8] = b"handle";
+#[constant]
+pub const RESEARCHER_PROFILE_SEED: &[u8] = b"researcher_profile";
+#[constant]
+pub const MILESTONE_SEED: &[u8] = b"milestone";
+#[constant]
+pub const BOUNTY_BOOST_SEED: &[u8] = b"bounty_boost";
+#[constant]
+pub const BOOST_ESCROW_SEED: &[u8] = b"boost_escrow";
+#[constant]
+pub const RESPONSE_PACKET_SEED: &[u8] = b"response_packet";
+#[constant]
+pub const SUBSCRIPTION_SEED: &[u8] = b"subscription";
+#[constant]
+pub const POLICY_VERSION_SEED: &[u8] = b"policy_version";
+#[constant]
+pub const TRUSTED_RESEARCHER_SEED: &[u8] = b"trusted_researcher";
+#[constant]
+pub const FIRM_MEMBER_SEED: &[u8] = b"firm_member";
+#[constant]
+pub const PLATFORM_VAULT_SEED: &[u8] = b"platform";
+#[constant]
+pub const EXTERNAL_ATTESTATION_SEED: &[u8] = b"external_attestation";
+#[constant]
+pub const GOVERNANCE_BOND_SEED: &[u8] = b"governance_bond";
+#[constant]
+pub const GOVERNANCE_BOND_ESCROW_SEED: &[u8] = b"governance_bond_escrow";
+#[constant]
+pub const ACKNOWLEDGMENT_SEED: &[u8] = b"acknowledgment";
+#[constant]
+pub const COMPROMISE_ALERT_SEED: &[u8] = b"compromise_alert";
+#[constant]
+pub const REPORT_SUMMARY_SEED: &[u8] = b"report_summary";
+
+pub const MAX_SWAP_PROGRAMS: usize = 4;
+
+// Boosts one payout can drain, passed to execute_payout as remaining_accounts triples
+// (boost, boost escrow, researcher's ATA for the boost mint); bounded by transaction size
+pub const MAX_BOOSTS_PER_PAYOUT: usize = 4;
+
+// Every bit a severity mask (paused_tiers, boost filters) may set, one per SeverityTier
+pub const ALL_SEVERITY_TIERS: u8 = (1 << 5) - 1;
+
+// Vault feature_flags bits; each gates an experimental instruction, off until the team opts in
+pub const FEATURE_BOUNTY_BOOSTS: u64 = 1 << 0;     // create_bounty_boost
+pub const FEATURE_PRIORITY_STAKE: u64 = 1 << 1;    // stake_for_priority
+pub const FEATURE_SEVERITY_DISPUTES: u64 = 1 << 2; // dispute_severity
+pub const FEATURE_RENT_SUBSIDY: u64 = 1 << 3;      // submit_report_subsidized
+pub const ALL_FEATURES: u64 = (1 << 4) - 1;
+
+#[constant]
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+const AUDIT_LOG_CAPACITY: usize = 64;
+
+const DEFAULT_CLAIM_WINDOW_SECONDS: i64 = 90 * 24 * 60 * 60;
+
+const SNAPSHOT_RETENTION_SECONDS: i64 = 2 * 365 * 24 * 60 * 60;
+
+// Payout circuit breaker: a rolling hour tracked in 10-minute buckets
+pub const CIRCUIT_BREAKER_WINDOW_SECONDS: i64 = 60 * 60;
+pub const CIRCUIT_BREAKER_BUCKETS: usize = 6;
+const CIRCUIT_BREAKER_BUCKET_SECONDS: i64 = CIRCUIT_BREAKER_WINDOW_SECONDS / CIRCUIT_BREAKER_BUCKETS as i64;
+
+// Wait between governance asking for its bond back and withdrawing it, so approvals made
+// just before unbonding can still be arbitrated
+pub const GOVERNANCE_BOND_COOLDOWN_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+// Held with a governance-compromise alert to deter false alarms; returned if the team confirms it
+pub const COMPROMISE_ALERT_DEPOSIT_LAMPORTS: u64 = 100_000_000;
+
+// Sole signer of set_test_clock. Its secret key is checked in at
+// tests/keys/test-clock-authority.json, so the feature must never reach a deployed build
+#[cfg(feature = "test-clock")]
+pub const TEST_CLOCK_AUTHORITY: Pubkey = pubkey!("EgE71Ec57dyZLErFdBVS4aP8VpokGQPNoduAbEEnFozZ");
+
+// Furthest a caller-supplied deadline or duration may reach; anything beyond is a unit mix-up
+pub const MAX_TIMESTAMP_HORIZON_SECONDS: i64 = 10 * 365 * 24 * 60 * 60;
+
+pub const HALL_OF_FAME_CAPACITY: usize = 16;
+
+pub const REGISTRY_PAGE_CAPACITY: usize = 128;
+
+pub const MAX_HANDLE_LEN: usize = 32;
+
+const DEFAULT_HANDLE_FEE_LAMPORTS: u64 = 10_000_000;
+
+// Shortest allowed governance recovery window when recovery is enabled
+pub const MIN_GOVERNANCE_RECOVERY_DELAY_SECONDS: i64 = 24 * 60 * 60;
+
+// Auto-approved reports can't be paid out for this long, leaving governance time to revoke
+pub const AUTO_APPROVAL_TIMELOCK_SECONDS: i64 = 24 * 60 * 60;
+
+pub const MAX_PROJECT_NAME_LEN: usize = 64;
+
+// Milestone badges by id; the id is part of the badge seed, so entries are only ever appended.
+// TotalEarned thresholds are in whole tokens
+pub const MILESTONES: [MilestoneDefinition; 6] = [
+    MilestoneDefinition { metric: MilestoneMetric::PaidReports, threshold: 1 },
+    MilestoneDefinition { metric: MilestoneMetric::PaidReports, threshold: 10 },
+    MilestoneDefinition { metric: MilestoneMetric::PaidReports, threshold: 50 },
+    MilestoneDefinition { metric: MilestoneMetric::PaidCriticalReports, threshold: 1 },
+    MilestoneDefinition { metric: MilestoneMetric::PaidCriticalReports, threshold: 5 },
+    MilestoneDefinition { metric: MilestoneMetric::TotalEarned, threshold: 100_000 },
+];
+
+// Reward mints a vault can pay tiers in besides its primary vault token account
+pub const MAX_EXTRA_REWARD_MINTS: usize = 2;
+
+// Retired primary reward mints kept on the vault for reading historical payouts
+pub const MAX_PREVIOUS_MINTS: usize = 4;
+
+// Canned decision reasons kept on the vault; reports store only the template index
+pub const MAX_REASON_TEMPLATES: usize = 8;
+pub const REASON_TEMPLATE_LEN: usize = 64;
+
+/// Hash function behind report reason hashes: the sha256 digest of the UTF-8 reason text,
+/// whose content lives off-chain. An all-zero hash means no reason was given.
+pub const REASON_HASH_ALGORITHM: &str = "sha256";
+
+/// Current VulnerabilityReport layout; older accounts must go through migrate_report
+pub const REPORT_VERSION: u8 = 2;
+
+/// Byte offsets into a VulnerabilityReport account, discriminator included, for RPC memcmp
+/// filters. These fields precede the first Option, so their position never varies
+#[constant]
+pub const REPORT_VAULT_OFFSET: usize = 8;
+#[constant]
+pub const REPORT_RESEARCHER_OFFSET: usize = REPORT_VAULT_OFFSET + 32;
+#[constant]
+pub const REPORT_SEVERITY_OFFSET: usize = REPORT_RESEARCHER_OFFSET + 32;
+#[constant]
+pub const REPORT_STATUS_OFFSET: usize = REPORT_SEVERITY_OFFSET + 1;
+
+// Fixed size of a ReportSummary account, discriminator included
+#[constant]
+pub const REPORT_SUMMARY_SPACE: usize = 128;
+
+// Bubblegum compressed NFTs, used for cheap reputation badges
+pub const BUBBLEGUM_PROGRAM_ID: Pubkey = pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+pub const SPL_ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey = pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+pub const SPL_NOOP_PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+const BUBBLEGUM_MINT_V1_DISCRIMINATOR: [u8; 8] = [145, 98, 192, 118, 184, 147, 118, 104];
+const BUBBLEGUM_MAX_NAME_LEN: usize = 32;
+const BADGE_SYMBOL: &str = "BOUNTY";
+// TreeConfig.num_minted follows the discriminator, creator, delegate and capacity
+const TREE_CONFIG_NUM_MINTED_OFFSET: usize = 8 + 32 + 32 + 8;
+
+// Native ed25519 precompile, checked by introspection for sponsored submissions
+pub const ED25519_PROGRAM_ID: Pubkey = pubkey!("Ed25519SigVerify111111111111111111111111111");
+
+// Audit log action codes
+const AUDIT_UPDATE_REWARD_TIERS: u8 = 1;
+const AUDIT_TOGGLE_VAULT_STATUS: u8 = 2;
+const AUDIT_ROTATE_GOVERNANCE: u8 = 3;
+const AUDIT_REQUEST_WITHDRAWAL: u8 = 4;
+const AUDIT_EXECUTE_WITHDRAWAL: u8 = 5;
+const AUDIT_CANCEL_WITHDRAWAL: u8 = 6;
+const AUDIT_RELEASE_INSURANCE: u8 = 7;
+const AUDIT_HOLD_REPORT: u8 = 8;
+const AUDIT_RELEASE_REPORT: u8 = 9;
+const AUDIT_REOPEN_SUBMISSIONS: u8 = 10;
+const AUDIT_SET_MAX_OPEN_REPORTS: u8 = 11;
+const AUDIT_SET_PRIORITY_STAKE: u8 = 12;
+const AUDIT_SET_EMBARGO: u8 = 13;
+const AUDIT_SET_WITHDRAWAL_DELAY: u8 = 14;
+const AUDIT_SET_INSURANCE_BPS: u8 = 15;
+const AUDIT_SET_SPAM_GUARD: u8 = 16;
+const AUDIT_GRANT_ROLE: u8 = 17;
+const AUDIT_REVOKE_ROLE: u8 = 18;
+const AUDIT_SET_CLAIM_WINDOW: u8 = 19;
+const AUDIT_SET_VAULT_METADATA: u8 = 20;
+const AUDIT_SET_COMPRESSED_REPUTATION: u8 = 21;
+const AUDIT_SET_ALLOW_SELF_APPROVAL: u8 = 22;
+const AUDIT_SET_DUAL_APPROVAL_THRESHOLD: u8 = 23;
+const AUDIT_CO_APPROVE_REPORT: u8 = 24;
+const AUDIT_INITIATE_GOVERNANCE_RECOVERY: u8 = 25;
+const AUDIT_CANCEL_GOVERNANCE_RECOVERY: u8 = 26;
+const AUDIT_FINALIZE_GOVERNANCE_RECOVERY: u8 = 27;
+const AUDIT_SET_MAX_VAULT_BALANCE: u8 = 28;
+const AUDIT_MARK_REMEDIATED: u8 = 29;
+const AUDIT_SET_REASON_TEMPLATE: u8 = 30;
+const AUDIT_SET_REPUTATION_ON_APPROVAL: u8 = 31;
+const AUDIT_ADD_REWARD_MINT: u8 = 32;
+const AUDIT_REMOVE_REWARD_MINT: u8 = 33;
+const AUDIT_CHANGE_REWARD_MINT: u8 = 34;
+const AUDIT_SET_DECAY_CONFIG: u8 = 35;
+const AUDIT_SET_INVOICE_REQUIRED: u8 = 36;
+const AUDIT_SET_REJECTION_COOLDOWN: u8 = 37;
+const AUDIT_SET_REWARD_GRANULARITY: u8 = 38;
+const AUDIT_SET_TIER_PAUSE: u8 = 39;
+const AUDIT_SET_MAX_PENDING_REPORTS: u8 = 40;
+const AUDIT_SET_FEATURE_FLAGS: u8 = 41;
+const AUDIT_SET_CIRCUIT_BREAKER: u8 = 42;
+const AUDIT_RESET_CIRCUIT_BREAKER: u8 = 43;
+const AUDIT_SET_REPUTATION_GATE: u8 = 44;
+const AUDIT_SET_MAX_PAYOUT_FRACTION: u8 = 45;
+const AUDIT_SET_DUST_POLICY: u8 = 46;
+const AUDIT_SET_MAINTAIN_SUMMARIES: u8 = 47;
+
+// Role permission bits
+pub const PERMISSION_APPROVE: u16 = 1 << 0;
+pub const PERMISSION_REJECT: u16 = 1 << 1;
+pub const PERMISSION_FUND_WITHDRAW: u16 = 1 << 2;
+pub const PERMISSION_UPDATE_TIERS: u16 = 1 << 3;
+pub const PERMISSION_PAUSE: u16 = 1 << 4;
+pub const PERMISSION_BAN: u16 = 1 << 5;
+pub const PERMISSION_TRIAGE: u16 = 1 << 6;
+pub const ALL_PERMISSIONS: u16 = (1 << 7) - 1;
 
 // ============================================================================
 // DATA STRUCTURES
 // ============================================================================
 
+/// Borsh-encoded as the single discriminant byte below, which clients may rely on;
+/// variants are only ever appended
 #[derive(PartialEq, Eq, Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+#[repr(u8)]
 pub enum SeverityTier {
-    Critical,  // Highest reward
+    Critical = 0,  // Highest reward
+    High = 1,
+    Medium = 2,
+    Low = 3,
+    Informational = 4,  // Acknowledged without a reward
+}
+
+impl SeverityTier {
+    pub const ALL: [SeverityTier; 5] = [
+        SeverityTier::Critical,
+        SeverityTier::High,
+        SeverityTier::Medium,
+        SeverityTier::Low,
+        SeverityTier::Informational,
+    ];
+    
+    /// Payout a vault assigns a new report of this severity, before any decay multiplier
+    pub fn reward_for(self, vault: &BugBountyVault) -> u64 {
+        match self {
+            SeverityTier::Critical => vault.critical_reward,
+            SeverityTier::High => vault.high_reward,
+            SeverityTier::Medium => vault.medium_reward,
+            SeverityTier::Low => vault.low_reward,
+            SeverityTier::Informational => 0,
+        }
+    }
+}
+
+impl TryFrom<u8> for SeverityTier {
+    type Error = anchor_lang::error::Error;
+    
+    fn try_from(discriminant: u8) -> Result<Self> {
+        SeverityTier::ALL
+            .get(discriminant as usize)
+            .copied()
+            .ok_or_else(|| error!(BugBountyError::UnknownDiscriminant))
+    }
+}
+
+impl std::fmt::Display for SeverityTier {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            SeverityTier::Critical => "Critical",
+            SeverityTier::High => "High",
+            SeverityTier::Medium => "Medium",
+            SeverityTier::Low => "Low",
+            SeverityTier::Informational => "Informational",
+        })
+    }
+}
+
+/// Severity as published in events; confidential reports are Unclassified
+/// until governance declassifies them
+#[derive(PartialEq, Eq, Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub enum EventSeverity {
+    Unclassified,
+    Critical,
     High,
     Medium,
     Low,
+    Informational,
+}
+
+impl From<SeverityTier> for EventSeverity {
+    fn from(severity: SeverityTier) -> Self {
+        match severity {
+            SeverityTier::Critical => EventSeverity::Critical,
+            SeverityTier::High => EventSeverity::High,
+            SeverityTier::Medium => EventSeverity::Medium,
+            SeverityTier::Low => EventSeverity::Low,
+            SeverityTier::Informational => EventSeverity::Informational,
+        }
+    }
 }
 
+/// Borsh-encoded as the single discriminant byte below, which clients may rely on;
+/// variants are only ever appended
 #[derive(PartialEq, Eq, Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+#[repr(u8)]
 pub enum ReportStatus {
-    Pending,
-    Approved,
-    Rejected,
-    Paid,
+    Pending = 0,
+    Approved = 1,
+    Rejected = 2,
+    Paid = 3,
+    Withdrawn = 4,
+    Expired = 5,
+    InArbitration = 6,
+    SeverityDisputed = 7, // Approved, but the researcher contests the tier; payout waits for governance
+}
+
+impl ReportStatus {
+    pub const ALL: [ReportStatus; 8] = [
+        ReportStatus::Pending,
+        ReportStatus::Approved,
+        ReportStatus::Rejected,
+        ReportStatus::Paid,
+        ReportStatus::Withdrawn,
+        ReportStatus::Expired,
+        ReportStatus::InArbitration,
+        ReportStatus::SeverityDisputed,
+    ];
+    
+    /// Statuses counted in a vault's unresolved_reports
+    pub fn is_unresolved(self) -> bool {
+        matches!(
+            self,
+            ReportStatus::Pending | ReportStatus::Approved | ReportStatus::InArbitration | ReportStatus::SeverityDisputed
+        )
+    }
+    
+    /// The report state machine. Handlers still check their own preconditions (holds,
+    /// escalating once, auto-approval revocation); this is the backstop against any
+    /// status change the lifecycle doesn't allow
+    pub fn can_transition_to(self, next: ReportStatus) -> bool {
+        use ReportStatus::*;
+        matches!(
+            (self, next),
+            (Pending, Approved | Rejected | Withdrawn)
+                | (Approved, Paid | Expired | SeverityDisputed | Pending)
+                | (SeverityDisputed, Approved)
+                | (Expired, Approved)
+                | (Rejected, InArbitration)
+                | (InArbitration, Approved | Rejected)
+        )
+    }
+}
+
+impl TryFrom<u8> for ReportStatus {
+    type Error = anchor_lang::error::Error;
+    
+    fn try_from(discriminant: u8) -> Result<Self> {
+        ReportStatus::ALL
+            .get(discriminant as usize)
+            .copied()
+            .ok_or_else(|| error!(BugBountyError::UnknownDiscriminant))
+    }
+}
+
+impl std::fmt::Display for ReportStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            ReportStatus::Pending => "Pending",
+            ReportStatus::Approved => "Approved",
+            ReportStatus::Rejected => "Rejected",
+            ReportStatus::Paid => "Paid",
+            ReportStatus::Withdrawn => "Withdrawn",
+            ReportStatus::Expired => "Expired",
+            ReportStatus::InArbitration => "In arbitration",
+            ReportStatus::SeverityDisputed => "Severity disputed",
+        })
+    }
+}
+
+/// Orderly shutdown: WindingDown blocks submissions and approvals after the triage
+/// deadline; WoundDown is final and keeps the vault inactive
+#[derive(PartialEq, Eq, Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub enum WindDownPhase {
+    Live,
+    WindingDown,
+    WoundDown,
+}
+
+/// How approval prices a report whose tier reward changed after it was submitted;
+/// chosen at vault creation
+#[derive(PartialEq, Eq, Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub enum PricingPolicy {
+    AtSubmission,
+    AtApproval,
+    HigherOfBoth,
+    LowerOfBoth,
+}
+
+impl PricingPolicy {
+    /// Payout given the amount frozen at submission and the current tier reward
+    pub fn price(self, at_submission: u64, at_approval: u64) -> u64 {
+        match self {
+            PricingPolicy::AtSubmission => at_submission,
+            PricingPolicy::AtApproval => at_approval,
+            PricingPolicy::HigherOfBoth => at_submission.max(at_approval),
+            PricingPolicy::LowerOfBoth => at_submission.min(at_approval),
+        }
+    }
+}
+
+/// Who keeps the units left over when a payout's legs are rounded down
+#[derive(PartialEq, Eq, Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub enum DustPolicy {
+    Researcher, // Added to the researcher leg
+    Vault,      // Never leaves the vault and goes back to its available balance
+}
+
+/// Why governance turned a report down, published in its ResponsePacket
+#[derive(PartialEq, Eq, Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub enum DecisionCode {
+    NotReproducible,
+    OutOfScope,
+    KnownIssue,
+    Duplicate,
+    InsufficientImpact,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub enum ArbitrationOutcome {
+    ForceApprove,
+    UpholdRejection,
+    Split,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub enum PocCluster {
+    Devnet,
+    Testnet,
+    Mainnet,
+}
+
+/// Proof-of-concept transaction a triager can open in an explorer; a reference, not verified
+#[derive(PartialEq, Eq, Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct PocReference {
+    pub tx_signature: [u8; 64],
+    pub cluster: PocCluster,
+}
+
+/// Payouts settled during one bucket of the circuit breaker window
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct PayoutBucket {
+    pub start: i64,
+    pub payouts: u32,
+    pub outflow: u64, // Base units, whichever mint paid
+}
+
+/// Amounts a payout would move, returned by preview_payout
+#[derive(PartialEq, Eq, Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct PayoutBreakdown {
+    pub payout_amount: u64,
+    pub payout_mint: Pubkey, // Default key means the vault's primary mint
+    pub researcher_amount: u64,
+    pub donation_amount: u64,
+    pub donation_recipient: Option<Pubkey>,
+    pub retained_dust_amount: u64, // Kept by the vault; the three legs sum to payout_amount
+    pub co_approval_required: bool,
+    pub payable: bool, // Whether execute_payout would accept the report right now
+}
+
+/// Vault budget and counters, returned by get_vault_status
+#[derive(PartialEq, Eq, Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct VaultStatus {
+    pub vault_active: bool,
+    pub submissions_open: bool,
+    pub recorded_balance: u64,
+    pub committed_amount: u64,
+    pub insurance_reserve: u64,
+    pub available_balance: u64, // Not yet committed to approved reports or the reserve
+    pub total_reports: u64,
+    pub approved_reports: u64,
+    pub rejected_reports: u64,
+    pub withdrawn_reports: u64,
+    pub total_paid_out: u64,
+    pub paused_tiers: u8, // Bit per SeverityTier, in declaration order
+    pub first_responses: u64,
+    pub total_first_response_seconds: u64, // Divide by first_responses for the average
+    pub pending_reports: u64,
+    pub max_pending_reports: u16,
+    pub rent_subsidy_budget: u64, // Lamports; 0 means submit_report_subsidized fails
+}
+
+/// Payout a new report of each severity would be assigned, returned by get_effective_rewards
+#[derive(PartialEq, Eq, Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct EffectiveRewards {
+    pub critical: u64,
+    pub high: u64,
+    pub medium: u64,
+    pub low: u64,
+    pub informational: u64,
+}
+
+/// Reward tiers a report was judged under, returned by get_report_policy
+#[derive(PartialEq, Eq, Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ReportPolicy {
+    pub submitted_policy_version: u32,
+    pub approved_policy_version: Option<u32>,
+    pub judged_policy_version: u32, // The approval's version once approved, else the submission's
+    pub rewards: [u64; 4],          // Base units: critical, high, medium, low
+}
+
+/// Whether submit_report would accept a researcher right now, returned by can_submit
+#[derive(PartialEq, Eq, Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SubmitEligibility {
+    pub allowed: bool,
+    pub error_code: Option<u32>, // Program error submit_report would fail with
+    pub open_reports: u16,
+    pub max_open_reports: u8,    // 0 means unlimited
+}
+
+/// Reward multiplier that moves linearly from start to end across a submission window
+#[derive(PartialEq, Eq, Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct DecayConfig {
+    pub start_multiplier_bps: u16,
+    pub end_multiplier_bps: u16,
+    pub decay_start: i64,
+    pub decay_end: i64,
+}
+
+impl DecayConfig {
+    /// Multiplier for a report submitted at `at`, rounded down; 100% outside the window
+    pub fn multiplier_bps_at(&self, at: i64) -> u16 {
+        if at < self.decay_start || at > self.decay_end {
+            return BPS_DENOMINATOR as u16;
+        }
+        
+        // Weighted average of the endpoints; the window is validated to be non-empty
+        let duration = (self.decay_end - self.decay_start) as u128;
+        let elapsed = (at - self.decay_start) as u128;
+        let weighted = self.start_multiplier_bps as u128 * (duration - elapsed)
+            + self.end_multiplier_bps as u128 * elapsed;
+        (weighted / duration) as u16
+    }
 }
 
 #[account]
@@ -49,577 +599,13646 @@ pub struct BugBountyVault {
     pub total_paid_out: u64,
     pub total_reports: u64,
     pub approved_reports: u64,
+    // Only count decisions made since these counters were added; older reports are not backfilled
+    pub rejected_reports: u64,
+    pub withdrawn_reports: u64,
     
     // Token mint for payouts (SOL if None, otherwise specific mint)
     pub reward_token_mint: Option<Pubkey>,
     pub vault_active: bool,
     pub created_at: i64,
-}
-
-#[account]
-pub struct VulnerabilityReport {
-    pub vault: Pubkey,
-    pub researcher: Pubkey,
-    pub severity: SeverityTier,
-    pub status: ReportStatus,
-    pub report_ipfs_hash: [u8; 32],  // IPFS hash (32 bytes)
-    pub report_bump: u8,
     
-    pub submitted_at: i64,
-    pub approved_at: Option<i64>,
-    pub paid_at: Option<i64>,
+    // Stake required to flag a report as priority (0 disables the fast lane)
+    pub priority_stake: u64,
     
-    // Governance decision
-    pub approver: Option<Pubkey>,
-    pub approval_reason: Option<String>, // Optional metadata
-    pub payout_amount: u64,
-}
-
-#[account]
-pub struct ReputationNFT {
-    pub researcher: Pubkey,
-    pub vault: Pubkey,
-    pub report: Pubkey,
-    pub severity: SeverityTier,
+    // Open (non-terminal) reports allowed per researcher (0 means unlimited)
+    pub max_open_reports_per_researcher: u8,
+    
+    // Set when the team opted out of separating governance from the program team
+    pub allow_same_authority: bool,
+    
+    // Funds reserved for approved reports that have not been paid yet
+    pub committed_amount: u64,
+    
+    // Timelocked team withdrawals
+    pub withdrawal_delay_seconds: i64,
+    pub withdrawal_pending: bool,
+    pub pending_withdrawal_amount: u64,
+    pub withdrawal_executable_at: i64,
+    pub total_withdrawn: u64,
+    
+    // Share of each funding round set aside as an insurance reserve
+    pub insurance_bps: u16,
+    pub insurance_reserve: u64,
+    
+    // Spam defense: submissions close automatically when spam rejections
+    // within the rolling window exceed the threshold (0 disables)
+    pub submissions_open: bool,
+    pub spam_threshold: u32,
+    pub spam_window_seconds: i64,
+    pub spam_window_start: i64,
+    pub recent_spam_count: u32,
+    
+    // Coordinated disclosure delay applied after payout
+    pub embargo_seconds: i64,
+    
+    // Approved reports left unpaid this long can be expired (0 = never)
+    pub claim_window_seconds: i64,
+    
+    // Opt-in: lets the platform admin settle disputes against this vault's funds
+    pub arbitration_enabled: bool,
+    
+    // Metadata stamped onto reputation NFTs; the team mints (and pays for) them when set
+    pub team_mints_reputation: bool,
     pub project_name: String,
-    pub minted_at: i64,
+    
+    // Zero-padded UTF-8 decision reasons, rendered by clients (an all-zero slot is unset)
+    pub reason_templates: [[u8; REASON_TEMPLATE_LEN]; MAX_REASON_TEMPLATES],
+    
+    // Mint badges as Bubblegum compressed NFTs into the platform tree
+    pub compressed_reputation: bool,
+    
+    // Badges may be minted once a report is approved, for programs that pay off-chain
+    pub reputation_on_approval: bool,
+    
+    // Solo-dev vaults may knowingly let an approver approve their own report
+    pub allow_self_approval: bool,
+    
+    // Payouts at or above this also need the program team's co-approval (0 disables)
+    pub dual_approval_threshold: u64,
+    
+    // Team-initiated governance recovery for a lost governance key, fixed at creation (0 = disabled)
+    pub governance_recovery_delay_seconds: i64,
+    pub pending_governance_authority: Option<Pubkey>,
+    pub governance_recovery_executable_at: i64,
+    
+    // Most tokens fund_vault may bring the vault token account to (0 = uncapped)
+    pub max_vault_balance: u64,
+    
+    // Last sequence number stamped on an event about this vault (starts at 1)
+    pub event_sequence: u64,
+    
+    // Extra reward mints, each with its own vault token account and accounting.
+    // Mint index 0 is the primary mint above; index i + 1 is extra_reward_mints[i].
+    pub extra_reward_mints: [Pubkey; MAX_EXTRA_REWARD_MINTS],
+    pub extra_token_accounts: [Pubkey; MAX_EXTRA_REWARD_MINTS],
+    pub extra_funded: [u64; MAX_EXTRA_REWARD_MINTS],
+    pub extra_paid_out: [u64; MAX_EXTRA_REWARD_MINTS],
+    pub extra_committed: [u64; MAX_EXTRA_REWARD_MINTS],
+    
+    // Mint paying Critical, High, Medium and Low reports (default = primary mint)
+    pub tier_mints: [Pubkey; 4],
+    
+    // Primary mints replaced by change_reward_mint, oldest first; the count doubles
+    // as the primary mint generation stamped on reports
+    pub previous_mints: [Pubkey; MAX_PREVIOUS_MINTS],
+    pub previous_mint_count: u8,
+    
+    // Authority of the vault's token accounts; the vault PDA itself, signed for via signer_seeds
+    pub vault_authority: Pubkey,
+    
+    // Submission-time reward multiplier window, if any
+    pub decay_config: Option<DecayConfig>,
+    
+    // execute_payout refuses reports without an attached invoice
+    pub invoice_required: bool,
+    
+    // Researchers must wait this long after a rejection before submitting again (0 disables)
+    pub cooldown_after_rejection_seconds: i64,
+    
+    // Target program whose upgrade authority was shown to be the program team
+    pub verified_program: Option<Pubkey>,
+    pub verified_at: i64,
+    
+    // Decimals of the primary reward mint, for display; tiers must be multiples of the
+    // granularity in base units (0 allows any amount)
+    pub reward_decimals: u8,
+    pub reward_granularity: u64,
+    
+    // Reports in Pending, Approved or InArbitration; finalize_wind_down waits for zero
+    pub unresolved_reports: u64,
+    pub wind_down_phase: WindDownPhase,
+    pub triage_deadline: i64,
+    
+    // Severity tiers not accepting new submissions, one bit per SeverityTier in declaration order
+    pub paused_tiers: u8,
+    
+    // First-response SLA: reports that got an acknowledgment or decision, and the summed
+    // seconds from submission to that first response (legal hold time excluded)
+    pub first_responses: u64,
+    pub total_first_response_seconds: u64,
+    
+    // Triage backlog: reports currently Pending, and the cap submit_report enforces (0 = unlimited)
+    pub pending_reports: u64,
+    pub max_pending_reports: u16,
+    
+    // Lamports held in the vault account for submit_report_subsidized, and the rent currently
+    // sitting in open subsidized reports (returned to the budget when they close)
+    pub rent_subsidy_budget: u64,
+    pub rent_subsidy_outstanding: u64,
+    
+    // Bumped each time vault_token_account is replaced; payout receipts record it
+    pub token_account_generation: u16,
+    
+    // Opted-in experimental features (FEATURE_* bits), and the in-flight reports that pin the
+    // money-moving ones: unsettled priority stakes and open severity disputes
+    pub feature_flags: u64,
+    pub open_priority_stakes: u64,
+    pub disputed_reports: u64,
+    
+    // Open Subscription accounts, echoed in tier, boost and pause events as the fan-out
+    pub subscriber_count: u32,
+    
+    // Number of update_reward_tiers calls so far; version 0 is the tiers set at creation
+    pub policy_version: u32,
+    
+    // Fixed at creation: whether approval pays the submitted or the current tier reward
+    pub pricing_policy: PricingPolicy,
+    
+    // Payout circuit breaker against a compromised governance key (0 disables each limit);
+    // once tripped, payouts stop until the team resets it
+    pub max_payouts_per_hour: u32,
+    pub max_hourly_outflow: u64,
+    pub payout_buckets: [PayoutBucket; CIRCUIT_BREAKER_BUCKETS],
+    pub circuit_breaker_tripped: bool,
+    
+    // The platform's own vault for bugs in this program, at [VAULT_SEED, PLATFORM_VAULT_SEED]
+    // instead of the program team's key
+    pub platform_vault: bool,
+    
+    // Reputation needed to submit Critical reports (0 = none): paid reports on chain, or an
+    // unexpired external attestation's score when the vault accepts them
+    pub min_critical_reputation: u32,
+    pub accept_external_attestations: bool,
+    
+    // Tokens the governance authority has bonded against mis-approval, and the reports in
+    // arbitration that keep the bond locked
+    pub governance_bond_amount: u64,
+    pub open_arbitrations: u64,
+    
+    // Largest share of the vault's token balance one payout may take (10,000 = no cap; 0 on
+    // vaults created before the cap existed, also no cap)
+    pub max_payout_fraction_bps: u16,
+    
+    // Someone reported the governance key as compromised; payouts above the Low reward, or in
+    // another mint than Low's, wait for the program team to resolve the alert
+    pub compromise_alert_open: bool,
+    
+    // Where rounding remainders of split payouts go
+    pub dust_policy: DustPolicy,
+    
+    // New reports must come with a ReportSummary, which roughly doubles their rent
+    pub maintain_summaries: bool,
+    
+    // Pinned unix time read by test-clock builds instead of the cluster clock (0 = none);
+    // only set_test_clock writes it, and other builds don't contain that instruction
+    pub clock_override: i64,
 }
 
-// ============================================================================
-// PROGRAM LOGIC
-// ============================================================================
+/// BugBountyVault as first deployed, before any field was added. Those vaults were allocated
+/// 8 + size_of of exactly this layout and must go through migrate_vault
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct VaultLayoutV1 {
+    program_team: Pubkey,
+    governance_authority: Pubkey,
+    vault_bump: u8,
+    vault_token_account: Pubkey,
+    critical_reward: u64,
+    high_reward: u64,
+    medium_reward: u64,
+    low_reward: u64,
+    total_funded: u64,
+    total_paid_out: u64,
+    total_reports: u64,
+    approved_reports: u64,
+    reward_token_mint: Option<Pubkey>,
+    vault_active: bool,
+    created_at: i64,
+}
 
-#[program]
-pub mod bug_bounty_platform {
-    use super::*;
+const VAULT_V1_ACCOUNT_LEN: usize = 8 + std::mem::size_of::<VaultLayoutV1>();
 
-    /// Initialize a new bug bounty vault
-    pub fn create_bounty_vault(
-        ctx: Context<CreateBountyVault>,
-        critical_reward: u64,
-        high_reward: u64,
-        medium_reward: u64,
-        low_reward: u64,
-        initial_funding: u64,
-        reward_token_mint: Option<Pubkey>,
+impl BugBountyVault {
+    /// A freshly submitted report entered Pending
+    pub fn report_opened(&mut self) -> Result<()> {
+        self.pending_reports = self.pending_reports.checked_add(1)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        self.report_unresolved()
+    }
+    
+    /// Move a report to a new status; every status change after submission goes through here
+    /// so the state machine is enforced in one place and pending_reports and unresolved_reports
+    /// stay in step with the reports themselves
+    pub fn transition_report(
+        &mut self,
+        report: &mut VulnerabilityReport,
+        status: ReportStatus,
+        actor: Pubkey,
     ) -> Result<()> {
-        let vault = &mut ctx.accounts.vault;
-        
-        vault.program_team = ctx.accounts.program_team.key();
-        vault.governance_authority = ctx.accounts.governance_authority.key();
-        vault.vault_bump = ctx.bumps.vault;
-        vault.vault_token_account = ctx.accounts.vault_token_account.key();
-        
-        vault.critical_reward = critical_reward;
-        vault.high_reward = high_reward;
-        vault.medium_reward = medium_reward;
-        vault.low_reward = low_reward;
-        
-        vault.total_funded = initial_funding;
-        vault.total_paid_out = 0;
-        vault.total_reports = 0;
-        vault.approved_reports = 0;
-        
-        vault.reward_token_mint = reward_token_mint;
-        vault.vault_active = true;
-        vault.created_at = Clock::get()?.unix_timestamp;
+        let previous = report.status;
+        if !previous.can_transition_to(status) {
+            msg!("⛔ Report cannot move from {} to {} (attempted by {})", previous, status, actor);
+            return err!(BugBountyError::InvalidReportStatus);
+        }
+        report.status = status;
         
-        msg!("✅ Bug Bounty Vault created with {} critical, {} high, {} medium, {} low rewards", critical_reward, high_reward, medium_reward, low_reward);
+        // Saturating for reports opened before the pending count existed
+        match (previous == ReportStatus::Pending, status == ReportStatus::Pending) {
+            (true, false) => self.pending_reports = self.pending_reports.saturating_sub(1),
+            (false, true) => {
+                self.pending_reports = self.pending_reports.checked_add(1)
+                    .ok_or(BugBountyError::ArithmeticOverflow)?;
+            }
+            _ => {}
+        }
+        match (previous.is_unresolved(), status.is_unresolved()) {
+            (true, false) => self.report_resolved(),
+            (false, true) => self.report_unresolved()?,
+            _ => {}
+        }
+        match (previous == ReportStatus::SeverityDisputed, status == ReportStatus::SeverityDisputed) {
+            (true, false) => self.disputed_reports = self.disputed_reports.saturating_sub(1),
+            (false, true) => {
+                self.disputed_reports = self.disputed_reports.checked_add(1)
+                    .ok_or(BugBountyError::ArithmeticOverflow)?;
+            }
+            _ => {}
+        }
+        // Saturating for reports escalated before the arbitration count existed
+        match (previous == ReportStatus::InArbitration, status == ReportStatus::InArbitration) {
+            (true, false) => self.open_arbitrations = self.open_arbitrations.saturating_sub(1),
+            (false, true) => {
+                self.open_arbitrations = self.open_arbitrations.checked_add(1)
+                    .ok_or(BugBountyError::ArithmeticOverflow)?;
+            }
+            _ => {}
+        }
+        if status == ReportStatus::Approved {
+            report.approved_policy_version = Some(self.policy_version);
+        }
         Ok(())
     }
-
-    /// Submit a vulnerability report
-    pub fn submit_report(
-        ctx: Context<SubmitReport>,
-        severity: SeverityTier,
-        ipfs_hash: [u8; 32],
-    ) -> Result<()> {
-        let vault = &mut ctx.accounts.vault;
-        require!(vault.vault_active, BugBountyError::VaultInactive);
-        
-        let report = &mut ctx.accounts.report;
-        
-        report.vault = vault.key();
-        report.researcher = ctx.accounts.researcher.key();
-        report.severity = severity;
-        report.status = ReportStatus::Pending;
-        report.report_ipfs_hash = ipfs_hash;
-        report.report_bump = ctx.bumps.report;
-        report.submitted_at = Clock::get()?.unix_timestamp;
-        
-        // Set expected payout based on severity
-        report.payout_amount = match severity {
-            SeverityTier::Critical => vault.critical_reward,
-            SeverityTier::High => vault.high_reward,
-            SeverityTier::Medium => vault.medium_reward,
-            SeverityTier::Low => vault.low_reward,
-        };
-        
-        vault.total_reports += 1;
-        
-        msg!("📋 Report submitted by {} with {:?} severity", ctx.accounts.researcher.key(), severity);
+    
+    /// Whether the vault opted into a FEATURE_* bit
+    pub fn feature_enabled(&self, feature: u64) -> bool {
+        self.feature_flags & feature != 0
+    }
+    
+    /// A report entered Pending, Approved or InArbitration from a resolved status
+    pub fn report_unresolved(&mut self) -> Result<()> {
+        self.unresolved_reports = self.unresolved_reports.checked_add(1)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
         Ok(())
     }
-
-    /// Governance approves a vulnerability report (requires governance authority)
-    pub fn approve_report(
-        ctx: Context<ApproveReport>,
-        approval_reason: Option<String>,
-    ) -> Result<()> {
-        let vault = &ctx.accounts.vault;
-        let report = &mut ctx.accounts.report;
-        
-        // Verify approver is governance authority
-        require!(
-            ctx.accounts.governance_authority.key() == vault.governance_authority,
-            BugBountyError::NotGovernanceAuthority
-        );
-        
-        require!(report.status == ReportStatus::Pending, BugBountyError::InvalidReportStatus);
-        
-        report.status = ReportStatus::Approved;
-        report.approver = Some(ctx.accounts.governance_authority.key());
-        report.approved_at = Some(Clock::get()?.unix_timestamp);
-        report.approval_reason = approval_reason;
-        
-        let mut vault_mut = vault.clone();
-        vault_mut.approved_reports += 1;
-        
-        msg!("✅ Report approved by governance. Payout: {} tokens", report.payout_amount);
-        Ok(())
+    
+    /// A report reached a resolved status; saturates for reports opened before the count existed
+    pub fn report_resolved(&mut self) {
+        self.unresolved_reports = self.unresolved_reports.saturating_sub(1);
     }
-
-    /// Governance rejects a vulnerability report
-    pub fn reject_report(
-        ctx: Context<RejectReport>,
-        rejection_reason: String,
-    ) -> Result<()> {
-        let vault = &ctx.accounts.vault;
-        let report = &mut ctx.accounts.report;
-        
-        // Verify rejector is governance authority
-        require!(
-            ctx.accounts.governance_authority.key() == vault.governance_authority,
-            BugBountyError::NotGovernanceAuthority
-        );
-        
-        require!(report.status == ReportStatus::Pending, BugBountyError::InvalidReportStatus);
-        
-        report.status = ReportStatus::Rejected;
-        report.approver = Some(ctx.accounts.governance_authority.key());
-        report.approval_reason = Some(rejection_reason);
-        
-        msg!("❌ Report rejected by governance");
+    
+    /// Point the vault at a new primary token account; the caller must have emptied the old one,
+    /// since the vault PDA could otherwise still sign for a balance nobody tracks
+    pub fn replace_vault_token_account(&mut self, new_account: Pubkey) -> Result<()> {
+        self.vault_token_account = new_account;
+        self.token_account_generation = self.token_account_generation.checked_add(1)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
         Ok(())
     }
-
-    /// Execute automatic payout after approval
-    pub fn execute_payout(
-        ctx: Context<ExecutePayout>,
-    ) -> Result<()> {
-        let vault = &ctx.accounts.vault;
-        let report = &mut ctx.accounts.report;
-        
-        // Verify report is approved
-        require!(report.status == ReportStatus::Approved, BugBountyError::ReportNotApproved);
-        
-        // Verify researcher matches
-        require_eq!(report.researcher, ctx.accounts.researcher.key(), BugBountyError::UnauthorizedResearcher);
-        
-        let payout_amount = report.payout_amount;
-        let bump_bytes = vec![vault.vault_bump];
-        
-        let vault_seed_bytes = VAULT_SEED.as_bytes().to_vec();
-        let program_team_bytes = vault.program_team.as_ref().to_vec();
-        
-        // Create signer seeds array
-        let seeds_inner: Vec<&[u8]> = vec![
-            vault_seed_bytes.as_slice(),
-            program_team_bytes.as_slice(),
-            bump_bytes.as_slice(),
-        ];
-        
-        let signer_seeds_vec = vec![seeds_inner.as_slice()];
-        let signer_seeds: &[&[&[u8]]] = signer_seeds_vec.as_slice();
-        
-        // Execute transfer with PDA signature
-        let cpi_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.vault_token_account.to_account_info(),
-                to: ctx.accounts.researcher_token_account.to_account_info(),
-                authority: ctx.accounts.vault_authority.to_account_info(),
-            },
-            signer_seeds,
-        );
-        
-        token::transfer(cpi_ctx, payout_amount)?;
-        
-        report.status = ReportStatus::Paid;
-        report.paid_at = Some(Clock::get()?.unix_timestamp);
-        
-        msg!("💰 Payout of {} executed to researcher", payout_amount);
-        Ok(())
+    
+    /// Second seed of the vault PDA: the program team's key, or the reserved platform seed
+    pub fn pda_seed(&self) -> &[u8] {
+        if self.platform_vault {
+            PLATFORM_VAULT_SEED
+        } else {
+            self.program_team.as_ref()
+        }
     }
-
-    /// Mint reputation NFT for approved reports (optional)
-    pub fn mint_reputation_nft(
-        ctx: Context<MintReputationNFT>,
-        project_name: String,
-    ) -> Result<()> {
-        let report = &ctx.accounts.report;
-        
-        require!(report.status == ReportStatus::Paid, BugBountyError::ReportNotPaid);
-        
-        let reputation_nft = &mut ctx.accounts.reputation_nft;
-        reputation_nft.researcher = report.researcher;
-        reputation_nft.vault = report.vault;
-        reputation_nft.report = report.key();
-        reputation_nft.severity = report.severity;
-        reputation_nft.project_name = project_name;
-        reputation_nft.minted_at = Clock::get()?.unix_timestamp;
-        
-        msg!("🏆 Reputation NFT minted for researcher");
-        Ok(())
+    
+    /// Seeds the program signs with as vault_authority
+    pub fn signer_seeds(&self) -> [&[u8]; 3] {
+        [VAULT_SEED, self.pda_seed(), std::slice::from_ref(&self.vault_bump)]
     }
-
-    /// Allow vault to receive additional funding
-    pub fn fund_vault(
-        ctx: Context<FundVault>,
-        amount: u64,
-    ) -> Result<()> {
-        let vault = &mut ctx.accounts.vault;
-        
-        let cpi_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.funder_token_account.to_account_info(),
-                to: ctx.accounts.vault_token_account.to_account_info(),
-                authority: ctx.accounts.funder.to_account_info(),
-            },
-        );
-        
-        token::transfer(cpi_ctx, amount)?;
-        
-        vault.total_funded = vault.total_funded.checked_add(amount)
-            .ok_or(BugBountyError::ArithmeticOverflow)?;
-        
-        msg!("💸 Vault funded with additional {} tokens", amount);
-        Ok(())
+    
+    /// Tokens the vault should hold according to its own accounting
+    pub fn recorded_balance(&self) -> Result<u64> {
+        self.total_funded
+            .checked_sub(self.total_paid_out)
+            .and_then(|v| v.checked_sub(self.total_withdrawn))
+            .ok_or(error!(BugBountyError::ArithmeticUnderflow))
     }
-
-    /// Pause/unpause the vault (only program team)
-    pub fn toggle_vault_status(
-        ctx: Context<ToggleVaultStatus>,
-    ) -> Result<()> {
-        let vault = &mut ctx.accounts.vault;
-        
-        require_eq!(ctx.accounts.program_team.key(), vault.program_team, BugBountyError::UnauthorizedTeam);
-        
-        vault.vault_active = !vault.vault_active;
-        
-        msg!("🔄 Vault status toggled: {}", vault.vault_active);
-        Ok(())
+    
+    /// Lamports sent straight to the vault PDA: everything above its rent-exempt minimum
+    /// and the rent subsidy budget
+    pub fn sweepable_lamports(&self, lamports: u64, rent_exempt_minimum: u64) -> u64 {
+        lamports
+            .saturating_sub(rent_exempt_minimum)
+            .saturating_sub(self.rent_subsidy_budget)
     }
-
-    /// Update reward tiers (only program team)
-    pub fn update_reward_tiers(
-        ctx: Context<UpdateRewardTiers>,
-        critical_reward: u64,
-        high_reward: u64,
-        medium_reward: u64,
-        low_reward: u64,
-    ) -> Result<()> {
-        let vault = &mut ctx.accounts.vault;
-        
-        require_eq!(ctx.accounts.program_team.key(), vault.program_team, BugBountyError::UnauthorizedTeam);
-        
-        vault.critical_reward = critical_reward;
-        vault.high_reward = high_reward;
-        vault.medium_reward = medium_reward;
-        vault.low_reward = low_reward;
-        
-        msg!("⚙️ Reward tiers updated");
-        Ok(())
+    
+    /// True when the primary reward mint is wrapped SOL
+    pub fn pays_in_wrapped_sol(&self) -> bool {
+        self.reward_token_mint == Some(token::spl_token::native_mint::ID)
     }
-
-
-    /// Delete vault and return remaining funds to program team
-    /// Only the program team can delete the vault
-    /// Vault must be inactive and have no pending reports
-    pub fn delete_vault(
-        ctx: Context<DeleteVault>,
-        force_delete: bool,
-    ) -> Result<()> {
-        let vault = &ctx.accounts.vault;
-        
-        // Verify caller is the program team
-        require_eq!(
-            ctx.accounts.program_team.key(),
-            vault.program_team,
-            BugBountyError::UnauthorizedTeam
-        );
-        
-        // Vault must be inactive before deletion
-        require!(!vault.vault_active, BugBountyError::VaultMustBeInactive);
-        
-        // Check if there are any pending reports that haven't been resolved
-        let pending_reports = vault.total_reports
-            .checked_sub(vault.approved_reports)
+    
+    /// Count a spam rejection in the rolling window, starting a fresh window once the
+    /// current one has elapsed. Returns true when the threshold is exceeded.
+    pub fn record_spam_rejection(&mut self, now: i64) -> Result<bool> {
+        let window_end = self.spam_window_start
+            .checked_add(self.spam_window_seconds)
             .ok_or(BugBountyError::ArithmeticOverflow)?;
         
-        // Allow deletion only if all reports have been processed
-        // Or the team accepts responsibility for unprocessed reports
-        require!(
-            pending_reports == 0 || force_delete,
-            BugBountyError::HasPendingReports
-        );
+        if self.recent_spam_count == 0 || now >= window_end {
+            self.spam_window_start = now;
+            self.recent_spam_count = 0;
+        }
         
-        // Transfer remaining tokens from vault token account to program team
-        let remaining_balance = vault.total_funded
-            .checked_sub(vault.total_paid_out)
+        self.recent_spam_count = self.recent_spam_count.checked_add(1)
             .ok_or(BugBountyError::ArithmeticOverflow)?;
         
-        if remaining_balance > 0 {
-            let bump_bytes = [vault.vault_bump];
-            let program_team_key = vault.program_team;
-            
-            let signer_seeds: &[&[&[u8]]] = &[
-                &[
-                    VAULT_SEED.as_bytes(),
-                    program_team_key.as_ref(),
-                    &bump_bytes,
-                ]
-            ];
-            
-            let cpi_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.vault_token_account.to_account_info(),
-                    to: ctx.accounts.program_team_token_account.to_account_info(),
-                    authority: ctx.accounts.vault.to_account_info(),
-                },
-                signer_seeds,
-            );
-            
-            token::transfer(cpi_ctx, remaining_balance)?;
+        Ok(self.spam_threshold > 0 && self.recent_spam_count > self.spam_threshold)
+    }
+    
+    /// Payouts and outflow over the circuit breaker window ending at `now`, counting one
+    /// more payout of `amount`
+    pub fn hourly_payouts_with(&self, amount: u64, now: i64) -> Result<(u32, u64)> {
+        let window_start = now.checked_sub(CIRCUIT_BREAKER_WINDOW_SECONDS)
+            .ok_or(BugBountyError::ArithmeticUnderflow)?;
+        self.payout_buckets
+            .iter()
+            .filter(|bucket| bucket.payouts > 0 && bucket.start > window_start)
+            .try_fold((1u32, amount), |(payouts, outflow), bucket| {
+                Some((payouts.checked_add(bucket.payouts)?, outflow.checked_add(bucket.outflow)?))
+            })
+            .ok_or(error!(BugBountyError::ArithmeticOverflow))
+    }
+    
+    /// Whether a payout would take more than the capped share of the balance it's paid from
+    pub fn exceeds_payout_fraction(&self, payout_amount: u64, balance: u64) -> bool {
+        let cap_bps = self.max_payout_fraction_bps as u128;
+        if cap_bps == 0 || cap_bps >= BPS_DENOMINATOR as u128 {
+            return false;
         }
-        
-        msg!("🗑️ Vault deleted. {} tokens returned to program team", remaining_balance);
-        
-        // The vault account will be closed automatically due to the `close` constraint
-        // in the DeleteVault accounts struct
-        Ok(())
+        payout_amount as u128 * BPS_DENOMINATOR as u128 > balance as u128 * cap_bps
     }
-}
-
-// ============================================================================
-// ACCOUNT CONTEXTS
-// ============================================================================
-
-#[derive(Accounts)]
-#[instruction(critical_reward: u64, high_reward: u64, medium_reward: u64, low_reward: u64, initial_funding: u64)]
-pub struct CreateBountyVault<'info> {
-    #[account(mut)]
-    pub program_team: Signer<'info>,
     
-    pub governance_authority: SystemAccount<'info>,
+    /// Whether an open compromise alert holds back a payout. The Low reward is only a limit in
+    /// Low's own mint, so payouts in any other mint wait for the alert to be resolved
+    pub fn held_by_compromise_alert(&self, payout_mint: &Pubkey, payout_amount: u64) -> bool {
+        self.compromise_alert_open
+            && (*payout_mint != self.tier_mint_for(SeverityTier::Low) || payout_amount > self.low_reward)
+    }
     
-    #[account(
-        init,
-        payer = program_team,
-        space = 8 + std::mem::size_of::<BugBountyVault>(),
-        seeds = [VAULT_SEED.as_bytes(), program_team.key().as_ref()],
-        bump
-    )]
-    pub vault: Account<'info, BugBountyVault>,
+    /// Whether window totals break either circuit breaker limit
+    pub fn breaks_circuit(&self, payouts: u32, outflow: u64) -> bool {
+        (self.max_payouts_per_hour > 0 && payouts > self.max_payouts_per_hour)
+            || (self.max_hourly_outflow > 0 && outflow > self.max_hourly_outflow)
+    }
     
-    /// CHECK: Token account for the vault
-    pub vault_token_account: UncheckedAccount<'info>,
+    /// Count a settled payout in the bucket covering `now`, recycling the slot once stale
+    pub fn record_hourly_payout(&mut self, amount: u64, now: i64) -> Result<()> {
+        let start = now - now.rem_euclid(CIRCUIT_BREAKER_BUCKET_SECONDS);
+        let slot = now.div_euclid(CIRCUIT_BREAKER_BUCKET_SECONDS).rem_euclid(CIRCUIT_BREAKER_BUCKETS as i64) as usize;
+        let bucket = &mut self.payout_buckets[slot];
+        if bucket.start != start {
+            *bucket = PayoutBucket { start, ..PayoutBucket::default() };
+        }
+        bucket.payouts = bucket.payouts.checked_add(1).ok_or(BugBountyError::ArithmeticOverflow)?;
+        bucket.outflow = bucket.outflow.checked_add(amount).ok_or(BugBountyError::ArithmeticOverflow)?;
+        Ok(())
+    }
     
-    pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
-}
-
-#[derive(Accounts)]
-#[instruction(severity: SeverityTier, ipfs_hash: [u8; 32])]
-pub struct SubmitReport<'info> {
-    #[account(mut)]
-    pub researcher: Signer<'info>,
+    /// Recorded balance not yet reserved for approved reports or the insurance reserve
+    pub fn available_balance(&self) -> Result<u64> {
+        self.recorded_balance()?
+            .checked_sub(self.committed_amount)
+            .and_then(|v| v.checked_sub(self.insurance_reserve))
+            .ok_or(error!(BugBountyError::ArithmeticUnderflow))
+    }
     
-    #[account(mut)]
-    pub vault: Account<'info, BugBountyVault>,
+    /// Take the sequence number for the next event about this vault, so consumers
+    /// can deduplicate retried deliveries and spot gaps
+    pub fn next_event_sequence(&mut self) -> Result<u64> {
+        self.event_sequence = self.event_sequence.checked_add(1)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        Ok(self.event_sequence)
+    }
     
-    #[account(
-        init,
-        payer = researcher,
-        space = 8 + std::mem::size_of::<VulnerabilityReport>() + 256,
-        seeds = [REPORT_SEED.as_bytes(), vault.key().as_ref(), researcher.key().as_ref(), &vault.total_reports.to_le_bytes()],
-        bump
-    )]
-    pub report: Account<'info, VulnerabilityReport>,
+    /// Index of a reward mint: 0 for the primary mint (or the default key), i + 1 for extra_reward_mints[i]
+    pub fn mint_index(&self, mint: &Pubkey) -> Option<u8> {
+        if *mint == Pubkey::default() || Some(*mint) == self.reward_token_mint {
+            return Some(0);
+        }
+        self.extra_reward_mints.iter().position(|m| m == mint).map(|i| i as u8 + 1)
+    }
     
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-pub struct ApproveReport<'info> {
-    pub governance_authority: Signer<'info>,
+    /// Whether new submissions of this severity are paused
+    pub fn tier_paused(&self, severity: SeverityTier) -> bool {
+        self.paused_tiers & tier_bit(severity) != 0
+    }
     
-    pub vault: Account<'info, BugBountyVault>,
+    /// Mint a new report of the given severity is paid in (default = primary mint)
+    pub fn tier_mint_for(&self, severity: SeverityTier) -> Pubkey {
+        match severity {
+            SeverityTier::Critical => self.tier_mints[0],
+            SeverityTier::High => self.tier_mints[1],
+            SeverityTier::Medium => self.tier_mints[2],
+            SeverityTier::Low => self.tier_mints[3],
+            SeverityTier::Informational => Pubkey::default(),
+        }
+    }
     
-    #[account(mut, constraint = report.vault == vault.key())]
-    pub report: Account<'info, VulnerabilityReport>,
-}
-
-#[derive(Accounts)]
-pub struct RejectReport<'info> {
-    pub governance_authority: Signer<'info>,
+    /// Position in the extra_* arrays of a nonzero mint index
+    fn extra_index(&self, mint_index: u8) -> Result<usize> {
+        (mint_index as usize)
+            .checked_sub(1)
+            .filter(|&i| i < MAX_EXTRA_REWARD_MINTS && self.extra_reward_mints[i] != Pubkey::default())
+            .ok_or(error!(BugBountyError::UnknownRewardMint))
+    }
     
-    pub vault: Account<'info, BugBountyVault>,
+    /// Vault token account holding a reward mint's funds
+    pub fn mint_token_account(&self, mint_index: u8) -> Result<Pubkey> {
+        match mint_index {
+            0 => Ok(self.vault_token_account),
+            _ => Ok(self.extra_token_accounts[self.extra_index(mint_index)?]),
+        }
+    }
     
-    #[account(mut, constraint = report.vault == vault.key())]
-    pub report: Account<'info, VulnerabilityReport>,
+    /// Balance in a reward mint not yet reserved for approved reports
+    pub fn mint_available_balance(&self, mint_index: u8) -> Result<u64> {
+        if mint_index == 0 {
+            return self.available_balance();
+        }
+        let i = self.extra_index(mint_index)?;
+        self.extra_funded[i]
+            .checked_sub(self.extra_paid_out[i])
+            .and_then(|v| v.checked_sub(self.extra_committed[i]))
+            .ok_or(error!(BugBountyError::ArithmeticUnderflow))
+    }
+    
+    fn mint_committed_mut(&mut self, mint_index: u8) -> Result<&mut u64> {
+        if mint_index == 0 {
+            return Ok(&mut self.committed_amount);
+        }
+        let i = self.extra_index(mint_index)?;
+        Ok(&mut self.extra_committed[i])
+    }
+    
+    /// Reserve an approved payout in its mint so withdrawals can't spend it
+    pub fn commit_payout(&mut self, mint_index: u8, amount: u64) -> Result<()> {
+        require!(self.mint_available_balance(mint_index)? >= amount, BugBountyError::InsufficientVaultFunds);
+        let committed = self.mint_committed_mut(mint_index)?;
+        *committed = committed.checked_add(amount).ok_or(BugBountyError::ArithmeticOverflow)?;
+        Ok(())
+    }
+    
+    /// Return a reserved payout to its mint's free balance
+    pub fn release_payout(&mut self, mint_index: u8, amount: u64) -> Result<()> {
+        let committed = self.mint_committed_mut(mint_index)?;
+        *committed = committed.checked_sub(amount).ok_or(BugBountyError::ArithmeticUnderflow)?;
+        Ok(())
+    }
+    
+    /// Turn a reserved payout into a paid one
+    pub fn settle_payout(&mut self, mint_index: u8, amount: u64) -> Result<()> {
+        self.release_payout(mint_index, amount)?;
+        let paid_out = match mint_index {
+            0 => &mut self.total_paid_out,
+            _ => {
+                let i = self.extra_index(mint_index)?;
+                &mut self.extra_paid_out[i]
+            }
+        };
+        *paid_out = paid_out.checked_add(amount).ok_or(BugBountyError::ArithmeticOverflow)?;
+        Ok(())
+    }
 }
 
-#[derive(Accounts)]
-pub struct ExecutePayout<'info> {
-    #[account(mut)]
-    pub researcher: Signer<'info>,
+#[account]
+pub struct VulnerabilityReport {
+    pub vault: Pubkey,
+    pub researcher: Pubkey,
+    pub severity: SeverityTier,
+    pub status: ReportStatus,
+    pub report_ipfs_hash: [u8; 32],  // IPFS hash (32 bytes)
+    pub report_bump: u8,
     
-    pub vault: Account<'info, BugBountyVault>,
+    pub submitted_at: i64,
+    pub approved_at: Option<i64>,
+    pub paid_at: Option<i64>,
     
-    #[account(mut, constraint = report.vault == vault.key())]
-    pub report: Account<'info, VulnerabilityReport>,
+    // Governance decision
+    pub approver: Option<Pubkey>,
+    pub reason_hash: [u8; 32],       // See REASON_HASH_ALGORITHM, zeroed when absent
+    pub reason_template: Option<u8>, // Index into the vault's reason templates
+    pub payout_amount: u64,
     
-    /// CHECK: Vault token account
-    #[account(mut)]
-    pub vault_token_account: UncheckedAccount<'info>,
+    // Legal hold (freezes governance and payout actions without changing status)
+    pub on_hold: bool,
+    pub hold_reason_hash: [u8; 32],
+    pub held_at: Option<i64>,
+    pub total_held_seconds: i64, // Excluded from SLA metrics
     
-    /// CHECK: Researcher token account
-    #[account(mut)]
-    pub researcher_token_account: UncheckedAccount<'info>,
+    // Optional researcher-chosen donation split applied at payout
+    pub donation_bps: u16,
+    pub donation_recipient: Option<Pubkey>,
     
-    /// CHECK: Vault authority (PDA)
-    pub vault_authority: AccountInfo<'info>,
+    // Optional claim escrow holding the payout for a later claim_as swap
+    pub claim_escrow: Option<Pubkey>,
+    pub claim_escrow_amount: u64,
     
-    pub token_program: Program<'info, Token>,
+    // Priority triage stake, refunded on approval and forfeited when marked spam
+    pub priority: bool,
+    pub priority_stake_amount: u64,
+    pub stake_escrow: Option<Pubkey>,
+    pub is_spam: bool,
+    
+    // Whether this report holds a slot in the researcher's SubmissionCounter
+    pub counted_open: bool,
+    
+    // Confidential reports keep severity, researcher and amounts out of logs and events
+    pub confidential: bool,
+    
+    // Coordinated disclosure
+    pub embargo_until: Option<i64>,
+    pub public_ipfs_hash: Option<[u8; 32]>,
+    pub disclosed_at: Option<i64>,
+    
+    // A report may be escalated to platform arbitration once
+    pub escalated: bool,
+    
+    // Program team sign-off for payouts at or above the vault's dual approval threshold
+    pub team_co_approved: bool,
+    
+    // Relayer that paid rent for a sponsored submission
+    pub sponsor: Option<Pubkey>,
+    
+    // Link to the fix; once set the disclosure embargo runs from remediated_at
+    pub remediation_ref: [u8; 32],  // Hash or external tracker id
+    pub remediated_at: Option<i64>,
+    
+    pub version: u8,
+    
+    // Fields below were appended after version 2 and decode from zeroed padding
+    pub rejected_at: Option<i64>,
+    pub rent_payer: Pubkey,          // Refunded when the report is closed
+    pub payout_mint: Pubkey,         // Fixed at submission from the vault's tier mints (default = primary)
+    pub mint_generation: u8,         // Vault's previous_mint_count at submission
+    pub reward_multiplier_bps: u16,  // Decay multiplier at submission (0 = none)
+    pub invoice_hash: [u8; 32],      // Accounting invoice reference, zeroed when absent
+    pub linked_report: Option<Pubkey>, // Same finding reported to another vault by this researcher
+    pub poc_tx_signature: [u8; 64],  // Raw PoC transaction signature, zeroed when absent
+    pub poc_cluster: Option<PocCluster>,
+    pub acknowledged_at: Option<i64>, // Receipt from triage; status is unaffected
+    
+    // Severity dispute, at most one per report: the researcher's claim and evidence, the tier
+    // governance had approved at, and when governance settled the final tier
+    pub disputed_severity: Option<SeverityTier>,
+    pub dispute_evidence_hash: [u8; 32],
+    pub approved_severity: Option<SeverityTier>,
+    pub dispute_resolved_at: Option<i64>,
+    
+    // Structured rejection cause, set when governance published a ResponsePacket
+    pub decision_code: Option<DecisionCode>,
+    
+    // Vault policy_version in force at submission and at the latest approval
+    pub submitted_policy_version: u32,
+    pub approved_policy_version: Option<u32>,
+    
+    // Approved at submission under a TrustedResearcher grant; the approver is the vault PDA
+    pub auto_approved: bool,
+    
+    // Vault pricing policy that produced payout_amount on approval; None before approval
+    // and for arbitration overrides
+    pub priced_by: Option<PricingPolicy>,
+    
+    // Owner of the payout destination when not the researcher, e.g. a firm's multisig vault
+    pub payout_recipient: Option<Pubkey>,
+    
+    // The governance bond was slashed over this report's approval; each approval slashes once
+    pub bond_slashed: bool,
+    
+    // The approver's Role was charged payout_amount against its spend caps; expire_approval
+    // must refund it
+    pub approver_role_charged: bool,
 }
 
-#[derive(Accounts)]
-pub struct MintReputationNFT<'info> {
-    #[account(mut)]
-    pub researcher: Signer<'info>,
+/// Report fields that precede the decision reason in every layout version
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct ReportLayoutHead {
+    vault: Pubkey,
+    researcher: Pubkey,
+    severity: SeverityTier,
+    status: ReportStatus,
+    report_ipfs_hash: [u8; 32],
+    report_bump: u8,
+    submitted_at: i64,
+    approved_at: Option<i64>,
+    paid_at: Option<i64>,
+    approver: Option<Pubkey>,
+}
+
+impl VulnerabilityReport {
+    /// Owner the payout and boost destination accounts must belong to
+    pub fn payout_owner(&self) -> Pubkey {
+        self.payout_recipient.unwrap_or(self.researcher)
+    }
     
-    pub report: Account<'info, VulnerabilityReport>,
+    /// Severity safe to publish in events
+    pub fn event_severity(&self) -> EventSeverity {
+        if self.confidential {
+            EventSeverity::Unclassified
+        } else {
+            self.severity.into()
+        }
+    }
     
-    #[account(
-        init,
-        payer = researcher,
-        space = 8 + std::mem::size_of::<ReputationNFT>() + 256,
-        seeds = [REPUTATION_SEED.as_bytes(), report.researcher.as_ref(), report.key().as_ref()],
-        bump
-    )]
-    pub reputation_nft: Account<'info, ReputationNFT>,
+    /// Payout amount safe to publish in events
+    pub fn event_payout_amount(&self) -> Option<u64> {
+        if self.confidential {
+            None
+        } else {
+            Some(self.payout_amount)
+        }
+    }
     
-    pub system_program: Program<'info, System>,
+    /// Where closing the report sends its rent. Reports opened before rent_payer
+    /// was recorded were funded by their sponsor, or else by the researcher.
+    pub fn rent_refund_address(&self) -> Pubkey {
+        if self.rent_payer != Pubkey::default() {
+            self.rent_payer
+        } else {
+            self.sponsor.unwrap_or(self.researcher)
+        }
+    }
 }
 
-#[derive(Accounts)]
-pub struct FundVault<'info> {
-    #[account(mut)]
-    pub funder: Signer<'info>,
+/// String-free copy of a report's headline fields for bandwidth-constrained clients, kept in
+/// step by submit, approve, reject and payout when passed, and by sync_report_summary otherwise.
+/// The first four fields sit at the REPORT_*_OFFSET positions of a VulnerabilityReport
+#[account]
+pub struct ReportSummary {
+    pub vault: Pubkey,
+    pub researcher: Pubkey,
+    pub severity: SeverityTier,
+    pub status: ReportStatus,
+    pub payout_amount: u64,
+    pub updated_at: i64, // Last time the summary was written
+    pub report: Pubkey,
+    pub summary_bump: u8,
+}
+
+impl ReportSummary {
+    /// Copy the report's current state
+    pub fn sync(&mut self, report: &Account<VulnerabilityReport>, now: i64) {
+        self.vault = report.vault;
+        self.researcher = report.researcher;
+        self.severity = report.severity;
+        self.status = report.status;
+        self.payout_amount = report.payout_amount;
+        self.updated_at = now;
+        self.report = report.key();
+    }
+}
+
+#[account]
+pub struct ReputationNFT {
+    pub researcher: Pubkey,
+    pub vault: Pubkey,
+    pub report: Pubkey,
+    pub severity: SeverityTier,
+    pub project_name: String,
+    pub minted_at: i64,
+    pub disclosed_report_hash: Option<[u8; 32]>,
     
-    #[account(mut)]
-    pub vault: Account<'info, BugBountyVault>,
+    // Set when the underlying payout is found to be fraudulent; revoked badges count as nonexistent
+    pub revoked: bool,
+    pub revoked_reason_hash: [u8; 32],
     
-    /// CHECK: Funder token account
-    #[account(mut)]
-    pub funder_token_account: UncheckedAccount<'info>,
+    // Leaf in the platform merkle tree for compressed badges
+    pub leaf_index: Option<u64>,
     
-    /// CHECK: Vault token account
-    #[account(mut)]
-    pub vault_token_account: UncheckedAccount<'info>,
+    // Minted while the report was Approved rather than Paid
+    pub minted_at_approval: bool,
     
-    pub token_program: Program<'info, Token>,
+    // Account that paid the badge's rent
+    pub rent_payer: Pubkey,
 }
 
-#[derive(Accounts)]
-pub struct ToggleVaultStatus<'info> {
-    pub program_team: Signer<'info>,
+/// Frozen copy of a vault's metrics for one reporting period
+#[account]
+pub struct VaultSnapshot {
+    pub vault: Pubkey,
+    pub period_id: u32,
+    pub taken_at: i64,
+    pub snapshot_bump: u8,
     
-    #[account(mut, constraint = vault.program_team == program_team.key())]
-    pub vault: Account<'info, BugBountyVault>,
+    // Counters copied from the vault
+    pub total_funded: u64,
+    pub total_paid_out: u64,
+    pub total_withdrawn: u64,
+    pub committed_amount: u64,
+    pub insurance_reserve: u64,
+    pub total_reports: u64,
+    pub approved_reports: u64,
+    pub rejected_reports: u64,
+    pub withdrawn_reports: u64,
+    
+    // Actual vault token balance at snapshot time
+    pub token_balance: u64,
 }
 
-#[derive(Accounts)]
-pub struct UpdateRewardTiers<'info> {
-    pub program_team: Signer<'info>,
-    
-    #[account(mut, constraint = vault.program_team == program_team.key())]
-    pub vault: Account<'info, BugBountyVault>,
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct HallOfFameEntry {
+    pub researcher: Pubkey,
+    pub report: Pubkey,
+    pub note_hash: [u8; 32],
 }
 
-#[derive(Accounts)]
-#[instruction(force_delete: bool)]
-pub struct DeleteVault<'info> {
-    #[account(mut)]
-    pub program_team: Signer<'info>,
-    
-    #[account(
-        mut,
-        close = program_team,
-        constraint = vault.program_team == program_team.key() @ BugBountyError::UnauthorizedTeam,
-        seeds = [VAULT_SEED.as_bytes(), program_team.key().as_ref()],
-        bump = vault.vault_bump
-    )]
-    pub vault: Account<'info, BugBountyVault>,
-    
-    /// CHECK: Vault token account - tokens will be transferred out
-    #[account(mut)]
-    pub vault_token_account: UncheckedAccount<'info>,
+/// A whistleblower's report that the vault's governance key is compromised, one open per vault
+#[account]
+pub struct CompromiseAlert {
+    pub vault: Pubkey,
+    pub reporter: Pubkey,
+    pub evidence_hash: [u8; 32], // sha256 of the off-chain evidence
+    pub deposit_lamports: u64,   // Held on top of rent, forfeited to the team if the alert is false
+    pub raised_at: i64,
+    pub alert_bump: u8,
+}
+
+/// A team's public thanks for a paid report, one per report; frontends render the
+/// hall-of-thanks from these and leave out hidden ones
+#[account]
+pub struct Acknowledgment {
+    pub vault: Pubkey,
+    pub report: Pubkey,
+    pub researcher: Pubkey,
+    pub message_hash: [u8; 32],
+    pub display_name_override: Option<[u8; 32]>, // Zero-padded UTF-8 shown instead of the researcher
+    pub posted_at: i64,
+    pub hidden: bool, // Set by the researcher to stay unnamed
+    pub acknowledgment_bump: u8,
+}
+
+/// Researchers a vault's team chose to spotlight, at most one entry per report
+#[account]
+pub struct HallOfFame {
+    pub vault: Pubkey,
+    pub hall_bump: u8,
+    pub entry_count: u8,
+    pub entries: [HallOfFameEntry; HALL_OF_FAME_CAPACITY],
+}
+
+impl HallOfFame {
+    pub fn active_entries(&self) -> &[HallOfFameEntry] {
+        &self.entries[..self.entry_count as usize]
+    }
     
-    /// CHECK: Program team's token account to receive remaining funds
-    #[account(mut)]
-    pub program_team_token_account: UncheckedAccount<'info>,
+    pub fn add(&mut self, entry: HallOfFameEntry) -> Result<()> {
+        require!(
+            !self.active_entries().iter().any(|e| e.report == entry.report),
+            BugBountyError::AlreadyInHallOfFame
+        );
+        require!((self.entry_count as usize) < HALL_OF_FAME_CAPACITY, BugBountyError::HallOfFameFull);
+        
+        self.entries[self.entry_count as usize] = entry;
+        self.entry_count = self.entry_count.checked_add(1)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        Ok(())
+    }
     
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
+    /// Remove the entry for a report, keeping the remaining entries in order
+    pub fn remove(&mut self, report: &Pubkey) -> Result<HallOfFameEntry> {
+        let count = self.entry_count as usize;
+        let index = self.active_entries()
+            .iter()
+            .position(|e| e.report == *report)
+            .ok_or(BugBountyError::NotInHallOfFame)?;
+        
+        let removed = self.entries[index];
+        self.entries.copy_within(index + 1..count, index);
+        self.entries[count - 1] = HallOfFameEntry::default();
+        self.entry_count = self.entry_count.checked_sub(1)
+            .ok_or(BugBountyError::ArithmeticUnderflow)?;
+        Ok(removed)
+    }
 }
 
-// ============================================================================
-// ERROR HANDLING
-// ============================================================================
+#[account]
+pub struct SubmissionCounter {
+    pub vault: Pubkey,
+    pub researcher: Pubkey,
+    pub open_reports: u16,
+    pub counter_bump: u8,
+    pub nonce: u64,  // Next nonce a sponsored submission must be signed with
+    pub last_rejected_at: i64,  // Latest rejection of this researcher's reports (0 = never)
+}
 
-#[error_code]
-pub enum BugBountyError {
-    #[msg("Vault is inactive")]
-    VaultInactive,
-    
-    #[msg("Not authorized as governance authority")]
-    NotGovernanceAuthority,
+#[account]
+pub struct PlatformConfig {
+    pub admin: Pubkey,
+    pub config_bump: u8,
     
-    #[msg("Invalid report status for this operation")]
-    InvalidReportStatus,
+    // Swap programs claim_as may CPI into
+    pub swap_programs: [Pubkey; MAX_SWAP_PROGRAMS],
+    pub swap_program_count: u8,
     
-    #[msg("Report must be approved before payout")]
-    ReportNotApproved,
+    // Merkle tree compressed reputation badges are minted into; its Bubblegum
+    // tree delegate must be this config PDA (default = not configured)
+    pub reputation_tree: Pubkey,
     
-    #[msg("Report must be paid before NFT minting")]
-    ReportNotPaid,
+    // Vault registry pages opened so far; only the last one takes new vaults
+    pub registry_page_count: u32,
     
-    #[msg("Unauthorized researcher")]
-    UnauthorizedResearcher,
+    // Anti-squatting fee for researcher handles, paid to the treasury
+    pub treasury: Pubkey,
+    pub handle_fee_lamports: u64,
+}
+
+impl PlatformConfig {
+    pub fn is_whitelisted_swap_program(&self, program_id: &Pubkey) -> bool {
+        self.swap_programs[..self.swap_program_count as usize].contains(program_id)
+    }
+}
+
+/// Bubblegum `MetadataArgs`, mirrored so badges can be minted without the mpl-bubblegum crate
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BubblegumMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<u8>, // TokenStandard discriminant, 0 = NonFungible
+    pub collection: Option<BubblegumCollection>,
+    pub uses: Option<BubblegumUses>,
+    pub token_program_version: u8, // 0 = Original
+    pub creators: Vec<BubblegumCreator>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BubblegumCollection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BubblegumUses {
+    pub use_method: u8,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BubblegumCreator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+/// Researcher-level data shared across vaults
+#[account]
+pub struct ResearcherProfile {
+    pub researcher: Pubkey,
+    pub profile_bump: u8,
     
-    #[msg("Only program team can perform this action")]
-    UnauthorizedTeam,
+    // Handle PDA registered by this researcher, if any
+    pub handle: Option<Pubkey>,
     
-    #[msg("Arithmetic overflow")]
-    ArithmeticOverflow,
+    // Lifetime payout counters across vaults, credited by execute_payout when the profile is supplied
+    pub paid_reports: u32,
+    pub paid_critical_reports: u32,
+    pub total_earned: u64, // Whole tokens of the paying vaults' primary mints
     
-    #[msg("Vault must be inactive before deletion")]
-    VaultMustBeInactive,
+    // Latest external attestation, copied for display (0/0 = none or revoked)
+    pub external_source: u8,
+    pub external_score: u32,
+}
+
+impl ResearcherProfile {
+    /// Credit a paid report and the whole tokens it earned; informational reports pay nothing
+    /// and don't count
+    pub fn record_payout(&mut self, severity: SeverityTier, payout_amount: u64, earned_tokens: u64) -> Result<()> {
+        if payout_amount == 0 {
+            return Ok(());
+        }
+        self.paid_reports = self.paid_reports.checked_add(1).ok_or(BugBountyError::ArithmeticOverflow)?;
+        if severity == SeverityTier::Critical {
+            self.paid_critical_reports = self.paid_critical_reports.checked_add(1)
+                .ok_or(BugBountyError::ArithmeticOverflow)?;
+        }
+        self.total_earned = self.total_earned.checked_add(earned_tokens)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        Ok(())
+    }
     
-    #[msg("Cannot delete vault with pending reports. Use force_delete to override")]
-    HasPendingReports,
+    pub fn progress(&self, metric: MilestoneMetric) -> u64 {
+        match metric {
+            MilestoneMetric::PaidReports => self.paid_reports as u64,
+            MilestoneMetric::PaidCriticalReports => self.paid_critical_reports as u64,
+            MilestoneMetric::TotalEarned => self.total_earned,
+        }
+    }
+}
+
+/// Profile counter a milestone is measured against
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MilestoneMetric {
+    PaidReports,
+    PaidCriticalReports,
+    TotalEarned,
+}
+
+#[derive(Clone, Copy)]
+pub struct MilestoneDefinition {
+    pub metric: MilestoneMetric,
+    pub threshold: u64,
+}
+
+/// Third-party reward on top of a vault's bounty, paid out of its own escrow to matching reports
+#[account]
+pub struct BountyBoost {
+    pub vault: Pubkey,
+    pub sponsor: Pubkey,
+    pub boost_id: u64,
+    pub mint: Pubkey,
+    pub escrow: Pubkey,
+    pub severity_mask: u8, // Bit per SeverityTier, as in paused_tiers
+    pub max_per_report: u64, // 0 = the whole remaining balance goes to the first match
+    pub expires_at: i64,
+    pub total_deposited: u64,
+    pub total_paid: u64,
+    pub boost_bump: u8,
+}
+
+/// Structured team response to a rejected report, at most one per report.
+/// Fixed layout, so decision_code sits at byte 72 for memcmp filters (unlike the report's
+/// copy, whose offset moves with the report's optional fields).
+#[account]
+pub struct ResponsePacket {
+    pub report: Pubkey,
+    pub vault: Pubkey,
+    pub decision_code: DecisionCode,
+    pub details_ipfs_hash: [u8; 32],
+    pub decided_by: Pubkey,
+    pub decided_at: i64,
+    pub packet_bump: u8,
+}
+
+/// Achievement badge for crossing a profile milestone; one per researcher and milestone id
+#[account]
+pub struct MilestoneBadge {
+    pub researcher: Pubkey,
+    pub milestone_id: u8,
+    pub badge_bump: u8,
+    pub claimed_at: i64,
+    pub rent_payer: Pubkey,
+}
+
+/// Unique lowercase handle pointing at a researcher; the seed is the handle itself
+#[account]
+pub struct Handle {
+    pub researcher: Pubkey,
+    pub handle_bump: u8,
+    pub registered_at: i64,
+    pub name: String,
+}
+
+/// Reward tiers as set by one update_reward_tiers call; version n is the vault's nth update
+#[account]
+pub struct PolicyVersion {
+    pub vault: Pubkey,
+    pub version: u32,
+    pub rewards: [u64; 4],          // Base units: critical, high, medium, low
+    pub previous_rewards: [u64; 4], // Tiers this version replaced, i.e. version - 1
+    pub tier_mints: [Pubkey; 4],
+    pub updated_by: Pubkey,
+    pub updated_at: i64,
+    pub policy_bump: u8,
+}
+
+/// Governance's standing approval of a researcher's low-stakes reports in one vault
+#[account]
+pub struct TrustedResearcher {
+    pub vault: Pubkey,
+    pub researcher: Pubkey,
+    pub auto_approve_max_severity: SeverityTier, // Most severe tier approved on submission
+    pub auto_approve_max_amount: u64,            // Base units
+    pub granted_by: Pubkey,
+    pub granted_at: i64,
+    pub trusted_bump: u8,
+}
+
+/// A researcher watching a vault; notification services fan vault events out to these
+#[account]
+pub struct Subscription {
+    pub vault: Pubkey,
+    pub researcher: Pubkey,
+    pub subscribed_at: i64,
+    pub subscription_bump: u8,
+}
+
+/// A key the payout recipient lets crank payouts on its behalf, e.g. a security firm member
+/// whose reports pay into the firm's multisig; registered once by the recipient itself
+#[account]
+pub struct FirmMember {
+    pub recipient: Pubkey,
+    pub member: Pubkey,
+    pub registered_at: i64,
+    pub rent_payer: Pubkey,
+    pub firm_member_bump: u8,
+}
+
+/// The platform admin's attestation of a researcher's track record on another platform.
+/// The score is in paid-report equivalents so vault gates can weigh it against on-chain history
+#[account]
+pub struct ExternalAttestation {
+    pub researcher: Pubkey,
+    pub source: u8, // Platform the record comes from, enumerated off-chain
+    pub score: u32,
+    pub expires_at: i64,
+    pub issued_by: Pubkey,
+    pub issued_at: i64,
+    pub attestation_bump: u8,
+}
+
+/// Tokens the governance authority staked against approving fraudulent reports; the platform
+/// admin can slash them back into the vault
+#[account]
+pub struct GovernanceBond {
+    pub vault: Pubkey,
+    pub governance_authority: Pubkey,
+    pub escrow: Pubkey,
+    pub posted_at: i64,
+    pub withdrawable_at: i64, // 0 until governance asks for the bond back
+    pub bond_bump: u8,
+}
+
+impl ExternalAttestation {
+    pub fn is_valid(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+}
+
+/// Delegated permissions for a vault member alongside the legacy team/governance keys
+#[account]
+pub struct Role {
+    pub vault: Pubkey,
+    pub member: Pubkey,
+    pub permissions: u16,
+    pub role_bump: u8,
+    
+    // Spend caps on the member's approvals, set by governance (0 = uncapped);
+    // spent counts at approval and is refunded if the approval expires
+    pub max_total_payout: u64,
+    pub max_single_payout: u64,
+    pub spent: u64,
+}
+
+impl Role {
+    pub fn has(&self, permission: u16) -> bool {
+        self.permissions & permission == permission
+    }
+    
+    /// Count an approval against the caps, failing if it would exceed either
+    pub fn charge_payout(&mut self, amount: u64) -> Result<()> {
+        let spent = self.spent.checked_add(amount).ok_or(BugBountyError::ArithmeticOverflow)?;
+        require!(
+            (self.max_single_payout == 0 || amount <= self.max_single_payout)
+                && (self.max_total_payout == 0 || spent <= self.max_total_payout),
+            BugBountyError::DelegationCapExceeded
+        );
+        self.spent = spent;
+        Ok(())
+    }
+    
+    /// Give back an approval that never reached payout
+    pub fn refund_payout(&mut self, amount: u64) {
+        self.spent = self.spent.saturating_sub(amount);
+    }
+}
+
+#[zero_copy]
+pub struct AuditEntry {
+    pub actor: Pubkey,
+    pub data: u64,
+    pub at: i64,
+    pub action: u8,
+    pub _padding: [u8; 7],
+}
+
+#[zero_copy]
+pub struct RegistryEntry {
+    pub vault: Pubkey,
+    pub listed: u8,
+    pub verified: u8,
+    pub _padding: [u8; 6],
+}
+
+/// One page of the platform vault registry; pages fill in order and a new one can
+/// only be opened once the last is full
+#[account(zero_copy)]
+pub struct VaultRegistryPage {
+    pub page_index: u32,
+    pub count: u32,
+    pub page_bump: u8,
+    pub _padding: [u8; 7],
+    pub entries: [RegistryEntry; REGISTRY_PAGE_CAPACITY],
+}
+
+impl VaultRegistryPage {
+    pub fn is_full(&self) -> bool {
+        self.count as usize >= REGISTRY_PAGE_CAPACITY
+    }
+    
+    pub fn append(&mut self, vault: Pubkey) -> Result<()> {
+        require!(!self.is_full(), BugBountyError::RegistryPageFull);
+        
+        self.entries[self.count as usize] = RegistryEntry {
+            vault,
+            listed: 1,
+            verified: 0,
+            _padding: [0; 6],
+        };
+        self.count = self.count.checked_add(1)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        Ok(())
+    }
+    
+    pub fn entry_mut(&mut self, vault: &Pubkey) -> Result<&mut RegistryEntry> {
+        let count = self.count as usize;
+        self.entries[..count]
+            .iter_mut()
+            .find(|e| e.vault == *vault)
+            .ok_or(error!(BugBountyError::VaultNotInRegistry))
+    }
+}
+
+/// Fixed-size ring buffer of privileged actions taken on a vault
+/// `head` only ever grows, so readers detect wrap-around by comparing it to the capacity
+#[account(zero_copy)]
+pub struct AuditLog {
+    pub vault: Pubkey,
+    pub head: u64,
+    pub log_bump: u8,
+    pub _padding: [u8; 7],
+    pub entries: [AuditEntry; AUDIT_LOG_CAPACITY],
+}
+
+impl AuditLog {
+    pub fn append(&mut self, action: u8, actor: Pubkey, data: u64, at: i64) -> Result<()> {
+        let slot = (self.head % AUDIT_LOG_CAPACITY as u64) as usize;
+        self.entries[slot] = AuditEntry {
+            actor,
+            data,
+            at,
+            action,
+            _padding: [0; 7],
+        };
+        self.head = self.head.checked_add(1)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// EVENTS
+// ============================================================================
+
+#[event]
+pub struct ReportSubmitted {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub researcher: Option<Pubkey>,
+    pub severity: EventSeverity,
+    pub payout_amount: Option<u64>,
+    pub submitted_at: i64,
+}
+
+#[event]
+pub struct FeatureFlagsUpdated {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub feature_flags: u64,
+}
+
+#[event]
+pub struct RentSubsidyFunded {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub rent_subsidy_budget: u64,
+}
+
+#[event]
+pub struct GovernanceBondUpdated {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub governance_authority: Pubkey,
+    pub governance_bond_amount: u64,
+    pub withdrawable_at: i64,
+}
+
+#[event]
+pub struct GovernanceBondSlashed {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub slashed: u64,
+    pub governance_bond_amount: u64,
+    pub finding_hash: [u8; 32], // Arbitration finding behind the slash
+}
+
+#[event]
+pub struct ExternalAttestationUpdated {
+    pub researcher: Pubkey,
+    pub source: u8,
+    pub score: u32,
+    pub expires_at: i64,
+    pub revoked: bool,
+}
+
+#[event]
+pub struct LamportsSwept {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub amount: u64,
+    pub destination: Pubkey,
+    // Swept into the wSOL vault token account and counted in total_funded
+    pub into_reward_pool: bool,
+    pub total_funded: u64,
+}
+
+#[event]
+pub struct ReportAcknowledged {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub acknowledged_at: i64,
+    pub response_seconds: u64,
+}
+
+#[event]
+pub struct ReportApproved {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub severity: EventSeverity,
+    pub payout_amount: Option<u64>,
+    pub approved_at: i64,
+    pub reason_hash: [u8; 32],
+    pub auto_approved: bool,
+}
+
+#[event]
+pub struct ReportRejected {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub reason_hash: [u8; 32],
+    pub reason_template: Option<u8>,
+    pub is_spam: bool,
+    pub rejected_at: i64,
+    pub rejected_reports: u64,
+    pub decision_code: Option<DecisionCode>,
+}
+
+#[event]
+pub struct ReportWithdrawn {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub withdrawn_reports: u64,
+}
+
+#[event]
+pub struct ReportClosed {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub rent_payer: Pubkey,
+    pub lamports: u64,
+}
+
+#[event]
+pub struct ReportPublished {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub public_ipfs_hash: [u8; 32],
+    pub disclosed_at: i64,
+    pub early: bool,
+}
+
+#[event]
+pub struct ReportDeclassified {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub researcher: Pubkey,
+    pub severity: SeverityTier,
+    pub status: ReportStatus,
+    pub payout_amount: u64,
+}
+
+/// Amounts are zeroed for confidential reports and published by ReportDeclassified
+#[event]
+pub struct PayoutReceipt {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub researcher: Pubkey,
+    pub researcher_amount: u64,
+    pub donation_recipient: Option<Pubkey>,
+    pub donation_amount: u64,
+    pub paid_at: i64,
+    pub confidential: bool,
+    pub mint: Pubkey,
+    pub invoice_hash: [u8; 32],
+    pub token_account_generation: u16, // Primary token account generation at payout time
+    pub gross_amount: u64,
+    pub retained_dust_amount: u64, // Researcher, donation and retained legs sum to gross_amount
+}
+
+#[event]
+pub struct RewardMintChanged {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub previous_mint: Pubkey,
+    pub new_mint: Pubkey,
+    pub new_vault_token_account: Pubkey,
+    pub changed_at: i64,
+}
+
+#[event]
+pub struct VaultOwnershipVerified {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub target_program: Pubkey,
+    pub verified_at: i64,
+}
+
+#[event]
+pub struct VaultVerificationCleared {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub target_program: Pubkey,
+    pub challenger: Pubkey,
+}
+
+#[event]
+pub struct RewardTiersUpdated {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub rewards: [u64; 4], // Base units: critical, high, medium, low
+    pub reward_decimals: u8,
+    pub whole_token_rewards: [u64; 4], // Rounded down to whole tokens
+    pub subscriber_count: u32,
+    pub policy_version: u32,
+}
+
+#[event]
+pub struct TierPauseUpdated {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub tier: SeverityTier,
+    pub paused: bool,
+    pub paused_tiers: u8,
+    pub subscriber_count: u32,
+}
+
+#[event]
+pub struct WindDownBegun {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub triage_deadline: i64,
+    pub unresolved_reports: u64,
+}
+
+#[event]
+pub struct WindDownFinalized {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub swept_amount: u64,
+    pub finalized_at: i64,
+}
+
+#[event]
+pub struct PocAttached {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub poc: Option<PocReference>, // Withheld for confidential reports
+}
+
+#[event]
+pub struct ReportLinked {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub linked_report: Pubkey,
+    pub linked_vault: Pubkey,
+}
+
+#[event]
+pub struct InvoiceAttached {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub invoice_hash: [u8; 32],
+    pub attached_by: Pubkey,
+}
+
+#[event]
+pub struct ReportRemediated {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub remediation_ref: [u8; 32],
+    pub remediated_at: i64,
+}
+
+#[event]
+pub struct ReportCoApproved {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub program_team: Pubkey,
+}
+
+#[event]
+pub struct ApprovalExpired {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub released_amount: Option<u64>,
+    pub expired_at: i64,
+}
+
+#[event]
+pub struct ReportEscalated {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub escalated_at: i64,
+}
+
+#[event]
+pub struct ArbitrationResolved {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub outcome: ArbitrationOutcome,
+    pub payout_amount: Option<u64>,
+    pub arbitrated_at: i64,
+}
+
+#[event]
+pub struct SeverityDisputed {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub approved_severity: EventSeverity,
+    pub claimed_severity: EventSeverity,
+    pub evidence_hash: [u8; 32],
+}
+
+#[event]
+pub struct SeverityDisputeResolved {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub final_severity: EventSeverity,
+    pub payout_amount: Option<u64>,
+    pub resolved_at: i64,
+}
+
+#[event]
+pub struct TrustedResearcherUpdated {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub researcher: Pubkey,
+    pub trusted: bool,
+    pub auto_approve_max_severity: SeverityTier,
+    pub auto_approve_max_amount: u64,
+}
+
+#[event]
+pub struct AutoApprovalRevoked {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub revoked_by: Pubkey,
+    pub reason_hash: [u8; 32],
+}
+
+#[event]
+pub struct FirmMemberUpdated {
+    pub recipient: Pubkey,
+    pub member: Pubkey,
+    pub registered: bool,
+}
+
+#[event]
+pub struct ReputationRevoked {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub reputation_nft: Pubkey,
+    pub report: Pubkey,
+    pub researcher: Pubkey,
+    pub reason_hash: [u8; 32],
+    pub revoked_at: i64,
+}
+
+#[event]
+pub struct ReputationRestored {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub reputation_nft: Pubkey,
+    pub report: Pubkey,
+    pub researcher: Pubkey,
+    pub restored_at: i64,
+}
+
+#[event]
+pub struct EscrowClaimed {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub researcher: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub output_mint: Pubkey,
+}
+
+#[event]
+pub struct BountyBoostCreated {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub boost: Pubkey,
+    pub sponsor: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub severity_mask: u8,
+    pub max_per_report: u64,
+    pub expires_at: i64,
+    pub subscriber_count: u32,
+}
+
+#[event]
+pub struct BountyBoostPaid {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub boost: Pubkey,
+    pub report: Pubkey,
+    pub amount: u64, // 0 for confidential reports
+}
+
+#[event]
+pub struct BountyBoostRefunded {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub boost: Pubkey,
+    pub sponsor: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ClaimEscrowClosed {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub researcher: Pubkey,
+    pub lamports: u64,
+}
+
+#[event]
+pub struct PriorityStakeSettled {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub researcher: Pubkey,
+    pub amount: u64,
+    pub forfeited: bool,
+}
+
+#[event]
+pub struct WithdrawalRequested {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub amount: u64,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct WithdrawalExecuted {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct WithdrawalCancelled {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct GovernanceRecoveryInitiated {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub current_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct GovernanceRecoveryCancelled {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct GovernanceRecoveryFinalized {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct VaultAutoPaused {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub recent_spam_count: u32,
+    pub spam_window_start: i64,
+    pub subscriber_count: u32,
+}
+
+#[event]
+pub struct PayoutCircuitBreakerTripped {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,                // The payout that was refused
+    pub payout_amount: u64,
+    pub hourly_payouts: u32,           // Including the refused payout
+    pub hourly_outflow: u64,           // Including the refused payout
+    pub max_payouts_per_hour: u32,
+    pub max_hourly_outflow: u64,
+    pub tripped_at: i64,
+    pub subscriber_count: u32,
+}
+
+#[event]
+pub struct PayoutCircuitBreakerReset {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub reset_by: Pubkey,
+}
+
+#[event]
+pub struct GovernanceCompromiseReported {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub alert: Pubkey,
+    pub reporter: Pubkey,
+    pub governance_authority: Pubkey, // The key reported as compromised
+    pub evidence_hash: [u8; 32],
+    pub raised_at: i64,
+    pub subscriber_count: u32,
+}
+
+#[event]
+pub struct CompromiseAlertResolved {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub alert: Pubkey,
+    pub reporter: Pubkey,
+    pub valid: bool,
+    pub deposit_returned: bool,
+    pub resolved_by: Pubkey,
+}
+
+#[event]
+pub struct VaultSnapshotTaken {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub period_id: u32,
+    pub taken_at: i64,
+}
+
+#[event]
+pub struct HallOfFameEntryAdded {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub researcher: Pubkey,
+    pub report: Pubkey,
+    pub note_hash: [u8; 32],
+}
+
+#[event]
+pub struct AcknowledgmentPosted {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+    pub researcher: Pubkey,
+    pub message_hash: [u8; 32],
+    pub display_name_override: Option<[u8; 32]>,
+}
+
+#[event]
+pub struct AcknowledgmentHidden {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub report: Pubkey,
+}
+
+#[event]
+pub struct HallOfFameEntryRemoved {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub researcher: Pubkey,
+    pub report: Pubkey,
+}
+
+#[event]
+pub struct VaultRegistryUpdated {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub page_index: u32,
+    pub listed: bool,
+    pub verified: bool,
+}
+
+#[event]
+pub struct MilestoneClaimed {
+    pub researcher: Pubkey,
+    pub milestone_id: u8,
+    pub metric: MilestoneMetric,
+    pub threshold: u64,
+}
+
+#[event]
+pub struct HandleRegistered {
+    pub researcher: Pubkey,
+    pub handle: Pubkey,
+    pub name: String,
+}
+
+#[event]
+pub struct HandleReleased {
+    pub researcher: Pubkey,
+    pub handle: Pubkey,
+    pub name: String,
+    pub reclaimed: bool,
+}
+
+#[event]
+pub struct VaultSubscriptionChanged {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub researcher: Pubkey,
+    pub subscribed: bool,
+    pub subscriber_count: u32,
+}
+
+#[event]
+pub struct DonationDetected {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub delta: u64,
+    pub total_funded: u64,
+    // Direct transfers bypass fund_vault, so they can push the balance past the cap
+    pub over_cap: bool,
+}
+
+#[event]
+pub struct RoleUpdated {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub member: Pubkey,
+    pub permissions: u16,
+}
+
+#[event]
+pub struct DelegationCapsUpdated {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub member: Pubkey,
+    pub max_total_payout: u64,
+    pub max_single_payout: u64,
+    pub spent: u64,
+}
+
+#[event]
+pub struct AuditLogSkipped {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    pub action: u8,
+    pub actor: Pubkey,
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+/// Unix time as the vault sees it: the cluster clock, or the vault's pinned clock in
+/// test-clock builds. Every vault-scoped time read goes through here
+fn current_time(vault: &BugBountyVault) -> Result<i64> {
+    if cfg!(feature = "test-clock") && vault.clock_override != 0 {
+        return Ok(vault.clock_override);
+    }
+    Ok(Clock::get()?.unix_timestamp)
+}
+
+/// Basis-point share of an amount, rounded down
+fn bps_of(amount: u64, bps: u16) -> Result<u64> {
+    let share = (amount as u128)
+        .checked_mul(bps as u128)
+        .and_then(|v| v.checked_div(BPS_DENOMINATOR as u128))
+        .ok_or(BugBountyError::ArithmeticOverflow)?;
+    Ok(share as u64)
+}
+
+/// Leaf metadata for a compressed badge: the project name (cut to Bubblegum's 32-byte
+/// limit) plus a URI encoding the vault, report and severity
+fn compressed_badge_metadata(
+    project_name: &str,
+    vault: Pubkey,
+    report: Pubkey,
+    severity: SeverityTier,
+) -> BubblegumMetadataArgs {
+    let mut name_len = project_name.len().min(BUBBLEGUM_MAX_NAME_LEN);
+    while !project_name.is_char_boundary(name_len) {
+        name_len -= 1;
+    }
+    
+    BubblegumMetadataArgs {
+        name: project_name[..name_len].to_string(),
+        symbol: BADGE_SYMBOL.to_string(),
+        uri: format!("bounty://{}/{}?severity={:?}", vault, report, severity),
+        seller_fee_basis_points: 0,
+        primary_sale_happened: false,
+        is_mutable: false,
+        edition_nonce: None,
+        token_standard: Some(0),
+        collection: None,
+        uses: None,
+        token_program_version: 0,
+        creators: Vec::new(),
+    }
+}
+
+/// Re-price a primary-mint report submitted before the last change_reward_mint in the
+/// current tiers, so its payout is denominated in the token it will be paid in
+fn reprice_for_current_mint(vault: &BugBountyVault, report: &mut VulnerabilityReport) -> Result<()> {
+    if report.payout_mint == Pubkey::default() && report.mint_generation != vault.previous_mint_count {
+        report.payout_amount = scaled_reward(vault, report)?;
+        report.mint_generation = vault.previous_mint_count;
+    }
+    Ok(())
+}
+
+/// Payout for a report being approved out of Pending, and the policy that set it. Prices in
+/// another mint than the one the report was submitted in aren't comparable, so a report whose
+/// tier mint has since changed keeps its submitted amount
+fn approval_price(vault: &BugBountyVault, report: &VulnerabilityReport) -> Result<(u64, PricingPolicy)> {
+    let policy = if report.payout_mint == vault.tier_mint_for(report.severity) {
+        vault.pricing_policy
+    } else {
+        PricingPolicy::AtSubmission
+    };
+    Ok((policy.price(report.payout_amount, scaled_reward(vault, report)?), policy))
+}
+
+/// Lift a report's legal hold at `now`, returning how long it was held. An approval's claim
+/// window doesn't run while held, so the approval moves forward by the held time
+fn release_hold(report: &mut VulnerabilityReport, now: i64) -> Result<i64> {
+    let held_at = report.held_at.ok_or(BugBountyError::ReportNotOnHold)?;
+    let held_for = now.checked_sub(held_at)
+        .ok_or(BugBountyError::ArithmeticUnderflow)?;
+    
+    report.total_held_seconds = report.total_held_seconds.checked_add(held_for)
+        .ok_or(BugBountyError::ArithmeticOverflow)?;
+    if report.status == ReportStatus::Approved {
+        let approved_at = report.approved_at.ok_or(BugBountyError::ReportNotApproved)?;
+        report.approved_at = Some(approved_at.checked_add(held_for).ok_or(BugBountyError::ArithmeticOverflow)?);
+    }
+    report.on_hold = false;
+    report.held_at = None;
+    Ok(held_for)
+}
+
+/// When an approved report's claim window closes
+fn claim_expires_at(vault: &BugBountyVault, report: &VulnerabilityReport) -> Result<i64> {
+    let approved_at = report.approved_at.ok_or(BugBountyError::ReportNotApproved)?;
+    let expires_at = approved_at.checked_add(vault.claim_window_seconds)
+        .ok_or(BugBountyError::ArithmeticOverflow)?;
+    Ok(expires_at)
+}
+
+/// Move a disputed report to final_severity and re-price it. Upholding the approved tier keeps
+/// the approved payout; a new tier was never priced for this report, so approval_price sees its
+/// current reward as the submitted amount. A tier paid in another mint can't take over the report
+fn reprice_disputed_report(
+    vault: &BugBountyVault,
+    report: &mut VulnerabilityReport,
+    final_severity: SeverityTier,
+) -> Result<()> {
+    if report.approved_severity == Some(final_severity) {
+        report.severity = final_severity;
+        return Ok(());
+    }
+    require!(
+        vault.tier_mint_for(final_severity) == report.payout_mint,
+        BugBountyError::DisputeTierMintMismatch
+    );
+    
+    report.severity = final_severity;
+    report.payout_amount = scaled_reward(vault, report)?;
+    let (payout_amount, policy) = approval_price(vault, report)?;
+    report.payout_amount = payout_amount;
+    report.priced_by = Some(policy);
+    Ok(())
+}
+
+/// Tier reward for a report, scaled by the decay multiplier it was submitted under
+fn scaled_reward(vault: &BugBountyVault, report: &VulnerabilityReport) -> Result<u64> {
+    let reward = report.severity.reward_for(vault);
+    match report.reward_multiplier_bps {
+        0 => Ok(reward),
+        multiplier_bps => bps_of(reward, multiplier_bps),
+    }
+}
+
+/// Paid tiers must pay something; only Informational reports are worth zero
+fn require_nonzero_rewards(rewards: [u64; 4]) -> Result<()> {
+    require!(rewards.iter().all(|&reward| reward > 0), BugBountyError::ZeroAmount);
+    Ok(())
+}
+
+/// Tier amounts must be whole multiples of the vault's granularity (0 allows any amount)
+fn require_granular(granularity: u64, rewards: [u64; 4]) -> Result<()> {
+    require!(
+        granularity == 0 || rewards.iter().all(|&reward| reward % granularity == 0),
+        BugBountyError::GranularityViolation
+    );
+    Ok(())
+}
+
+/// A caller-supplied deadline must lie between now and MAX_TIMESTAMP_HORIZON_SECONDS ahead
+fn validate_timestamp(timestamp: i64, now: i64) -> Result<()> {
+    let horizon = now
+        .checked_add(MAX_TIMESTAMP_HORIZON_SECONDS)
+        .ok_or(BugBountyError::InvalidTimestamp)?;
+    require!(
+        timestamp >= now && timestamp <= horizon,
+        BugBountyError::InvalidTimestamp
+    );
+    Ok(())
+}
+
+/// A caller-supplied duration must fit within MAX_TIMESTAMP_HORIZON_SECONDS
+fn validate_duration(seconds: i64) -> Result<()> {
+    require!(
+        (0..=MAX_TIMESTAMP_HORIZON_SECONDS).contains(&seconds),
+        BugBountyError::InvalidTimestamp
+    );
+    Ok(())
+}
+
+/// An amount in whole tokens of a mint with the given decimals, rounded down
+fn whole_tokens(amount: u64, decimals: u8) -> u64 {
+    amount / 10u64.checked_pow(decimals as u32).unwrap_or(u64::MAX)
+}
+
+/// Tier amounts in whole tokens of a mint with the given decimals, rounded down
+fn whole_token_amounts(rewards: [u64; 4], decimals: u8) -> [u64; 4] {
+    rewards.map(|reward| whole_tokens(reward, decimals))
+}
+
+/// Fill in a freshly created vault; shared by create_bounty_vault and create_and_fund_vault
+fn init_vault(
+    vault: &mut Account<BugBountyVault>,
+    program_team: Pubkey,
+    governance_authority: Pubkey,
+    vault_bump: u8,
+    vault_token_account: Pubkey,
+    rewards: [u64; 4],
+    initial_funding: u64,
+    reward_token_mint: Option<Pubkey>,
+    reward_decimals: u8,
+    allow_same_authority: bool,
+    arbitration_enabled: bool,
+    governance_recovery_delay_seconds: i64,
+    pricing_policy: PricingPolicy,
+    vault_registry: Option<&AccountLoader<VaultRegistryPage>>,
+) -> Result<()> {
+    require!(
+        allow_same_authority || governance_authority != program_team,
+        BugBountyError::AuthoritiesMustDiffer
+    );
+    require_nonzero_rewards(rewards)?;
+    require!(
+        governance_recovery_delay_seconds == 0
+            || governance_recovery_delay_seconds >= MIN_GOVERNANCE_RECOVERY_DELAY_SECONDS,
+        BugBountyError::InvalidRecoveryDelay
+    );
+    validate_duration(governance_recovery_delay_seconds)?;
+    
+    vault.program_team = program_team;
+    vault.governance_authority = governance_authority;
+    vault.vault_bump = vault_bump;
+    vault.vault_token_account = vault_token_account;
+    
+    [vault.critical_reward, vault.high_reward, vault.medium_reward, vault.low_reward] = rewards;
+    
+    vault.total_funded = initial_funding;
+    vault.total_paid_out = 0;
+    vault.total_reports = 0;
+    vault.approved_reports = 0;
+    vault.rejected_reports = 0;
+    vault.withdrawn_reports = 0;
+    
+    vault.reward_token_mint = reward_token_mint;
+    vault.reward_decimals = reward_decimals;
+    vault.reward_granularity = 0;
+    vault.vault_active = true;
+    vault.created_at = current_time(vault)?;
+    vault.allow_same_authority = allow_same_authority;
+    vault.submissions_open = true;
+    vault.claim_window_seconds = DEFAULT_CLAIM_WINDOW_SECONDS;
+    vault.arbitration_enabled = arbitration_enabled;
+    vault.team_mints_reputation = false;
+    vault.project_name = String::new();
+    vault.compressed_reputation = false;
+    vault.allow_self_approval = false;
+    vault.dual_approval_threshold = 0;
+    vault.governance_recovery_delay_seconds = governance_recovery_delay_seconds;
+    vault.pending_governance_authority = None;
+    vault.governance_recovery_executable_at = 0;
+    vault.max_vault_balance = 0;
+    vault.vault_authority = vault.key();
+    vault.pricing_policy = pricing_policy;
+    vault.max_payout_fraction_bps = BPS_DENOMINATOR as u16;
+    vault.dust_policy = DustPolicy::Researcher;
+    
+    // Listing is optional; unlisted vaults work the same but aren't discoverable
+    if let Some(vault_registry) = vault_registry {
+        let mut page = vault_registry.load_mut()?;
+        page.append(vault.key())?;
+        
+        emit!(VaultRegistryUpdated {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            page_index: page.page_index,
+            listed: true,
+            verified: false,
+        });
+    }
+    
+    Ok(())
+}
+
+/// Whether a report's payout is large enough to need the program team's co-approval
+fn requires_co_approval(vault: &BugBountyVault, report: &VulnerabilityReport) -> bool {
+    vault.dual_approval_threshold > 0 && report.payout_amount >= vault.dual_approval_threshold
+}
+
+/// Payout math shared by execute_payout and preview_payout so the two can't diverge
+fn payout_breakdown(vault: &BugBountyVault, report: &VulnerabilityReport) -> Result<PayoutBreakdown> {
+    let payout_amount = report.payout_amount;
+    
+    // Every leg is its floored share; the remainder is assigned by the vault's dust policy
+    let donation_amount = bps_of(payout_amount, report.donation_bps)?;
+    let researcher_bps = (BPS_DENOMINATOR as u16).checked_sub(report.donation_bps)
+        .ok_or(BugBountyError::InvalidDonationBps)?;
+    let researcher_share = bps_of(payout_amount, researcher_bps)?;
+    let dust = payout_amount
+        .checked_sub(donation_amount)
+        .and_then(|v| v.checked_sub(researcher_share))
+        .ok_or(BugBountyError::ArithmeticUnderflow)?;
+    let (researcher_amount, retained_dust_amount) = match vault.dust_policy {
+        DustPolicy::Researcher => (researcher_share + dust, 0),
+        DustPolicy::Vault => (researcher_share, dust),
+    };
+    
+    let co_approval_required = requires_co_approval(vault, report);
+    Ok(PayoutBreakdown {
+        payout_amount,
+        payout_mint: report.payout_mint,
+        researcher_amount,
+        donation_amount,
+        donation_recipient: report.donation_recipient,
+        retained_dust_amount,
+        co_approval_required,
+        payable: report.status == ReportStatus::Approved
+            && !report.on_hold
+            && (!co_approval_required || report.team_co_approved),
+    })
+}
+
+/// Handles are 1-32 characters of a-z, 0-9 and underscore
+fn validate_handle(name: &str) -> Result<()> {
+    require!(
+        !name.is_empty()
+            && name.len() <= MAX_HANDLE_LEN
+            && name.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_'),
+        BugBountyError::InvalidHandle
+    );
+    Ok(())
+}
+
+/// Free the researcher's open-report slot held by a report reaching a terminal status.
+/// Reports submitted without a counter never held a slot, so this only fails when a
+/// counted report is missing its counter.
+fn release_open_report(
+    report: &mut VulnerabilityReport,
+    submission_counter: Option<&mut Account<SubmissionCounter>>,
+) -> Result<()> {
+    if !report.counted_open {
+        return Ok(());
+    }
+    
+    let counter = submission_counter.ok_or(BugBountyError::SubmissionCounterRequired)?;
+    require!(
+        counter.vault == report.vault && counter.researcher == report.researcher,
+        BugBountyError::SubmissionCounterMismatch
+    );
+    
+    counter.open_reports = counter.open_reports.checked_sub(1)
+        .ok_or(BugBountyError::ArithmeticUnderflow)?;
+    report.counted_open = false;
+    
+    Ok(())
+}
+
+/// Deserialize an SPL token account handed in as an unchecked account
+fn load_token_account(info: &AccountInfo) -> Result<TokenAccount> {
+    require_keys_eq!(*info.owner, token::ID, BugBountyError::InvalidTokenAccount);
+    TokenAccount::try_deserialize(&mut &info.data.borrow()[..])
+}
+
+/// Payouts are cranked by the report's researcher, or by a member the payout recipient registered
+fn check_payout_signer(signer: Pubkey, report: &VulnerabilityReport, firm_member: Option<&FirmMember>) -> Result<()> {
+    if signer == report.researcher {
+        return Ok(());
+    }
+    let member = firm_member.ok_or(BugBountyError::UnauthorizedResearcher)?;
+    require!(
+        member.member == signer && Some(member.recipient) == report.payout_recipient,
+        BugBountyError::UnauthorizedResearcher
+    );
+    Ok(())
+}
+
+/// The "has a bug bounty" check other programs run through assert_active_vault: the vault is
+/// live, carries the ownership badge for target_program and has min_balance unreserved
+fn check_active_vault(vault: &BugBountyVault, target_program: Pubkey, min_balance: u64) -> Result<()> {
+    require!(
+        vault.vault_active && vault.wind_down_phase == WindDownPhase::Live,
+        BugBountyError::VaultInactive
+    );
+    require!(vault.verified_program == Some(target_program), BugBountyError::VaultNotVerifiedForProgram);
+    require!(vault.available_balance()? >= min_balance, BugBountyError::InsufficientVaultFunds);
+    Ok(())
+}
+
+/// A payout leg must leave the vault: sending it back to the vault token account, or to the
+/// claim escrow outside the researcher leg, would settle the report without paying anyone
+fn check_payout_destination(
+    destination: Pubkey,
+    vault_token_account: Pubkey,
+    claim_escrow: Option<Pubkey>,
+) -> Result<()> {
+    require_keys_neq!(destination, vault_token_account, BugBountyError::SelfTransferDestination);
+    require!(Some(destination) != claim_escrow, BugBountyError::SelfTransferDestination);
+    Ok(())
+}
+
+/// Double-entry checks run at the end of every instruction that moves vault funds.
+/// token_balance is the primary vault token account after the instruction, when it is in the context
+fn assert_vault_invariants(vault: &BugBountyVault, token_balance: Option<u64>) -> Result<()> {
+    let outflow = vault.total_paid_out as u128 + vault.total_withdrawn as u128;
+    let reserved = vault.committed_amount as u128 + vault.insurance_reserve as u128;
+    let recorded = (vault.total_funded as u128).saturating_sub(outflow);
+    
+    let mut checks = vec![
+        ("total_paid_out + total_withdrawn <= total_funded", outflow <= vault.total_funded as u128),
+        ("committed_amount + insurance_reserve <= recorded_balance", reserved <= recorded),
+    ];
+    for i in 0..MAX_EXTRA_REWARD_MINTS {
+        let extra_out = vault.extra_paid_out[i] as u128 + vault.extra_committed[i] as u128;
+        checks.push(("extra_paid_out + extra_committed <= extra_funded", extra_out <= vault.extra_funded[i] as u128));
+    }
+    if let Some(balance) = token_balance {
+        checks.push(("committed_amount <= token_balance", vault.committed_amount <= balance));
+        checks.push(("insurance_reserve <= token_balance", vault.insurance_reserve <= balance));
+    }
+    
+    if let Some((name, _)) = checks.iter().find(|(_, holds)| !holds) {
+        msg!("🧮 Accounting invariant violated: {}", name);
+        return err!(BugBountyError::AccountingInvariantViolated);
+    }
+    Ok(())
+}
+
+/// Drain every boost passed with a payout into the payout owner's ATA for the boost's mint.
+/// Each boost must belong to the vault, cover the report's severity and be unexpired.
+fn pay_boosts<'info>(
+    vault: &mut Account<'info, BugBountyVault>,
+    report: &Account<'info, VulnerabilityReport>,
+    boost_accounts: &'info [AccountInfo<'info>],
+    token_program: AccountInfo<'info>,
+    now: i64,
+) -> Result<()> {
+    require!(
+        boost_accounts.len().is_multiple_of(3) && boost_accounts.len() / 3 <= MAX_BOOSTS_PER_PAYOUT,
+        BugBountyError::InvalidBoostAccounts
+    );
+    
+    let mut paid_boosts = Vec::with_capacity(boost_accounts.len() / 3);
+    for chunk in boost_accounts.chunks(3) {
+        let (boost_info, escrow_info, destination_info) = (&chunk[0], &chunk[1], &chunk[2]);
+        require!(!paid_boosts.contains(boost_info.key), BugBountyError::InvalidBoostAccounts);
+        paid_boosts.push(boost_info.key());
+        
+        let mut boost: Account<BountyBoost> = Account::try_from(boost_info)?;
+        require!(
+            boost.vault == vault.key() && escrow_info.key() == boost.escrow,
+            BugBountyError::InvalidBoostAccounts
+        );
+        require!(
+            boost.severity_mask & tier_bit(report.severity) != 0 && now <= boost.expires_at,
+            BugBountyError::BoostNotApplicable
+        );
+        require_keys_eq!(
+            destination_info.key(),
+            get_associated_token_address(&report.payout_owner(), &boost.mint),
+            BugBountyError::DestinationNotAssociatedTokenAccount
+        );
+        
+        let balance = load_token_account(escrow_info)?.amount;
+        let amount = match boost.max_per_report {
+            0 => balance,
+            cap => balance.min(cap),
+        };
+        
+        if amount > 0 {
+            let boost_id = boost.boost_id.to_le_bytes();
+            let signer_seeds: &[&[&[u8]]] = &[&[
+                BOUNTY_BOOST_SEED,
+                boost.vault.as_ref(),
+                boost.sponsor.as_ref(),
+                &boost_id,
+                &[boost.boost_bump],
+            ]];
+            
+            let cpi_ctx = CpiContext::new_with_signer(
+                token_program.clone(),
+                Transfer {
+                    from: escrow_info.clone(),
+                    to: destination_info.clone(),
+                    authority: boost_info.clone(),
+                },
+                signer_seeds,
+            );
+            
+            token::transfer(cpi_ctx, amount)?;
+        }
+        
+        boost.total_paid = boost.total_paid.checked_add(amount)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        boost.exit(&crate::ID)?;
+        
+        emit!(BountyBoostPaid {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            boost: boost.key(),
+            report: report.key(),
+            amount: if report.confidential { 0 } else { amount },
+        });
+    }
+    
+    Ok(())
+}
+
+/// Authorize a privileged signer: either the legacy authority for the instruction, or a
+/// role holder on this vault with the permission bit set. Without a role the legacy error is kept.
+fn require_permission(
+    signer: Pubkey,
+    legacy_authority: Pubkey,
+    vault: Pubkey,
+    role: Option<&Account<Role>>,
+    permission: u16,
+    legacy_error: BugBountyError,
+) -> Result<()> {
+    if signer == legacy_authority {
+        return Ok(());
+    }
+    
+    let Some(role) = role else {
+        return Err(legacy_error.into());
+    };
+    require!(role.vault == vault && role.member == signer, BugBountyError::RoleMismatch);
+    require!(role.has(permission), BugBountyError::MissingPermission);
+    
+    Ok(())
+}
+
+/// Record the reason behind a governance decision as a vault template, an off-chain hash, or both
+fn set_decision_reason(
+    vault: &BugBountyVault,
+    report: &mut VulnerabilityReport,
+    reason_hash: [u8; 32],
+    reason_template_index: Option<u8>,
+) -> Result<()> {
+    if let Some(index) = reason_template_index {
+        let template = vault.reason_templates.get(index as usize)
+            .ok_or(BugBountyError::InvalidReasonTemplate)?;
+        require!(template[0] != 0, BugBountyError::InvalidReasonTemplate);
+    }
+    
+    report.reason_hash = reason_hash;
+    report.reason_template = reason_template_index;
+    Ok(())
+}
+
+/// Decode a pre-version-2 report (free-text approval_reason, no version byte) into the current layout.
+/// Everything around the reason is byte-identical, so the reason is swapped for its hash in place.
+fn migrate_legacy_report(data: &[u8]) -> Result<VulnerabilityReport> {
+    let mut cursor = data;
+    ReportLayoutHead::deserialize(&mut cursor).map_err(|_| BugBountyError::NotLegacyReport)?;
+    let head_len = data.len() - cursor.len();
+    let approval_reason = Option::<String>::deserialize(&mut cursor)
+        .map_err(|_| BugBountyError::NotLegacyReport)?;
+    
+    let reason_hash = approval_reason
+        .map(|reason| hash(reason.as_bytes()).to_bytes())
+        .unwrap_or_default();
+    
+    // Zeroed version slot and fields appended since, in case the legacy account had no padding
+    // left. However many fields that is, it's never more than a whole zeroed report serializes to
+    let zeroed = vec![0u8; std::mem::size_of::<VulnerabilityReport>()];
+    let mut zeroed_cursor = zeroed.as_slice();
+    VulnerabilityReport::deserialize(&mut zeroed_cursor).map_err(|_| BugBountyError::NotLegacyReport)?;
+    let padding = zeroed.len() - zeroed_cursor.len();
+    
+    let mut migrated = Vec::with_capacity(data.len() + 32 + padding);
+    migrated.extend_from_slice(&data[..head_len]);
+    migrated.extend_from_slice(&reason_hash);
+    migrated.extend_from_slice(cursor);
+    migrated.resize(migrated.len() + padding, 0);
+    
+    let mut report = VulnerabilityReport::deserialize(&mut migrated.as_slice())
+        .map_err(|_| BugBountyError::NotLegacyReport)?;
+    report.version = REPORT_VERSION;
+    Ok(report)
+}
+
+/// Rebuild a first-deployment vault in the current layout. Fields added since take the values
+/// init_vault gives a new vault; reports approved before the upgrade hold no reservation
+fn migrate_legacy_vault(legacy: VaultLayoutV1, vault_key: Pubkey, reward_decimals: u8) -> Result<BugBountyVault> {
+    let zeroed = vec![0u8; std::mem::size_of::<BugBountyVault>() + MAX_PROJECT_NAME_LEN];
+    let mut vault = BugBountyVault::deserialize(&mut zeroed.as_slice())
+        .map_err(|_| BugBountyError::NotLegacyVault)?;
+    
+    vault.program_team = legacy.program_team;
+    vault.governance_authority = legacy.governance_authority;
+    vault.vault_bump = legacy.vault_bump;
+    vault.vault_token_account = legacy.vault_token_account;
+    [vault.critical_reward, vault.high_reward, vault.medium_reward, vault.low_reward] =
+        [legacy.critical_reward, legacy.high_reward, legacy.medium_reward, legacy.low_reward];
+    vault.total_funded = legacy.total_funded;
+    vault.total_paid_out = legacy.total_paid_out;
+    vault.total_reports = legacy.total_reports;
+    vault.approved_reports = legacy.approved_reports;
+    vault.reward_token_mint = legacy.reward_token_mint;
+    vault.vault_active = legacy.vault_active;
+    vault.created_at = legacy.created_at;
+    
+    vault.reward_decimals = reward_decimals;
+    vault.submissions_open = true;
+    vault.claim_window_seconds = DEFAULT_CLAIM_WINDOW_SECONDS;
+    vault.vault_authority = vault_key;
+    vault.pricing_policy = PricingPolicy::AtSubmission;
+    vault.max_payout_fraction_bps = BPS_DENOMINATOR as u16;
+    vault.dust_policy = DustPolicy::Researcher;
+    Ok(vault)
+}
+
+/// Checks submit_report applies before opening a report, against the researcher's
+/// SubmissionCounter when one is supplied
+fn check_can_submit(vault: &BugBountyVault, counter: Option<&SubmissionCounter>, now: i64) -> Result<()> {
+    require!(vault.vault_active, BugBountyError::VaultInactive);
+    require!(vault.wind_down_phase == WindDownPhase::Live, BugBountyError::VaultWindingDown);
+    require!(vault.submissions_open, BugBountyError::SubmissionsClosed);
+    require!(
+        vault.max_pending_reports == 0 || vault.pending_reports < vault.max_pending_reports as u64,
+        BugBountyError::TriageQueueFull
+    );
+    
+    // Enforce the per-researcher open report cap and post-rejection cooldown
+    let cap = vault.max_open_reports_per_researcher;
+    let cooldown = vault.cooldown_after_rejection_seconds;
+    match counter {
+        Some(counter) => {
+            require!(
+                cap == 0 || counter.open_reports < cap as u16,
+                BugBountyError::TooManyOpenReports
+            );
+            
+            let cooldown_ends = counter.last_rejected_at.checked_add(cooldown)
+                .ok_or(BugBountyError::ArithmeticOverflow)?;
+            if cooldown > 0 && counter.last_rejected_at > 0 && now < cooldown_ends {
+                msg!("⏳ Submission cooldown active for another {} seconds", cooldown_ends - now);
+                return err!(BugBountyError::SubmissionCooldownActive);
+            }
+        }
+        None => require!(cap == 0 && cooldown == 0, BugBountyError::SubmissionCounterRequired),
+    }
+    Ok(())
+}
+
+/// Experimental instructions start with this; fails until the vault enables the feature
+fn require_feature(vault: &BugBountyVault, feature: u64) -> Result<()> {
+    require!(vault.feature_enabled(feature), BugBountyError::FeatureDisabled);
+    Ok(())
+}
+
+/// Money-moving features can't flip while reports still depend on them
+fn check_feature_toggle(vault: &BugBountyVault, changed: u64) -> Result<()> {
+    require!(
+        changed & FEATURE_PRIORITY_STAKE == 0 || vault.open_priority_stakes == 0,
+        BugBountyError::FeatureInUse
+    );
+    require!(
+        changed & FEATURE_SEVERITY_DISPUTES == 0 || vault.disputed_reports == 0,
+        BugBountyError::FeatureInUse
+    );
+    Ok(())
+}
+
+/// Bit of a severity tier in the vault's paused_tiers mask
+fn tier_bit(severity: SeverityTier) -> u8 {
+    1 << severity as u8
+}
+
+/// Approvals stop at the triage deadline once a vault is winding down
+fn require_triage_open(vault: &BugBountyVault) -> Result<()> {
+    match vault.wind_down_phase {
+        WindDownPhase::Live => Ok(()),
+        WindDownPhase::WindingDown => {
+            require!(
+                current_time(vault)? <= vault.triage_deadline,
+                BugBountyError::TriageDeadlinePassed
+            );
+            Ok(())
+        }
+        WindDownPhase::WoundDown => err!(BugBountyError::InvalidWindDownPhase),
+    }
+}
+
+/// Stamp a rejection on the researcher's counter so the vault's cooldown can apply
+fn record_rejection(
+    vault: &BugBountyVault,
+    report: &VulnerabilityReport,
+    submission_counter: Option<&mut Account<SubmissionCounter>>,
+    rejected_at: i64,
+) -> Result<()> {
+    let Some(counter) = submission_counter else {
+        require!(vault.cooldown_after_rejection_seconds == 0, BugBountyError::SubmissionCounterRequired);
+        return Ok(());
+    };
+    require!(
+        counter.vault == report.vault && counter.researcher == report.researcher,
+        BugBountyError::SubmissionCounterMismatch
+    );
+    
+    counter.last_rejected_at = rejected_at;
+    Ok(())
+}
+
+/// Shared body of reject_report and reject_report_with_response, after the permission check
+fn reject_with_reason(
+    vault: &mut Account<BugBountyVault>,
+    report: &mut Account<VulnerabilityReport>,
+    mut submission_counter: Option<&mut Account<SubmissionCounter>>,
+    rejector: Pubkey,
+    reason_hash: [u8; 32],
+    reason_template_index: Option<u8>,
+    decision_code: Option<DecisionCode>,
+) -> Result<i64> {
+    require!(!report.on_hold, BugBountyError::ReportOnHold);
+    require!(report.status == ReportStatus::Pending, BugBountyError::InvalidReportStatus);
+    // Researchers must be told why, either in the off-chain reason or through a template
+    require!(
+        reason_hash != [0; 32] || reason_template_index.is_some(),
+        BugBountyError::RejectionReasonRequired
+    );
+    
+    let rejected_at = current_time(vault)?;
+    record_first_response(vault, report, rejected_at)?;
+    vault.transition_report(report, ReportStatus::Rejected, rejector)?;
+    report.approver = Some(rejector);
+    report.rejected_at = Some(rejected_at);
+    report.decision_code = decision_code;
+    set_decision_reason(vault, report, reason_hash, reason_template_index)?;
+    
+    release_open_report(report, submission_counter.as_deref_mut())?;
+    record_rejection(vault, report, submission_counter, rejected_at)?;
+    
+    vault.rejected_reports = vault.rejected_reports.checked_add(1)
+        .ok_or(BugBountyError::ArithmeticOverflow)?;
+    
+    emit!(ReportRejected {
+        vault: vault.key(),
+        sequence: vault.next_event_sequence()?,
+        report: report.key(),
+        reason_hash,
+        reason_template: reason_template_index,
+        is_spam: false,
+        rejected_at,
+        rejected_reports: vault.rejected_reports,
+        decision_code,
+    });
+    Ok(rejected_at)
+}
+
+/// Count a Pending report's first acknowledgment or decision toward the vault's SLA metric
+/// Returns the seconds since submission, excluding time spent on legal hold
+fn record_first_response(vault: &mut BugBountyVault, report: &VulnerabilityReport, now: i64) -> Result<u64> {
+    let elapsed = now.checked_sub(report.submitted_at)
+        .ok_or(BugBountyError::ArithmeticUnderflow)?
+        .saturating_sub(report.total_held_seconds)
+        .max(0) as u64;
+    if report.status != ReportStatus::Pending || report.acknowledged_at.is_some() {
+        return Ok(elapsed);
+    }
+    
+    vault.first_responses = vault.first_responses.checked_add(1)
+        .ok_or(BugBountyError::ArithmeticOverflow)?;
+    vault.total_first_response_seconds = vault.total_first_response_seconds.checked_add(elapsed)
+        .ok_or(BugBountyError::ArithmeticOverflow)?;
+    Ok(elapsed)
+}
+
+/// Record a proof-of-concept transaction reference on a report and announce it
+fn set_poc(vault: &mut Account<BugBountyVault>, report: &mut Account<VulnerabilityReport>, poc: PocReference) -> Result<()> {
+    require!(poc.tx_signature != [0; 64], BugBountyError::InvalidPocReference);
+    
+    report.poc_tx_signature = poc.tx_signature;
+    report.poc_cluster = Some(poc.cluster);
+    
+    emit!(PocAttached {
+        vault: vault.key(),
+        sequence: vault.next_event_sequence()?,
+        report: report.key(),
+        poc: if report.confidential { None } else { Some(poc) },
+    });
+    Ok(())
+}
+
+/// Reputation a researcher brings to vault gates: paid reports on chain, or the score of an
+/// unexpired external attestation when the vault accepts them, whichever is higher
+fn researcher_reputation(
+    vault: &BugBountyVault,
+    profile: Option<&ResearcherProfile>,
+    attestation: Option<&ExternalAttestation>,
+    now: i64,
+) -> u32 {
+    let on_chain = profile.map_or(0, |profile| profile.paid_reports);
+    let external = attestation
+        .filter(|attestation| vault.accept_external_attestations && attestation.is_valid(now))
+        .map_or(0, |attestation| attestation.score);
+    on_chain.max(external)
+}
+
+/// Fill in a freshly created report and count it against the vault and researcher
+fn open_report(
+    vault: &mut Account<BugBountyVault>,
+    report: &mut Account<VulnerabilityReport>,
+    report_bump: u8,
+    researcher: Pubkey,
+    rent_payer: Pubkey,
+    severity: SeverityTier,
+    ipfs_hash: [u8; 32],
+    confidential: bool,
+    submission_counter: Option<&mut Account<SubmissionCounter>>,
+    researcher_profile: Option<&ResearcherProfile>,
+    external_attestation: Option<&ExternalAttestation>,
+) -> Result<()> {
+    let submitted_at = current_time(vault)?;
+    check_can_submit(vault, submission_counter.as_deref().map(|counter| &**counter), submitted_at)?;
+    require!(!vault.tier_paused(severity), BugBountyError::SeverityTierPaused);
+    require!(
+        severity != SeverityTier::Critical
+            || researcher_reputation(vault, researcher_profile, external_attestation, submitted_at)
+                >= vault.min_critical_reputation,
+        BugBountyError::InsufficientReputation
+    );
+    
+    report.vault = vault.key();
+    report.researcher = researcher;
+    report.rent_payer = rent_payer;
+    report.severity = severity;
+    report.status = ReportStatus::Pending;
+    report.report_ipfs_hash = ipfs_hash;
+    report.report_bump = report_bump;
+    report.submitted_at = submitted_at;
+    report.confidential = confidential;
+    report.version = REPORT_VERSION;
+    report.submitted_policy_version = vault.policy_version;
+    
+    // Set expected payout based on severity, scaled by any decay window
+    report.reward_multiplier_bps = vault.decay_config
+        .map_or(0, |decay| decay.multiplier_bps_at(report.submitted_at));
+    report.payout_amount = scaled_reward(vault, report)?;
+    report.payout_mint = vault.tier_mint_for(severity);
+    report.mint_generation = vault.previous_mint_count;
+    
+    // Take a slot under the per-researcher open report cap
+    if let Some(counter) = submission_counter {
+        counter.open_reports = counter.open_reports.checked_add(1)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        report.counted_open = true;
+    }
+    
+    vault.total_reports = vault.total_reports.checked_add(1)
+        .ok_or(BugBountyError::ArithmeticOverflow)?;
+    vault.report_opened()?;
+    
+    emit!(ReportSubmitted {
+        vault: vault.key(),
+        sequence: vault.next_event_sequence()?,
+        report: report.key(),
+        researcher: if confidential { None } else { Some(researcher) },
+        severity: report.event_severity(),
+        payout_amount: report.event_payout_amount(),
+        submitted_at: report.submitted_at,
+    });
+    
+    Ok(())
+}
+
+/// Shared body of submit_report and submit_report_subsidized, once the report account exists
+fn submit_report_for(
+    vault: &mut Account<BugBountyVault>,
+    report: &mut Account<VulnerabilityReport>,
+    report_bump: u8,
+    researcher: Pubkey,
+    rent_payer: Pubkey,
+    severity: SeverityTier,
+    ipfs_hash: [u8; 32],
+    confidential: bool,
+    poc: Option<PocReference>,
+    submission_counter: Option<&mut Account<SubmissionCounter>>,
+    linked_report: Option<&Account<VulnerabilityReport>>,
+    trusted_researcher: Option<&Account<TrustedResearcher>>,
+    researcher_profile: Option<&ResearcherProfile>,
+    external_attestation: Option<&ExternalAttestation>,
+    report_summary: Option<&mut Account<ReportSummary>>,
+    summary_bump: Option<u8>,
+) -> Result<()> {
+    open_report(
+        vault,
+        report,
+        report_bump,
+        researcher,
+        rent_payer,
+        severity,
+        ipfs_hash,
+        confidential,
+        submission_counter,
+        researcher_profile,
+        external_attestation,
+    )?;
+    
+    if let Some(poc) = poc {
+        set_poc(vault, report, poc)?;
+    }
+    
+    if let Some(linked_report) = linked_report {
+        report.linked_report = Some(linked_report.key());
+        
+        emit!(ReportLinked {
+            vault: report.vault,
+            sequence: vault.next_event_sequence()?,
+            report: report.key(),
+            linked_report: linked_report.key(),
+            linked_vault: linked_report.vault,
+        });
+    }
+    
+    if let Some(trusted) = trusted_researcher {
+        auto_approve(vault, report, trusted)?;
+    }
+    
+    // Written last so an auto-approval is already reflected
+    check_summary_policy(vault, report_summary.is_some())?;
+    if let Some(summary) = report_summary {
+        summary.summary_bump = summary_bump.ok_or(BugBountyError::ReportSummaryRequired)?;
+        update_report_summary(vault, Some(summary), report)?;
+    }
+    Ok(())
+}
+
+/// Create a program-owned PDA with rent from the vault's lamports, returning the rent paid.
+/// The vault is program-owned, so it pays by debiting its lamports directly; the system
+/// program only allocates and assigns the account, signed for with its seeds
+fn create_subsidized_account<'info>(
+    vault: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+) -> Result<u64> {
+    let rent = Rent::get()?.minimum_balance(space).saturating_sub(account.lamports());
+    vault.sub_lamports(rent)?;
+    account.add_lamports(rent)?;
+    
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate { account_to_allocate: account.clone() },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign { account_to_assign: account.clone() },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )?;
+    Ok(rent)
+}
+
+/// Submissions carry a summary exactly when the vault maintains them
+fn check_summary_policy(vault: &BugBountyVault, summary_passed: bool) -> Result<()> {
+    require!(summary_passed || !vault.maintain_summaries, BugBountyError::ReportSummaryRequired);
+    require!(!summary_passed || vault.maintain_summaries, BugBountyError::ReportSummariesDisabled);
+    Ok(())
+}
+
+/// Bring a report's summary up to date, when the caller passed one
+fn update_report_summary(
+    vault: &BugBountyVault,
+    summary: Option<&mut Account<ReportSummary>>,
+    report: &Account<VulnerabilityReport>,
+) -> Result<()> {
+    if let Some(summary) = summary {
+        summary.sync(report, current_time(vault)?);
+    }
+    Ok(())
+}
+
+/// Whether a report fits under a trusted researcher's auto-approval limits
+fn fits_auto_approval(trusted: &TrustedResearcher, report: &VulnerabilityReport) -> bool {
+    // SeverityTier runs from Critical down, so a higher discriminant is a less severe tier
+    report.severity as u8 >= trusted.auto_approve_max_severity as u8
+        && report.payout_amount <= trusted.auto_approve_max_amount
+}
+
+/// Approve a trusted researcher's fresh report on the spot when it fits their limits and the
+/// vault can cover it; anything else waits for triage as usual. Not a team response, so the
+/// first-response metrics are left alone
+fn auto_approve(
+    vault: &mut Account<BugBountyVault>,
+    report: &mut Account<VulnerabilityReport>,
+    trusted: &TrustedResearcher,
+) -> Result<()> {
+    if !vault.vault_active || !fits_auto_approval(trusted, report) {
+        return Ok(());
+    }
+    let mint_index = vault.mint_index(&report.payout_mint).ok_or(BugBountyError::UnknownRewardMint)?;
+    if vault.mint_available_balance(mint_index)? < report.payout_amount {
+        return Ok(());
+    }
+    
+    vault.commit_payout(mint_index, report.payout_amount)?;
+    vault.approved_reports = vault.approved_reports.checked_add(1)
+        .ok_or(BugBountyError::ArithmeticOverflow)?;
+    
+    let approved_at = current_time(vault)?;
+    let vault_key = vault.key();
+    vault.transition_report(report, ReportStatus::Approved, vault_key)?;
+    report.approver = Some(vault_key);
+    report.approved_at = Some(approved_at);
+    report.auto_approved = true;
+    // Priced in the same instant it was submitted, so every policy yields the submitted amount
+    report.priced_by = Some(vault.pricing_policy);
+    
+    emit!(ReportApproved {
+        vault: vault.key(),
+        sequence: vault.next_event_sequence()?,
+        report: report.key(),
+        severity: report.event_severity(),
+        payout_amount: report.event_payout_amount(),
+        approved_at,
+        reason_hash: [0; 32],
+        auto_approved: true,
+    });
+    
+    msg!("⚡ Report auto-approved for a trusted researcher");
+    Ok(())
+}
+
+/// Bytes a researcher signs to authorize a sponsored submission
+pub fn sponsored_report_message(
+    vault: &Pubkey,
+    ipfs_hash: &[u8; 32],
+    severity: SeverityTier,
+    confidential: bool,
+    nonce: u64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(32 + 32 + 1 + 1 + 8);
+    message.extend_from_slice(vault.as_ref());
+    message.extend_from_slice(ipfs_hash);
+    message.push(severity as u8);
+    message.push(confidential as u8);
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message
+}
+
+/// Signer and message of a single-signature ed25519 precompile instruction.
+/// Offsets must point into the instruction itself so they can't be redirected elsewhere.
+fn parse_ed25519_instruction(data: &[u8]) -> Option<(Pubkey, &[u8])> {
+    const HEADER_LEN: usize = 2;
+    const OFFSETS_LEN: usize = 14;
+    
+    if data.len() < HEADER_LEN + OFFSETS_LEN || data[0] != 1 {
+        return None;
+    }
+    
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let offsets = HEADER_LEN;
+    let signature_ix = read_u16(offsets + 2);
+    let public_key_offset = read_u16(offsets + 4) as usize;
+    let public_key_ix = read_u16(offsets + 6);
+    let message_offset = read_u16(offsets + 8) as usize;
+    let message_size = read_u16(offsets + 10) as usize;
+    let message_ix = read_u16(offsets + 12);
+    
+    if signature_ix != u16::MAX || public_key_ix != u16::MAX || message_ix != u16::MAX {
+        return None;
+    }
+    
+    let public_key = data.get(public_key_offset..public_key_offset + 32)?;
+    let message = data.get(message_offset..message_offset + message_size)?;
+    Some((Pubkey::try_from(public_key).ok()?, message))
+}
+
+/// Require that the instruction just before this one had the ed25519 program verify
+/// `signer`'s signature over `message`
+fn require_ed25519_signature(
+    instructions: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current = instructions_sysvar::load_current_index_checked(instructions)?;
+    require!(current > 0, BugBountyError::InvalidSponsorSignature);
+    
+    let verify_ix = instructions_sysvar::load_instruction_at_checked(current as usize - 1, instructions)?;
+    require_keys_eq!(verify_ix.program_id, ED25519_PROGRAM_ID, BugBountyError::InvalidSponsorSignature);
+    
+    let (signed_by, signed_message) = parse_ed25519_instruction(&verify_ix.data)
+        .ok_or(BugBountyError::InvalidSponsorSignature)?;
+    require!(
+        signed_by == *signer && signed_message == message,
+        BugBountyError::InvalidSponsorSignature
+    );
+    
+    Ok(())
+}
+
+/// Append a privileged action to the vault's audit log.
+/// The log is optional for backward compatibility; when it is missing a warning event is emitted instead.
+fn record_admin_action(
+    audit_log: &Option<AccountLoader<AuditLog>>,
+    vault: &mut Account<BugBountyVault>,
+    action: u8,
+    actor: Pubkey,
+    data: u64,
+) -> Result<()> {
+    let Some(audit_log) = audit_log else {
+        emit!(AuditLogSkipped {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            action,
+            actor,
+        });
+        return Ok(());
+    };
+    
+    let mut log = audit_log.load_mut()?;
+    require!(log.vault == vault.key(), BugBountyError::AuditLogMismatch);
+    log.append(action, actor, data, current_time(vault)?)
+}
+
+// ============================================================================
+// PDA ADDRESSES
+// ============================================================================
+
+/// Vault PDA owned by a program team
+pub fn find_vault_address(program_team: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED, program_team.as_ref()], &crate::ID)
+}
+
+/// Report PDA, indexed by the vault's `total_reports` at submission time
+pub fn find_report_address(vault: &Pubkey, researcher: &Pubkey, report_index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[REPORT_SEED, vault.as_ref(), researcher.as_ref(), &report_index.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+/// Reputation NFT PDA minted for a paid report
+pub fn find_reputation_address(researcher: &Pubkey, report: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REPUTATION_SEED, researcher.as_ref(), report.as_ref()], &crate::ID)
+}
+
+/// Per-vault open report counter for a researcher
+pub fn find_submission_counter_address(vault: &Pubkey, researcher: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SUBMISSION_COUNTER_SEED, vault.as_ref(), researcher.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Singleton platform configuration
+pub fn find_platform_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PLATFORM_CONFIG_SEED], &crate::ID)
+}
+
+/// Signer PDA for a report's claim and stake escrows
+pub fn find_escrow_authority_address(report: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ESCROW_AUTHORITY_SEED, report.as_ref()], &crate::ID)
+}
+
+/// Claim escrow token account for a report
+pub fn find_claim_escrow_address(report: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CLAIM_ESCROW_SEED, report.as_ref()], &crate::ID)
+}
+
+/// Priority stake escrow token account for a report
+pub fn find_stake_escrow_address(report: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STAKE_ESCROW_SEED, report.as_ref()], &crate::ID)
+}
+
+/// Admin audit log for a vault
+pub fn find_audit_log_address(vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AUDIT_LOG_SEED, vault.as_ref()], &crate::ID)
+}
+
+/// Role PDA for a vault member
+pub fn find_role_address(vault: &Pubkey, member: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ROLE_SEED, vault.as_ref(), member.as_ref()], &crate::ID)
+}
+
+/// Vault snapshot PDA for a reporting period
+pub fn find_snapshot_address(vault: &Pubkey, period_id: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SNAPSHOT_SEED, vault.as_ref(), &period_id.to_le_bytes()], &crate::ID)
+}
+
+/// Hall of fame PDA of a vault
+pub fn find_hall_of_fame_address(vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[HALL_OF_FAME_SEED, vault.as_ref()], &crate::ID)
+}
+
+/// Researcher profile PDA
+pub fn find_researcher_profile_address(researcher: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RESEARCHER_PROFILE_SEED, researcher.as_ref()], &crate::ID)
+}
+
+/// Milestone badge PDA of a researcher
+pub fn find_milestone_badge_address(researcher: &Pubkey, milestone_id: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MILESTONE_SEED, researcher.as_ref(), &[milestone_id]], &crate::ID)
+}
+
+/// Response packet PDA of a rejected report
+pub fn find_response_packet_address(report: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RESPONSE_PACKET_SEED, report.as_ref()], &crate::ID)
+}
+
+/// Policy version PDA recording a vault's nth tier update
+pub fn find_policy_version_address(vault: &Pubkey, version: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POLICY_VERSION_SEED, vault.as_ref(), &version.to_le_bytes()], &crate::ID)
+}
+
+/// Trusted researcher PDA granting auto-approval in a vault
+pub fn find_trusted_researcher_address(vault: &Pubkey, researcher: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TRUSTED_RESEARCHER_SEED, vault.as_ref(), researcher.as_ref()], &crate::ID)
+}
+
+/// Firm member PDA letting a member crank payouts to a recipient
+pub fn find_firm_member_address(recipient: &Pubkey, member: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FIRM_MEMBER_SEED, recipient.as_ref(), member.as_ref()], &crate::ID)
+}
+
+/// External reputation attestation of a researcher, issued by the platform admin
+pub fn find_external_attestation_address(researcher: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EXTERNAL_ATTESTATION_SEED, researcher.as_ref()], &crate::ID)
+}
+
+/// Governance bond of a vault, and the token account escrowing it
+pub fn find_governance_bond_address(vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GOVERNANCE_BOND_SEED, vault.as_ref()], &crate::ID)
+}
+
+pub fn find_governance_bond_escrow_address(vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GOVERNANCE_BOND_ESCROW_SEED, vault.as_ref()], &crate::ID)
+}
+
+/// Compact summary PDA of a report
+pub fn find_report_summary_address(report: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REPORT_SUMMARY_SEED, report.as_ref()], &crate::ID)
+}
+
+/// A vault's open governance-compromise alert
+pub fn find_compromise_alert_address(vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COMPROMISE_ALERT_SEED, vault.as_ref()], &crate::ID)
+}
+
+/// Acknowledgment PDA of a paid report
+pub fn find_acknowledgment_address(report: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ACKNOWLEDGMENT_SEED, report.as_ref()], &crate::ID)
+}
+
+/// The platform's own vault, bootstrapped once by the platform admin
+pub fn find_platform_vault_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED, PLATFORM_VAULT_SEED], &crate::ID)
+}
+
+/// Subscription PDA of a researcher watching a vault
+pub fn find_subscription_address(vault: &Pubkey, researcher: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SUBSCRIPTION_SEED, vault.as_ref(), researcher.as_ref()], &crate::ID)
+}
+
+/// Handle PDA for a lowercase handle
+pub fn find_handle_address(name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[HANDLE_SEED, name.as_bytes()], &crate::ID)
+}
+
+/// Vault registry page PDA
+pub fn find_vault_registry_address(page_index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_REGISTRY_SEED, &page_index.to_le_bytes()], &crate::ID)
+}
+
+// ============================================================================
+// CLIENT FILTERS
+// ============================================================================
+
+/// getProgramAccounts filters for listing reports. Kept free of solana-client so the on-chain
+/// build never sees it; map each filter to `RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, bytes))`
+#[cfg(feature = "client")]
+pub mod client {
+    use super::*;
+    
+    /// Matches when the account data holds `bytes` starting at `offset`
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct MemcmpFilter {
+        pub offset: usize,
+        pub bytes: Vec<u8>,
+    }
+    
+    impl MemcmpFilter {
+        pub fn matches(&self, data: &[u8]) -> bool {
+            data.get(self.offset..self.offset + self.bytes.len()) == Some(&self.bytes[..])
+        }
+    }
+    
+    /// Any VulnerabilityReport account
+    pub fn report_discriminator_filter() -> MemcmpFilter {
+        MemcmpFilter { offset: 0, bytes: VulnerabilityReport::DISCRIMINATOR.to_vec() }
+    }
+    
+    /// Reports submitted to one vault
+    pub fn report_filters_for_vault(vault: &Pubkey) -> Vec<MemcmpFilter> {
+        vec![
+            report_discriminator_filter(),
+            MemcmpFilter { offset: REPORT_VAULT_OFFSET, bytes: vault.to_bytes().to_vec() },
+        ]
+    }
+    
+    /// Reports by one researcher, across every vault
+    pub fn report_filters_for_researcher(researcher: &Pubkey) -> Vec<MemcmpFilter> {
+        vec![
+            report_discriminator_filter(),
+            MemcmpFilter { offset: REPORT_RESEARCHER_OFFSET, bytes: researcher.to_bytes().to_vec() },
+        ]
+    }
+    
+    /// A vault's reports in one status, e.g. the Pending triage queue
+    pub fn report_filters_for_vault_status(vault: &Pubkey, status: ReportStatus) -> Vec<MemcmpFilter> {
+        let mut filters = report_filters_for_vault(vault);
+        filters.push(MemcmpFilter { offset: REPORT_STATUS_OFFSET, bytes: vec![status as u8] });
+        filters
+    }
+    
+    /// A vault's reports of one severity
+    pub fn report_filters_for_vault_severity(vault: &Pubkey, severity: SeverityTier) -> Vec<MemcmpFilter> {
+        let mut filters = report_filters_for_vault(vault);
+        filters.push(MemcmpFilter { offset: REPORT_SEVERITY_OFFSET, bytes: vec![severity as u8] });
+        filters
+    }
+    
+    /// Summaries of one vault's reports; summaries share the report's field offsets, so the
+    /// status and severity filters above apply once the discriminator filter is swapped
+    pub fn report_summary_filters_for_vault(vault: &Pubkey) -> Vec<MemcmpFilter> {
+        vec![
+            MemcmpFilter { offset: 0, bytes: ReportSummary::DISCRIMINATOR.to_vec() },
+            MemcmpFilter { offset: REPORT_VAULT_OFFSET, bytes: vault.to_bytes().to_vec() },
+        ]
+    }
+}
+
+// ============================================================================
+// PROGRAM LOGIC
+// ============================================================================
+
+/// Every signer may be a PDA of a calling program (see `programs/bounty-cpi-tester`).
+/// Signers that pay for `init` accounts (`create_bounty_vault`, `submit_report`,
+/// `init_submission_counter`, `open_claim_escrow`, `stake_for_priority`, `mint_reputation_nft`,
+/// `init_audit_log`, `grant_role`, `initialize_platform_config`, `snapshot_vault`,
+/// `add_hall_of_fame_entry`, `open_registry_page`, `register_handle`) must be lamport-only PDAs, since
+/// the system program cannot debit accounts that carry data. No instruction rejects PDA signers
+/// on purpose.
+#[program]
+pub mod bug_bounty_platform {
+    use super::*;
+
+    /// Initialize a new bug bounty vault
+    /// Governance must differ from the program team unless allow_same_authority is set.
+    /// The governance recovery delay is fixed here for the vault's lifetime; 0 disables recovery.
+    /// So is the pricing policy, which decides what a report pays if tiers change before approval.
+    pub fn create_bounty_vault(
+        ctx: Context<CreateBountyVault>,
+        critical_reward: u64,
+        high_reward: u64,
+        medium_reward: u64,
+        low_reward: u64,
+        initial_funding: u64,
+        reward_token_mint: Option<Pubkey>,
+        allow_same_authority: bool,
+        arbitration_enabled: bool,
+        governance_recovery_delay_seconds: i64,
+        pricing_policy: PricingPolicy,
+    ) -> Result<()> {
+        init_vault(
+            &mut ctx.accounts.vault,
+            ctx.accounts.program_team.key(),
+            ctx.accounts.governance_authority.key(),
+            ctx.bumps.vault,
+            ctx.accounts.vault_token_account.key(),
+            [critical_reward, high_reward, medium_reward, low_reward],
+            initial_funding,
+            reward_token_mint,
+            ctx.accounts.reward_mint.decimals,
+            allow_same_authority,
+            arbitration_enabled,
+            governance_recovery_delay_seconds,
+            pricing_policy,
+            ctx.accounts.vault_registry.as_ref(),
+        )?;
+        
+        msg!("✅ Bug Bounty Vault created with {} critical, {} high, {} medium, {} low rewards", critical_reward, high_reward, medium_reward, low_reward);
+        Ok(())
+    }
+
+    /// Create a vault, its associated token account and fund it in one transaction
+    pub fn create_and_fund_vault(
+        ctx: Context<CreateAndFundVault>,
+        critical_reward: u64,
+        high_reward: u64,
+        medium_reward: u64,
+        low_reward: u64,
+        initial_funding: u64,
+        allow_same_authority: bool,
+        arbitration_enabled: bool,
+        governance_recovery_delay_seconds: i64,
+        pricing_policy: PricingPolicy,
+    ) -> Result<()> {
+        init_vault(
+            &mut ctx.accounts.vault,
+            ctx.accounts.program_team.key(),
+            ctx.accounts.governance_authority.key(),
+            ctx.bumps.vault,
+            ctx.accounts.vault_token_account.key(),
+            [critical_reward, high_reward, medium_reward, low_reward],
+            initial_funding,
+            Some(ctx.accounts.reward_mint.key()),
+            ctx.accounts.reward_mint.decimals,
+            allow_same_authority,
+            arbitration_enabled,
+            governance_recovery_delay_seconds,
+            pricing_policy,
+            ctx.accounts.vault_registry.as_ref(),
+        )?;
+        
+        // A zero deposit still leaves the vault with a ready-to-fund token account
+        if initial_funding > 0 {
+            let cpi_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.program_team_token_account.to_account_info(),
+                    to: ctx.accounts.vault_token_account.to_account_info(),
+                    authority: ctx.accounts.program_team.to_account_info(),
+                },
+            );
+            
+            token::transfer(cpi_ctx, initial_funding)?;
+        }
+        ctx.accounts.vault_token_account.reload()?;
+        assert_vault_invariants(&ctx.accounts.vault, Some(ctx.accounts.vault_token_account.amount))?;
+        
+        msg!("✅ Bug Bounty Vault created and funded with {} tokens of {}", initial_funding, ctx.accounts.reward_mint.key());
+        Ok(())
+    }
+
+    /// Create the platform's own vault for bugs in this program, with the platform admin as
+    /// program team (platform admin only, once: the vault sits at a reserved seed)
+    pub fn bootstrap_platform_vault(
+        ctx: Context<BootstrapPlatformVault>,
+        critical_reward: u64,
+        high_reward: u64,
+        medium_reward: u64,
+        low_reward: u64,
+        reward_token_mint: Option<Pubkey>,
+        allow_same_authority: bool,
+    ) -> Result<()> {
+        init_vault(
+            &mut ctx.accounts.vault,
+            ctx.accounts.admin.key(),
+            ctx.accounts.governance_authority.key(),
+            ctx.bumps.vault,
+            ctx.accounts.vault_token_account.key(),
+            [critical_reward, high_reward, medium_reward, low_reward],
+            0,
+            reward_token_mint,
+            ctx.accounts.reward_mint.decimals,
+            allow_same_authority,
+            false,
+            0,
+            PricingPolicy::AtSubmission,
+            ctx.accounts.vault_registry.as_ref(),
+        )?;
+        ctx.accounts.vault.platform_vault = true;
+        
+        msg!("🏠 Platform vault bootstrapped with {} critical, {} high, {} medium, {} low rewards", critical_reward, high_reward, medium_reward, low_reward);
+        Ok(())
+    }
+
+    /// Create a vault whose configuration is copied from an existing vault; the token mint
+    /// and governance authority are always chosen fresh
+    pub fn create_vault_from_template(
+        ctx: Context<CreateVaultFromTemplate>,
+        reward_token_mint: Option<Pubkey>,
+        allow_same_authority: bool,
+    ) -> Result<()> {
+        let template = &ctx.accounts.template_vault;
+        
+        init_vault(
+            &mut ctx.accounts.vault,
+            ctx.accounts.program_team.key(),
+            ctx.accounts.governance_authority.key(),
+            ctx.bumps.vault,
+            ctx.accounts.vault_token_account.key(),
+            [template.critical_reward, template.high_reward, template.medium_reward, template.low_reward],
+            0,
+            reward_token_mint,
+            ctx.accounts.reward_mint.decimals,
+            allow_same_authority,
+            template.arbitration_enabled,
+            template.governance_recovery_delay_seconds,
+            template.pricing_policy,
+            ctx.accounts.vault_registry.as_ref(),
+        )?;
+        
+        // Settings only; balances, reports, mints and the project name stay the new vault's own
+        let vault = &mut ctx.accounts.vault;
+        vault.priority_stake = template.priority_stake;
+        vault.feature_flags = template.feature_flags;
+        vault.max_open_reports_per_researcher = template.max_open_reports_per_researcher;
+        vault.withdrawal_delay_seconds = template.withdrawal_delay_seconds;
+        vault.insurance_bps = template.insurance_bps;
+        vault.spam_threshold = template.spam_threshold;
+        vault.spam_window_seconds = template.spam_window_seconds;
+        vault.max_payouts_per_hour = template.max_payouts_per_hour;
+        vault.max_hourly_outflow = template.max_hourly_outflow;
+        vault.embargo_seconds = template.embargo_seconds;
+        vault.claim_window_seconds = template.claim_window_seconds;
+        vault.team_mints_reputation = template.team_mints_reputation;
+        vault.reason_templates = template.reason_templates;
+        vault.compressed_reputation = template.compressed_reputation;
+        vault.reputation_on_approval = template.reputation_on_approval;
+        vault.allow_self_approval = template.allow_self_approval;
+        vault.dual_approval_threshold = template.dual_approval_threshold;
+        vault.max_vault_balance = template.max_vault_balance;
+        vault.decay_config = template.decay_config;
+        vault.invoice_required = template.invoice_required;
+        // Granularity is in base units, so it only carries over between mints of equal decimals
+        if template.reward_decimals == vault.reward_decimals {
+            vault.reward_granularity = template.reward_granularity;
+        }
+        
+        msg!("✅ Bug Bounty Vault created from template {}", template.key());
+        Ok(())
+    }
+
+    /// Prove the program team controls the target program's upgrade authority (re-runnable)
+    pub fn verify_vault_ownership(
+        ctx: Context<VerifyVaultOwnership>,
+        target_program: Pubkey,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require!(
+            ctx.accounts.program_data.upgrade_authority_address == Some(vault.program_team),
+            BugBountyError::ProgramAuthorityMismatch
+        );
+        
+        let verified_at = current_time(vault)?;
+        vault.verified_program = Some(target_program);
+        vault.verified_at = verified_at;
+        
+        emit!(VaultOwnershipVerified {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            target_program,
+            verified_at,
+        });
+        
+        msg!("🛡️ Vault verified as controlled by the upgrade authority of {}", target_program);
+        Ok(())
+    }
+
+    /// Permissionless: clear a vault's program verification once its upgrade authority
+    /// is no longer the program team (changed, revoked, or the program was closed)
+    pub fn challenge_verification(
+        ctx: Context<ChallengeVerification>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let target_program = vault.verified_program.ok_or(BugBountyError::VaultNotProgramVerified)?;
+        
+        let program_data = &ctx.accounts.program_data;
+        let still_valid = *program_data.owner == bpf_loader_upgradeable::ID
+            && ProgramData::try_deserialize(&mut &program_data.data.borrow()[..])
+                .is_ok_and(|data| data.upgrade_authority_address == Some(vault.program_team));
+        require!(!still_valid, BugBountyError::VerificationStillValid);
+        
+        vault.verified_program = None;
+        vault.verified_at = 0;
+        
+        emit!(VaultVerificationCleared {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            target_program,
+            challenger: ctx.accounts.challenger.key(),
+        });
+        
+        msg!("🛡️ Program verification cleared for {}", target_program);
+        Ok(())
+    }
+
+    /// Read-only guard for CPI callers: fails unless the vault is active, verified for
+    /// target_program and holds at least min_balance not yet reserved for reports
+    pub fn assert_active_vault(
+        ctx: Context<AssertActiveVault>,
+        target_program: Pubkey,
+        min_balance: u64,
+    ) -> Result<()> {
+        check_active_vault(&ctx.accounts.vault, target_program, min_balance)?;
+        
+        msg!("🛡️ Active bounty vault confirmed for {}", target_program);
+        Ok(())
+    }
+
+    /// Submit a vulnerability report
+    pub fn submit_report(
+        mut ctx: Context<SubmitReport>,
+        severity: SeverityTier,
+        ipfs_hash: [u8; 32],
+        confidential: bool,
+        poc: Option<PocReference>,
+    ) -> Result<()> {
+        let researcher = ctx.accounts.researcher.key();
+        let accounts = &mut ctx.accounts;
+        submit_report_for(
+            &mut accounts.vault,
+            &mut accounts.report,
+            ctx.bumps.report,
+            researcher,
+            researcher,
+            severity,
+            ipfs_hash,
+            confidential,
+            poc,
+            accounts.submission_counter.as_mut(),
+            accounts.linked_report.as_ref(),
+            accounts.trusted_researcher.as_ref(),
+            accounts.researcher_profile.as_deref(),
+            accounts.external_attestation.as_deref(),
+            accounts.report_summary.as_mut(),
+            ctx.bumps.report_summary,
+        )?;
+        
+        if confidential {
+            msg!("📋 Confidential report submitted");
+        } else {
+            msg!("📋 Report submitted by {} with {:?} severity", researcher, severity);
+        }
+        Ok(())
+    }
+
+    /// Submit a report with the vault's rent subsidy funding the report account and its summary,
+    /// so a researcher without SOL can submit; fails with RentSubsidyExhausted once the budget
+    /// can't cover them, in which case submit_report still works.
+    pub fn submit_report_subsidized<'info>(
+        ctx: Context<'_, '_, '_, 'info, SubmitReportSubsidized<'info>>,
+        severity: SeverityTier,
+        ipfs_hash: [u8; 32],
+        confidential: bool,
+        poc: Option<PocReference>,
+    ) -> Result<()> {
+        let accounts = ctx.accounts;
+        require_feature(&accounts.vault, FEATURE_RENT_SUBSIDY)?;
+        
+        let vault_key = accounts.vault.key();
+        let researcher = accounts.researcher.key();
+        let report_index = accounts.vault.total_reports.to_le_bytes();
+        let vault_info = accounts.vault.to_account_info();
+        let system_program = accounts.system_program.to_account_info();
+        
+        // Account wants an AccountInfo living for 'info; the program heap is never freed, so
+        // leaking the clones costs nothing
+        let report_info: &'info AccountInfo<'info> = Box::leak(Box::new(accounts.report.to_account_info()));
+        let report_rent = create_subsidized_account(
+            &vault_info,
+            report_info,
+            &system_program,
+            8 + std::mem::size_of::<VulnerabilityReport>(),
+            &[REPORT_SEED, vault_key.as_ref(), researcher.as_ref(), &report_index, &[ctx.bumps.report]],
+        )?;
+        let mut rent = report_rent;
+        let mut report_summary = None;
+        if let Some(summary) = accounts.report_summary.as_ref() {
+            let summary_info: &'info AccountInfo<'info> = Box::leak(Box::new(summary.to_account_info()));
+            let summary_bump = ctx.bumps.report_summary.ok_or(BugBountyError::ReportSummaryRequired)?;
+            let summary_rent = create_subsidized_account(
+                &vault_info,
+                summary_info,
+                &system_program,
+                REPORT_SUMMARY_SPACE,
+                &[REPORT_SUMMARY_SEED, report_info.key.as_ref(), &[summary_bump]],
+            )?;
+            rent = rent.checked_add(summary_rent).ok_or(BugBountyError::ArithmeticOverflow)?;
+            report_summary = Some(Account::<ReportSummary>::try_from_unchecked(summary_info)?);
+        }
+        
+        let vault = &mut accounts.vault;
+        let remaining_budget = vault.rent_subsidy_budget.checked_sub(rent)
+            .ok_or(BugBountyError::RentSubsidyExhausted)?;
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(vault_info.data_len());
+        require!(vault_info.lamports() >= rent_exempt_minimum, BugBountyError::RentSubsidyExhausted);
+        vault.rent_subsidy_budget = remaining_budget;
+        // Summaries are never closed, so only the report's rent comes back to the budget
+        vault.rent_subsidy_outstanding = vault.rent_subsidy_outstanding.checked_add(report_rent)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        
+        let mut report = Account::<VulnerabilityReport>::try_from_unchecked(report_info)?;
+        submit_report_for(
+            vault,
+            &mut report,
+            ctx.bumps.report,
+            researcher,
+            vault_key,
+            severity,
+            ipfs_hash,
+            confidential,
+            poc,
+            accounts.submission_counter.as_mut(),
+            accounts.linked_report.as_ref(),
+            accounts.trusted_researcher.as_ref(),
+            accounts.researcher_profile.as_deref(),
+            accounts.external_attestation.as_deref(),
+            report_summary.as_mut(),
+            ctx.bumps.report_summary,
+        )?;
+        
+        // Not context accounts, so nothing writes them back on exit otherwise
+        report.exit(&crate::ID)?;
+        if let Some(summary) = report_summary {
+            summary.exit(&crate::ID)?;
+        }
+        
+        msg!("📋 Subsidized report submitted, {} lamports of rent paid by the vault", rent);
+        Ok(())
+    }
+
+    /// Submit a report on a researcher's behalf, with the sponsor paying rent and fees.
+    /// The transaction must verify the researcher's ed25519 signature over
+    /// sponsored_report_message in the instruction right before this one.
+    pub fn submit_report_sponsored(
+        ctx: Context<SubmitReportSponsored>,
+        researcher: Pubkey,
+        severity: SeverityTier,
+        ipfs_hash: [u8; 32],
+        confidential: bool,
+    ) -> Result<()> {
+        let counter = &mut ctx.accounts.submission_counter;
+        if counter.researcher == Pubkey::default() {
+            counter.vault = ctx.accounts.vault.key();
+            counter.researcher = researcher;
+            counter.open_reports = 0;
+            counter.counter_bump = ctx.bumps.submission_counter;
+        }
+        
+        let message = sponsored_report_message(
+            &ctx.accounts.vault.key(),
+            &ipfs_hash,
+            severity,
+            confidential,
+            counter.nonce,
+        );
+        require_ed25519_signature(&ctx.accounts.instructions, &researcher, &message)?;
+        
+        // Burn the nonce so the signature can't be replayed
+        counter.nonce = counter.nonce.checked_add(1)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        
+        open_report(
+            &mut ctx.accounts.vault,
+            &mut ctx.accounts.report,
+            ctx.bumps.report,
+            researcher,
+            ctx.accounts.sponsor.key(),
+            severity,
+            ipfs_hash,
+            confidential,
+            Some(counter),
+            ctx.accounts.researcher_profile.as_deref(),
+            ctx.accounts.external_attestation.as_deref(),
+        )?;
+        ctx.accounts.report.sponsor = Some(ctx.accounts.sponsor.key());
+        
+        check_summary_policy(&ctx.accounts.vault, ctx.accounts.report_summary.is_some())?;
+        if let Some(summary) = ctx.accounts.report_summary.as_mut() {
+            summary.summary_bump = ctx.bumps.report_summary.ok_or(BugBountyError::ReportSummaryRequired)?;
+        }
+        update_report_summary(&ctx.accounts.vault, ctx.accounts.report_summary.as_mut(), &ctx.accounts.report)?;
+        
+        if confidential {
+            msg!("📋 Confidential sponsored report submitted");
+        } else {
+            msg!("📋 Sponsored report submitted for {} with {:?} severity", researcher, severity);
+        }
+        Ok(())
+    }
+
+    /// Confirm triage has seen a pending report without deciding it (governance or a triager)
+    pub fn acknowledge_report(
+        ctx: Context<AcknowledgeReport>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        require_permission(
+            ctx.accounts.governance_authority.key(),
+            vault.governance_authority,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_TRIAGE,
+            BugBountyError::NotGovernanceAuthority,
+        )?;
+        
+        require!(!report.on_hold, BugBountyError::ReportOnHold);
+        require!(report.status == ReportStatus::Pending, BugBountyError::InvalidReportStatus);
+        require!(report.acknowledged_at.is_none(), BugBountyError::AlreadyAcknowledged);
+        
+        let acknowledged_at = current_time(vault)?;
+        let response_seconds = record_first_response(vault, report, acknowledged_at)?;
+        report.acknowledged_at = Some(acknowledged_at);
+        
+        emit!(ReportAcknowledged {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            report: report.key(),
+            acknowledged_at,
+            response_seconds,
+        });
+        
+        msg!("👀 Report acknowledged after {} seconds", response_seconds);
+        Ok(())
+    }
+
+    /// Governance approves a vulnerability report (requires governance authority)
+    pub fn approve_report(
+        ctx: Context<ApproveReport>,
+        reason_hash: [u8; 32],
+        reason_template_index: Option<u8>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        // Verify approver holds governance rights
+        require_permission(
+            ctx.accounts.governance_authority.key(),
+            vault.governance_authority,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_APPROVE,
+            BugBountyError::NotGovernanceAuthority,
+        )?;
+        
+        require!(
+            vault.allow_self_approval || ctx.accounts.governance_authority.key() != report.researcher,
+            BugBountyError::SelfApprovalForbidden
+        );
+        require!(vault.vault_active, BugBountyError::ApprovalsPaused);
+        require_triage_open(vault)?;
+        require!(!report.on_hold, BugBountyError::ReportOnHold);
+        // Expired approvals come back here when governance honours a researcher's petition
+        require!(
+            report.status == ReportStatus::Pending || report.status == ReportStatus::Expired,
+            BugBountyError::InvalidReportStatus
+        );
+        
+        reprice_for_current_mint(vault, report)?;
+        // Re-approving an expired report keeps the price it was first approved at
+        if report.status == ReportStatus::Pending {
+            let (payout_amount, policy) = approval_price(vault, report)?;
+            report.payout_amount = payout_amount;
+            report.priced_by = Some(policy);
+        }
+        
+        // Delegates approve against their spend caps; the governance key itself is uncapped
+        report.approver_role_charged = false;
+        if ctx.accounts.governance_authority.key() != vault.governance_authority {
+            if let Some(role) = ctx.accounts.role.as_mut() {
+                role.charge_payout(report.payout_amount)?;
+                report.approver_role_charged = true;
+            }
+        }
+        
+        // Reserve the payout so queued withdrawals can't spend it
+        let mint_index = vault.mint_index(&report.payout_mint).ok_or(BugBountyError::UnknownRewardMint)?;
+        vault.commit_payout(mint_index, report.payout_amount)?;
+        
+        // Re-approving an expired report doesn't count it twice
+        if report.status == ReportStatus::Pending {
+            vault.approved_reports = vault.approved_reports.checked_add(1)
+                .ok_or(BugBountyError::ArithmeticOverflow)?;
+        }
+        
+        let approved_at = current_time(vault)?;
+        record_first_response(vault, report, approved_at)?;
+        vault.transition_report(report, ReportStatus::Approved, ctx.accounts.governance_authority.key())?;
+        report.approver = Some(ctx.accounts.governance_authority.key());
+        report.approved_at = Some(approved_at);
+        set_decision_reason(vault, report, reason_hash, reason_template_index)?;
+        report.team_co_approved = false;
+        
+        emit!(ReportApproved {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            report: report.key(),
+            severity: report.event_severity(),
+            payout_amount: report.event_payout_amount(),
+            approved_at,
+            reason_hash,
+            auto_approved: false,
+        });
+        
+        update_report_summary(vault, ctx.accounts.report_summary.as_mut(), report)?;
+        
+        if report.confidential {
+            msg!("✅ Confidential report approved by governance");
+        } else {
+            msg!("✅ Report approved by governance. Payout: {} tokens", report.payout_amount);
+        }
+        Ok(())
+    }
+
+    /// Governance rejects a vulnerability report
+    pub fn reject_report(
+        ctx: Context<RejectReport>,
+        reason_hash: [u8; 32],
+        reason_template_index: Option<u8>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        // Verify rejector holds governance rights
+        require_permission(
+            ctx.accounts.governance_authority.key(),
+            vault.governance_authority,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_REJECT,
+            BugBountyError::NotGovernanceAuthority,
+        )?;
+        
+        reject_with_reason(
+            vault,
+            report,
+            ctx.accounts.submission_counter.as_mut(),
+            ctx.accounts.governance_authority.key(),
+            reason_hash,
+            reason_template_index,
+            None,
+        )?;
+        update_report_summary(vault, ctx.accounts.report_summary.as_mut(), report)?;
+        
+        msg!("❌ Report rejected by governance");
+        Ok(())
+    }
+
+    /// Governance rejects a report and publishes a structured ResponsePacket alongside it
+    pub fn reject_report_with_response(
+        ctx: Context<RejectReportWithResponse>,
+        reason_hash: [u8; 32],
+        reason_template_index: Option<u8>,
+        decision_code: DecisionCode,
+        details_ipfs_hash: [u8; 32],
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        require_permission(
+            ctx.accounts.governance_authority.key(),
+            vault.governance_authority,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_REJECT,
+            BugBountyError::NotGovernanceAuthority,
+        )?;
+        
+        let rejected_at = reject_with_reason(
+            vault,
+            report,
+            ctx.accounts.submission_counter.as_mut(),
+            ctx.accounts.governance_authority.key(),
+            reason_hash,
+            reason_template_index,
+            Some(decision_code),
+        )?;
+        
+        let packet = &mut ctx.accounts.response_packet;
+        packet.report = report.key();
+        packet.vault = vault.key();
+        packet.decision_code = decision_code;
+        packet.details_ipfs_hash = details_ipfs_hash;
+        packet.decided_by = ctx.accounts.governance_authority.key();
+        packet.decided_at = rejected_at;
+        packet.packet_bump = ctx.bumps.response_packet;
+        
+        msg!("❌ Report rejected by governance: {:?}", decision_code);
+        Ok(())
+    }
+
+    /// Governance rejects a report as spam, forfeiting any priority stake
+    /// Too many spam rejections within the vault's window close submissions
+    pub fn reject_as_spam(
+        ctx: Context<RejectAsSpam>,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        require_permission(
+            ctx.accounts.governance_authority.key(),
+            vault.governance_authority,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_REJECT,
+            BugBountyError::NotGovernanceAuthority,
+        )?;
+        
+        require!(!report.on_hold, BugBountyError::ReportOnHold);
+        require!(report.status == ReportStatus::Pending, BugBountyError::InvalidReportStatus);
+        require!(reason_hash != [0; 32], BugBountyError::RejectionReasonRequired);
+        
+        let rejected_at = current_time(vault)?;
+        record_first_response(vault, report, rejected_at)?;
+        vault.transition_report(report, ReportStatus::Rejected, ctx.accounts.governance_authority.key())?;
+        report.is_spam = true;
+        report.approver = Some(ctx.accounts.governance_authority.key());
+        report.rejected_at = Some(rejected_at);
+        report.reason_hash = reason_hash;
+        report.reason_template = None;
+        
+        release_open_report(report, ctx.accounts.submission_counter.as_mut())?;
+        record_rejection(vault, report, ctx.accounts.submission_counter.as_mut(), rejected_at)?;
+        
+        vault.rejected_reports = vault.rejected_reports.checked_add(1)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        
+        emit!(ReportRejected {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            report: report.key(),
+            reason_hash,
+            reason_template: None,
+            is_spam: true,
+            rejected_at,
+            rejected_reports: vault.rejected_reports,
+            decision_code: None,
+        });
+        
+        if vault.record_spam_rejection(rejected_at)? && vault.submissions_open {
+            vault.submissions_open = false;
+            
+            emit!(VaultAutoPaused {
+                vault: vault.key(),
+                sequence: vault.next_event_sequence()?,
+                recent_spam_count: vault.recent_spam_count,
+                spam_window_start: vault.spam_window_start,
+                subscriber_count: vault.subscriber_count,
+            });
+            
+            msg!("🛑 Submissions closed after {} spam reports", vault.recent_spam_count);
+        }
+        
+        msg!("🚫 Report rejected as spam by governance");
+        Ok(())
+    }
+
+    /// Researcher withdraws their own pending report
+    pub fn withdraw_report(
+        ctx: Context<WithdrawReport>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        require!(!report.on_hold, BugBountyError::ReportOnHold);
+        require!(report.status == ReportStatus::Pending, BugBountyError::InvalidReportStatus);
+        
+        vault.transition_report(report, ReportStatus::Withdrawn, ctx.accounts.researcher.key())?;
+        
+        release_open_report(report, ctx.accounts.submission_counter.as_mut())?;
+        
+        vault.withdrawn_reports = vault.withdrawn_reports.checked_add(1)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        
+        emit!(ReportWithdrawn {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            report: report.key(),
+            withdrawn_reports: vault.withdrawn_reports,
+        });
+        
+        msg!("↩️ Report withdrawn by researcher");
+        Ok(())
+    }
+
+    /// Researcher closes a finished report (withdrawn, rejected or expired);
+    /// the rent goes back to whoever paid it, not to the signer
+    pub fn close_report(
+        ctx: Context<CloseReport>,
+    ) -> Result<()> {
+        let report = &ctx.accounts.report;
+        
+        require!(!report.on_hold, BugBountyError::ReportOnHold);
+        require!(
+            matches!(
+                report.status,
+                ReportStatus::Withdrawn | ReportStatus::Rejected | ReportStatus::Expired
+            ),
+            BugBountyError::ReportNotClosable
+        );
+        // Spam rejections stay on chain as evidence
+        require!(!report.is_spam, BugBountyError::ReportNotClosable);
+        require!(report.stake_escrow.is_none(), BugBountyError::PriorityStakeUnsettled);
+        
+        // Subsidized rent goes back into the vault's budget
+        let lamports = report.to_account_info().lamports();
+        if report.rent_payer == ctx.accounts.vault.key() {
+            let vault = &mut ctx.accounts.vault;
+            vault.rent_subsidy_budget = vault.rent_subsidy_budget.checked_add(lamports)
+                .ok_or(BugBountyError::ArithmeticOverflow)?;
+            vault.rent_subsidy_outstanding = vault.rent_subsidy_outstanding.saturating_sub(lamports);
+        }
+        
+        emit!(ReportClosed {
+            vault: report.vault,
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            report: report.key(),
+            rent_payer: ctx.accounts.rent_payer.key(),
+            lamports,
+        });
+        
+        msg!("🗑️ Report closed, rent returned to {}", ctx.accounts.rent_payer.key());
+        Ok(())
+    }
+
+    /// Create the per-vault submission counter for a researcher
+    pub fn init_submission_counter(
+        ctx: Context<InitSubmissionCounter>,
+    ) -> Result<()> {
+        let counter = &mut ctx.accounts.submission_counter;
+        
+        counter.vault = ctx.accounts.vault.key();
+        counter.researcher = ctx.accounts.researcher.key();
+        counter.open_reports = 0;
+        counter.counter_bump = ctx.bumps.submission_counter;
+        
+        msg!("🧮 Submission counter created for researcher");
+        Ok(())
+    }
+
+    /// Create the researcher's submission counter and profile when missing. Re-running it
+    /// is a no-op, so clients can prepend it to a first submit_report in one transaction.
+    pub fn ensure_researcher_accounts(
+        ctx: Context<EnsureResearcherAccounts>,
+    ) -> Result<()> {
+        let researcher = ctx.accounts.researcher.key();
+        
+        // init_if_needed hands back existing accounts as-is; only fill in fresh ones
+        let counter = &mut ctx.accounts.submission_counter;
+        if counter.researcher == Pubkey::default() {
+            counter.vault = ctx.accounts.vault.key();
+            counter.researcher = researcher;
+            counter.open_reports = 0;
+            counter.counter_bump = ctx.bumps.submission_counter;
+        }
+        require_keys_eq!(counter.researcher, researcher, BugBountyError::SubmissionCounterMismatch);
+        require_keys_eq!(counter.vault, ctx.accounts.vault.key(), BugBountyError::SubmissionCounterMismatch);
+        
+        let profile = &mut ctx.accounts.researcher_profile;
+        if profile.researcher == Pubkey::default() {
+            profile.researcher = researcher;
+            profile.profile_bump = ctx.bumps.researcher_profile;
+            profile.handle = None;
+        }
+        require_keys_eq!(profile.researcher, researcher, BugBountyError::UnauthorizedResearcher);
+        
+        msg!("🧮 Researcher accounts ready");
+        Ok(())
+    }
+
+    /// Create the vault's admin audit log (only program team)
+    /// Vaults created before the log existed can opt in at any time
+    pub fn init_audit_log(
+        ctx: Context<InitAuditLog>,
+    ) -> Result<()> {
+        let mut log = ctx.accounts.audit_log.load_init()?;
+        
+        log.vault = ctx.accounts.vault.key();
+        log.head = 0;
+        log.log_bump = ctx.bumps.audit_log;
+        
+        msg!("📜 Audit log created for vault");
+        Ok(())
+    }
+
+    /// Grant permission bits to a vault member, creating their role on first grant (only program team)
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        permissions: u16,
+    ) -> Result<()> {
+        require!(
+            permissions != 0 && permissions & !ALL_PERMISSIONS == 0,
+            BugBountyError::InvalidPermissions
+        );
+        
+        let role = &mut ctx.accounts.role;
+        role.vault = ctx.accounts.vault.key();
+        role.member = ctx.accounts.member.key();
+        role.permissions |= permissions;
+        role.role_bump = ctx.bumps.role;
+        
+        emit!(RoleUpdated {
+            vault: role.vault,
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            member: role.member,
+            permissions: role.permissions,
+        });
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            &mut ctx.accounts.vault,
+            AUDIT_GRANT_ROLE,
+            ctx.accounts.program_team.key(),
+            permissions as u64,
+        )?;
+        
+        msg!("🎖️ Role permissions now {:#09b}", ctx.accounts.role.permissions);
+        Ok(())
+    }
+
+    /// Revoke permission bits from a vault member, closing the role once none remain (only program team)
+    pub fn revoke_role(
+        ctx: Context<RevokeRole>,
+        permissions: u16,
+    ) -> Result<()> {
+        let role = &mut ctx.accounts.role;
+        role.permissions &= !permissions;
+        
+        emit!(RoleUpdated {
+            vault: role.vault,
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            member: role.member,
+            permissions: role.permissions,
+        });
+        
+        if role.permissions == 0 {
+            role.close(ctx.accounts.program_team.to_account_info())?;
+        }
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            &mut ctx.accounts.vault,
+            AUDIT_REVOKE_ROLE,
+            ctx.accounts.program_team.key(),
+            permissions as u64,
+        )?;
+        
+        msg!("🎖️ Role permissions now {:#09b}", ctx.accounts.role.permissions);
+        Ok(())
+    }
+
+    /// Cap what a delegate may approve, per report and in total (only governance, 0 = uncapped)
+    /// Caps can be raised or lowered on a live role; lowering below what is already spent
+    /// only blocks further approvals
+    pub fn set_delegation_caps(
+        ctx: Context<SetDelegationCaps>,
+        max_total_payout: u64,
+        max_single_payout: u64,
+    ) -> Result<()> {
+        let role = &mut ctx.accounts.role;
+        role.max_total_payout = max_total_payout;
+        role.max_single_payout = max_single_payout;
+        
+        emit!(DelegationCapsUpdated {
+            vault: role.vault,
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            member: role.member,
+            max_total_payout,
+            max_single_payout,
+            spent: role.spent,
+        });
+        
+        msg!("🎖️ Delegation caps set to {} total, {} per report", max_total_payout, max_single_payout);
+        Ok(())
+    }
+
+    /// Set the per-researcher open report cap (only program team, 0 means unlimited)
+    pub fn set_max_open_reports(
+        ctx: Context<UpdateRewardTiers>,
+        max_open_reports_per_researcher: u8,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.max_open_reports_per_researcher = max_open_reports_per_researcher;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_MAX_OPEN_REPORTS,
+            ctx.accounts.program_team.key(),
+            max_open_reports_per_researcher as u64,
+        )?;
+        
+        msg!("⚙️ Max open reports per researcher set to {}", max_open_reports_per_researcher);
+        Ok(())
+    }
+
+    /// Cap the vault's triage backlog of pending reports (only program team, 0 means unlimited)
+    /// Lowering it below the current backlog only blocks new submissions
+    pub fn set_max_pending_reports(
+        ctx: Context<UpdateRewardTiers>,
+        max_pending_reports: u16,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.max_pending_reports = max_pending_reports;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_MAX_PENDING_REPORTS,
+            ctx.accounts.program_team.key(),
+            max_pending_reports as u64,
+        )?;
+        
+        msg!("⚙️ Max pending reports set to {}", max_pending_reports);
+        Ok(())
+    }
+
+    /// Turn experimental features on or off for this vault (only program team)
+    /// Priority staking and severity disputes stay fixed while reports depend on them
+    pub fn set_feature_flags(
+        ctx: Context<UpdateRewardTiers>,
+        mask: u64,
+        enable: bool,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        require!(mask != 0 && mask & !ALL_FEATURES == 0, BugBountyError::InvalidFeatureFlags);
+        
+        let feature_flags = if enable { vault.feature_flags | mask } else { vault.feature_flags & !mask };
+        check_feature_toggle(vault, vault.feature_flags ^ feature_flags)?;
+        vault.feature_flags = feature_flags;
+        
+        emit!(FeatureFlagsUpdated {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            feature_flags,
+        });
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_FEATURE_FLAGS,
+            ctx.accounts.program_team.key(),
+            feature_flags,
+        )?;
+        
+        msg!("🚩 Feature flags set to {:#b}", feature_flags);
+        Ok(())
+    }
+
+    /// Freeze a report pending legal review (requires governance authority)
+    /// The report status is left untouched so release restores it as-is
+    pub fn hold_report(
+        ctx: Context<HoldReport>,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        require_permission(
+            ctx.accounts.governance_authority.key(),
+            vault.governance_authority,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_TRIAGE,
+            BugBountyError::NotGovernanceAuthority,
+        )?;
+        
+        require!(!report.on_hold, BugBountyError::ReportOnHold);
+        require!(
+            report.status == ReportStatus::Pending || report.status == ReportStatus::Approved,
+            BugBountyError::InvalidReportStatus
+        );
+        
+        report.on_hold = true;
+        report.hold_reason_hash = reason_hash;
+        report.held_at = Some(current_time(vault)?);
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_HOLD_REPORT,
+            ctx.accounts.governance_authority.key(),
+            0,
+        )?;
+        
+        msg!("⏸️ Report placed on hold with status {:?}", report.status);
+        Ok(())
+    }
+
+    /// Release a report from legal hold (requires governance authority)
+    pub fn release_report(
+        ctx: Context<HoldReport>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        require_permission(
+            ctx.accounts.governance_authority.key(),
+            vault.governance_authority,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_TRIAGE,
+            BugBountyError::NotGovernanceAuthority,
+        )?;
+        
+        require!(report.on_hold, BugBountyError::ReportNotOnHold);
+        
+        let held_for = release_hold(report, current_time(vault)?)?;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_RELEASE_REPORT,
+            ctx.accounts.governance_authority.key(),
+            held_for as u64,
+        )?;
+        
+        msg!("▶️ Report released from hold after {} seconds, status {:?}", held_for, report.status);
+        Ok(())
+    }
+
+    /// Publish the public write-up of a paid report (permissionless after the embargo)
+    /// Before the embargo ends both the researcher and governance must sign
+    pub fn publish_report(
+        ctx: Context<PublishReport>,
+        public_ipfs_hash: [u8; 32],
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        require!(report.disclosed_at.is_none(), BugBountyError::ReportAlreadyDisclosed);
+        let embargo_until = report.embargo_until.ok_or(BugBountyError::InvalidReportStatus)?;
+        
+        let now = current_time(vault)?;
+        let early = now < embargo_until;
+        if early {
+            let researcher_signed = ctx.accounts.researcher.as_ref()
+                .is_some_and(|s| s.key() == report.researcher);
+            let governance_signed = ctx.accounts.governance_authority.as_ref()
+                .is_some_and(|s| s.key() == vault.governance_authority);
+            require!(researcher_signed && governance_signed, BugBountyError::EmbargoActive);
+        }
+        
+        report.public_ipfs_hash = Some(public_ipfs_hash);
+        report.disclosed_at = Some(now);
+        
+        if let Some(reputation_nft) = ctx.accounts.reputation_nft.as_mut() {
+            require_keys_eq!(reputation_nft.report, report.key(), BugBountyError::ReputationReportMismatch);
+            reputation_nft.disclosed_report_hash = Some(public_ipfs_hash);
+        }
+        
+        emit!(ReportPublished {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            report: report.key(),
+            public_ipfs_hash,
+            disclosed_at: now,
+            early,
+        });
+        
+        msg!("📢 Report publicly disclosed");
+        Ok(())
+    }
+
+    /// Link an approved or paid report to the ticket that fixed it (only program team)
+    /// The disclosure embargo of a paid report restarts from the remediation time
+    pub fn mark_remediated(
+        ctx: Context<MarkRemediated>,
+        remediation_ref: [u8; 32],
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        require!(
+            report.status == ReportStatus::Approved || report.status == ReportStatus::Paid,
+            BugBountyError::InvalidReportStatus
+        );
+        require!(report.remediated_at.is_none(), BugBountyError::ReportAlreadyRemediated);
+        
+        let remediated_at = current_time(vault)?;
+        report.remediation_ref = remediation_ref;
+        report.remediated_at = Some(remediated_at);
+        
+        if report.embargo_until.is_some() {
+            report.embargo_until = Some(
+                remediated_at.checked_add(vault.embargo_seconds)
+                    .ok_or(BugBountyError::ArithmeticOverflow)?
+            );
+        }
+        
+        emit!(ReportRemediated {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            report: report.key(),
+            remediation_ref,
+            remediated_at,
+        });
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_MARK_REMEDIATED,
+            ctx.accounts.program_team.key(),
+            remediated_at as u64,
+        )?;
+        
+        msg!("🩹 Report marked as remediated");
+        Ok(())
+    }
+
+    /// Set the disclosure embargo applied to future payouts (only program team)
+    pub fn set_embargo_seconds(
+        ctx: Context<UpdateRewardTiers>,
+        embargo_seconds: i64,
+    ) -> Result<()> {
+        require!(embargo_seconds >= 0, BugBountyError::InvalidEmbargo);
+        validate_duration(embargo_seconds)?;
+        
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.embargo_seconds = embargo_seconds;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_EMBARGO,
+            ctx.accounts.program_team.key(),
+            embargo_seconds as u64,
+        )?;
+        
+        msg!("⚙️ Disclosure embargo set to {} seconds", embargo_seconds);
+        Ok(())
+    }
+
+    /// Set how long approved reports stay claimable (only program team, 0 means never expire)
+    pub fn set_claim_window_seconds(
+        ctx: Context<UpdateRewardTiers>,
+        claim_window_seconds: i64,
+    ) -> Result<()> {
+        require!(claim_window_seconds >= 0, BugBountyError::InvalidClaimWindow);
+        validate_duration(claim_window_seconds)?;
+        
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.claim_window_seconds = claim_window_seconds;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_CLAIM_WINDOW,
+            ctx.accounts.program_team.key(),
+            claim_window_seconds as u64,
+        )?;
+        
+        msg!("⚙️ Claim window set to {} seconds", claim_window_seconds);
+        Ok(())
+    }
+
+    /// Set the project name shown on reputation NFTs and who mints them
+    pub fn set_vault_metadata(
+        ctx: Context<UpdateRewardTiers>,
+        project_name: String,
+        team_mints_reputation: bool,
+    ) -> Result<()> {
+        require!(
+            !project_name.is_empty() && project_name.len() <= MAX_PROJECT_NAME_LEN,
+            BugBountyError::InvalidProjectName
+        );
+        
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.project_name = project_name;
+        vault.team_mints_reputation = team_mints_reputation;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_VAULT_METADATA,
+            ctx.accounts.program_team.key(),
+            team_mints_reputation as u64,
+        )?;
+        
+        msg!("⚙️ Vault metadata updated: {} (team mints reputation: {})", vault.project_name, team_mints_reputation);
+        Ok(())
+    }
+
+    /// Set or clear (empty string) one of the vault's decision reason templates (only program team)
+    pub fn set_reason_template(
+        ctx: Context<UpdateRewardTiers>,
+        index: u8,
+        template: String,
+    ) -> Result<()> {
+        require!((index as usize) < MAX_REASON_TEMPLATES, BugBountyError::InvalidReasonTemplate);
+        require!(template.len() <= REASON_TEMPLATE_LEN, BugBountyError::ReasonTooLong);
+        
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        let slot = &mut vault.reason_templates[index as usize];
+        *slot = [0; REASON_TEMPLATE_LEN];
+        slot[..template.len()].copy_from_slice(template.as_bytes());
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_REASON_TEMPLATE,
+            ctx.accounts.program_team.key(),
+            index as u64,
+        )?;
+        
+        msg!("⚙️ Reason template {} set to {:?}", index, template);
+        Ok(())
+    }
+
+    /// Rewrite a report created before reasons moved off-chain into the current layout (permissionless).
+    /// The old free-text reason is replaced by its hash; report_index is the vault's report count
+    /// when the report was submitted, used to prove the account is a legacy report PDA.
+    /// Legacy accounts are too small for the current layout, so the payer funds the growth.
+    pub fn migrate_report(
+        ctx: Context<MigrateReport>,
+        report_index: u64,
+    ) -> Result<()> {
+        let vault = &ctx.accounts.vault;
+        let report_info = ctx.accounts.report.to_account_info();
+        
+        let report = {
+            let data = report_info.try_borrow_data()?;
+            require!(
+                data.len() > 8 && data[..8] == *VulnerabilityReport::DISCRIMINATOR,
+                BugBountyError::NotLegacyReport
+            );
+            migrate_legacy_report(&data[8..])?
+        };
+        
+        // A current-layout account decodes to a different researcher, so the PDA check fails
+        let expected = Pubkey::create_program_address(
+            &[
+                REPORT_SEED,
+                vault.key().as_ref(),
+                report.researcher.as_ref(),
+                &report_index.to_le_bytes(),
+                &[report.report_bump],
+            ],
+            &crate::ID,
+        ).map_err(|_| BugBountyError::NotLegacyReport)?;
+        require!(
+            report.vault == vault.key() && expected == report_info.key(),
+            BugBountyError::NotLegacyReport
+        );
+        
+        let space = 8 + std::mem::size_of::<VulnerabilityReport>();
+        if report_info.data_len() < space {
+            let rent_exempt_minimum = Rent::get()?.minimum_balance(space);
+            let shortfall = rent_exempt_minimum.saturating_sub(report_info.lamports());
+            if shortfall > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.payer.to_account_info(),
+                            to: report_info.clone(),
+                        },
+                    ),
+                    shortfall,
+                )?;
+            }
+            report_info.resize(space)?;
+        }
+        
+        let mut data = report_info.try_borrow_mut_data()?;
+        data.fill(0);
+        report.try_serialize(&mut &mut data[..])?;
+        
+        msg!("🔁 Report migrated to layout version {}", REPORT_VERSION);
+        Ok(())
+    }
+
+    /// Rewrite a vault created before any vault field was added into the current layout
+    /// (permissionless). The account grows to the current size, with the payer funding the rent;
+    /// reward_mint must be the vault's reward mint so its decimals can be recorded.
+    pub fn migrate_vault(
+        ctx: Context<MigrateVault>,
+    ) -> Result<()> {
+        let vault_info = ctx.accounts.vault.to_account_info();
+        
+        let legacy = {
+            let data = vault_info.try_borrow_data()?;
+            require!(
+                data.len() == VAULT_V1_ACCOUNT_LEN && data[..8] == *BugBountyVault::DISCRIMINATOR,
+                BugBountyError::NotLegacyVault
+            );
+            VaultLayoutV1::deserialize(&mut &data[8..]).map_err(|_| BugBountyError::NotLegacyVault)?
+        };
+        let reward_decimals = match (legacy.reward_token_mint, ctx.accounts.reward_mint.as_ref()) {
+            (Some(mint), Some(reward_mint)) if reward_mint.key() == mint => reward_mint.decimals,
+            (None, None) => 0,
+            _ => return err!(BugBountyError::WrongRewardMint),
+        };
+        let vault = migrate_legacy_vault(legacy, vault_info.key(), reward_decimals)?;
+        
+        let space = 8 + std::mem::size_of::<BugBountyVault>() + MAX_PROJECT_NAME_LEN;
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(space);
+        let shortfall = rent_exempt_minimum.saturating_sub(vault_info.lamports());
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: vault_info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        vault_info.resize(space)?;
+        
+        let mut data = vault_info.try_borrow_mut_data()?;
+        data.fill(0);
+        vault.try_serialize(&mut &mut data[..])?;
+        
+        msg!("🔁 Vault migrated to the current layout");
+        Ok(())
+    }
+
+    /// Choose between compressed (Bubblegum) and regular reputation badges
+    pub fn set_compressed_reputation(
+        ctx: Context<UpdateRewardTiers>,
+        compressed_reputation: bool,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.compressed_reputation = compressed_reputation;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_COMPRESSED_REPUTATION,
+            ctx.accounts.program_team.key(),
+            compressed_reputation as u64,
+        )?;
+        
+        msg!("⚙️ Compressed reputation badges: {}", compressed_reputation);
+        Ok(())
+    }
+
+    /// Allow reputation badges for approved reports before (or without) an on-chain payout
+    pub fn set_reputation_on_approval(
+        ctx: Context<UpdateRewardTiers>,
+        reputation_on_approval: bool,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.reputation_on_approval = reputation_on_approval;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_REPUTATION_ON_APPROVAL,
+            ctx.accounts.program_team.key(),
+            reputation_on_approval as u64,
+        )?;
+        
+        msg!("⚙️ Reputation on approval: {}", reputation_on_approval);
+        Ok(())
+    }
+
+    /// Cap the balance fund_vault may bring the vault to (0 removes the cap)
+    /// The cap can never drop below funds already committed to approved reports
+    pub fn set_max_vault_balance(
+        ctx: Context<UpdateRewardTiers>,
+        max_vault_balance: u64,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        require!(
+            max_vault_balance == 0 || max_vault_balance >= vault.committed_amount,
+            BugBountyError::FundingCapBelowCommitted
+        );
+        
+        vault.max_vault_balance = max_vault_balance;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_MAX_VAULT_BALANCE,
+            ctx.accounts.program_team.key(),
+            max_vault_balance,
+        )?;
+        
+        msg!("⚙️ Max vault balance set to {}", max_vault_balance);
+        Ok(())
+    }
+
+    /// Configure (or clear) the reward decay window applied to new submissions
+    pub fn set_decay_config(
+        ctx: Context<UpdateRewardTiers>,
+        decay_config: Option<DecayConfig>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        if let Some(decay) = decay_config {
+            require!(decay.decay_end > decay.decay_start, BugBountyError::InvalidDecayConfig);
+            require!(
+                decay.start_multiplier_bps > 0 && decay.end_multiplier_bps > 0,
+                BugBountyError::InvalidDecayConfig
+            );
+            // The window may already be under way, but it must not have ended
+            validate_timestamp(decay.decay_end, current_time(vault)?)?;
+        }
+        
+        vault.decay_config = decay_config;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_DECAY_CONFIG,
+            ctx.accounts.program_team.key(),
+            decay_config.map_or(0, |decay| decay.start_multiplier_bps as u64),
+        )?;
+        
+        msg!("⚙️ Reward decay config set to {:?}", decay_config);
+        Ok(())
+    }
+
+    /// Require an attached invoice before any report in the vault can be paid
+    pub fn set_invoice_required(
+        ctx: Context<UpdateRewardTiers>,
+        invoice_required: bool,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.invoice_required = invoice_required;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_INVOICE_REQUIRED,
+            ctx.accounts.program_team.key(),
+            invoice_required as u64,
+        )?;
+        
+        msg!("⚙️ Invoice required: {}", invoice_required);
+        Ok(())
+    }
+
+    /// Set how long researchers must wait to submit again after a rejection (0 disables)
+    pub fn set_rejection_cooldown(
+        ctx: Context<UpdateRewardTiers>,
+        cooldown_after_rejection_seconds: i64,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        require!(cooldown_after_rejection_seconds >= 0, BugBountyError::InvalidRejectionCooldown);
+        validate_duration(cooldown_after_rejection_seconds)?;
+        
+        vault.cooldown_after_rejection_seconds = cooldown_after_rejection_seconds;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_REJECTION_COOLDOWN,
+            ctx.accounts.program_team.key(),
+            cooldown_after_rejection_seconds as u64,
+        )?;
+        
+        msg!("⚙️ Rejection cooldown set to {} seconds", cooldown_after_rejection_seconds);
+        Ok(())
+    }
+
+    /// Require tier amounts to be multiples of a base-unit granularity (0 disables);
+    /// the current tiers must already satisfy it
+    pub fn set_reward_granularity(
+        ctx: Context<UpdateRewardTiers>,
+        reward_granularity: u64,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        require_granular(
+            reward_granularity,
+            [vault.critical_reward, vault.high_reward, vault.medium_reward, vault.low_reward],
+        )?;
+        
+        vault.reward_granularity = reward_granularity;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_REWARD_GRANULARITY,
+            ctx.accounts.program_team.key(),
+            reward_granularity,
+        )?;
+        
+        msg!("⚙️ Reward granularity set to {} base units", reward_granularity);
+        Ok(())
+    }
+
+    /// Require program team co-approval for payouts at or above a threshold (0 disables)
+    pub fn set_dual_approval_threshold(
+        ctx: Context<UpdateTeamSetting>,
+        dual_approval_threshold: u64,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        vault.dual_approval_threshold = dual_approval_threshold;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_DUAL_APPROVAL_THRESHOLD,
+            ctx.accounts.program_team.key(),
+            dual_approval_threshold,
+        )?;
+        
+        msg!("⚙️ Dual approval threshold set to {}", dual_approval_threshold);
+        Ok(())
+    }
+
+    /// Program team sign-off on an approved report whose payout needs co-approval
+    pub fn co_approve_report(
+        ctx: Context<CoApproveReport>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        require!(report.status == ReportStatus::Approved, BugBountyError::ReportNotApproved);
+        require!(!report.on_hold, BugBountyError::ReportOnHold);
+        require!(requires_co_approval(vault, report), BugBountyError::CoApprovalNotRequired);
+        require!(!report.team_co_approved, BugBountyError::AlreadyCoApproved);
+        
+        report.team_co_approved = true;
+        
+        emit!(ReportCoApproved {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            report: report.key(),
+            program_team: ctx.accounts.program_team.key(),
+        });
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_CO_APPROVE_REPORT,
+            ctx.accounts.program_team.key(),
+            report.payout_amount,
+        )?;
+        
+        msg!("✍️ Report co-approved by the program team");
+        Ok(())
+    }
+
+    /// Let approvers approve reports they submitted themselves (only program team).
+    /// Meant for solo-dev vaults; the flag stays on the vault for anyone to see.
+    pub fn set_allow_self_approval(
+        ctx: Context<UpdateTeamSetting>,
+        allow_self_approval: bool,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        vault.allow_self_approval = allow_self_approval;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_ALLOW_SELF_APPROVAL,
+            ctx.accounts.program_team.key(),
+            allow_self_approval as u64,
+        )?;
+        
+        msg!("⚙️ Self-approval allowed: {}", allow_self_approval);
+        Ok(())
+    }
+
+    /// Lift confidentiality once the fix ships (requires governance authority)
+    /// Emits the real severity and payout so indexers can backfill
+    pub fn declassify_report(
+        ctx: Context<DeclassifyReport>,
+    ) -> Result<()> {
+        let report = &mut ctx.accounts.report;
+        
+        require!(report.confidential, BugBountyError::ReportNotConfidential);
+        
+        report.confidential = false;
+        
+        emit!(ReportDeclassified {
+            vault: report.vault,
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            report: report.key(),
+            researcher: report.researcher,
+            severity: report.severity,
+            status: report.status,
+            payout_amount: report.payout_amount,
+        });
+        
+        msg!("🔓 Report declassified with {:?} severity", report.severity);
+        Ok(())
+    }
+
+    /// Expire an approval left unpaid past the vault's claim window (permissionless)
+    /// The committed payout returns to the free balance; governance may re-approve later
+    pub fn expire_approval(
+        ctx: Context<ExpireApproval>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        require!(!report.on_hold, BugBountyError::ReportOnHold);
+        require!(report.status == ReportStatus::Approved, BugBountyError::ReportNotApproved);
+        require!(vault.claim_window_seconds > 0, BugBountyError::ClaimWindowDisabled);
+        
+        let now = current_time(vault)?;
+        require!(now >= claim_expires_at(vault, report)?, BugBountyError::ClaimWindowOpen);
+        
+        let mint_index = vault.mint_index(&report.payout_mint).ok_or(BugBountyError::UnknownRewardMint)?;
+        vault.release_payout(mint_index, report.payout_amount)?;
+        let vault_key = vault.key();
+        vault.transition_report(report, ReportStatus::Expired, vault_key)?;
+        
+        if report.approver_role_charged {
+            let approver_role = ctx.accounts.approver_role.as_mut()
+                .ok_or(BugBountyError::ApproverRoleRequired)?;
+            approver_role.refund_payout(report.payout_amount);
+            report.approver_role_charged = false;
+        }
+        release_open_report(report, ctx.accounts.submission_counter.as_mut())?;
+        
+        emit!(ApprovalExpired {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            report: report.key(),
+            released_amount: report.event_payout_amount(),
+            expired_at: now,
+        });
+        
+        msg!("⌛ Approval expired, committed payout returned to the free balance");
+        Ok(())
+    }
+
+    /// Escalate a rejected report to platform arbitration (researcher only, once per report)
+    /// Only vaults that opted into arbitration at creation accept escalations
+    pub fn escalate_to_platform(
+        ctx: Context<EscalateToPlatform>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        require!(vault.arbitration_enabled, BugBountyError::ArbitrationNotEnabled);
+        require!(vault.wind_down_phase != WindDownPhase::WoundDown, BugBountyError::InvalidWindDownPhase);
+        require!(report.status == ReportStatus::Rejected, BugBountyError::InvalidReportStatus);
+        require!(!report.escalated, BugBountyError::AlreadyEscalated);
+        
+        vault.transition_report(report, ReportStatus::InArbitration, ctx.accounts.researcher.key())?;
+        report.escalated = true;
+        
+        emit!(ReportEscalated {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            report: report.key(),
+            escalated_at: current_time(vault)?,
+        });
+        
+        msg!("⚖️ Report escalated to platform arbitration");
+        Ok(())
+    }
+
+    /// Settle an escalated report (platform admin only)
+    /// ForceApprove pays payout_override or the tier reward, Split defaults to half of it;
+    /// approved outcomes commit vault funds and are paid through execute_payout as usual
+    pub fn arbitrate(
+        ctx: Context<Arbitrate>,
+        outcome: ArbitrationOutcome,
+        payout_override: Option<u64>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        require!(vault.arbitration_enabled, BugBountyError::ArbitrationNotEnabled);
+        require!(report.status == ReportStatus::InArbitration, BugBountyError::InvalidReportStatus);
+        reprice_for_current_mint(vault, report)?;
+        
+        let (priced_amount, policy) = approval_price(vault, report)?;
+        let awarded = match outcome {
+            ArbitrationOutcome::UpholdRejection => None,
+            ArbitrationOutcome::ForceApprove => Some(payout_override.unwrap_or(priced_amount)),
+            ArbitrationOutcome::Split => Some(payout_override.unwrap_or(priced_amount / 2)),
+        };
+        
+        let arbitrated_at = current_time(vault)?;
+        match awarded {
+            Some(amount) => {
+                require!(amount > 0 || report.severity == SeverityTier::Informational, BugBountyError::ZeroAmount);
+                let mint_index = vault.mint_index(&report.payout_mint).ok_or(BugBountyError::UnknownRewardMint)?;
+                vault.commit_payout(mint_index, amount)?;
+                
+                report.payout_amount = amount;
+                report.priced_by = if payout_override.is_none() { Some(policy) } else { None };
+                vault.transition_report(report, ReportStatus::Approved, ctx.accounts.admin.key())?;
+                report.approver = Some(ctx.accounts.admin.key());
+                report.approved_at = Some(arbitrated_at);
+                // Arbitration exists to overrule the team, so it stands in for their co-approval
+                report.team_co_approved = true;
+            }
+            None => {
+                vault.transition_report(report, ReportStatus::Rejected, ctx.accounts.admin.key())?;
+            }
+        }
+        
+        emit!(ArbitrationResolved {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            report: report.key(),
+            outcome,
+            payout_amount: if report.confidential { None } else { awarded },
+            arbitrated_at,
+        });
+        
+        msg!("⚖️ Arbitration resolved: {:?}", outcome);
+        Ok(())
+    }
+
+    /// Stake primary reward tokens against mis-approval (only governance authority);
+    /// posting again tops the bond up
+    pub fn post_governance_bond(
+        ctx: Context<PostGovernanceBond>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, BugBountyError::ZeroAmount);
+        
+        let bond = &mut ctx.accounts.governance_bond;
+        if bond.vault == Pubkey::default() {
+            bond.vault = ctx.accounts.vault.key();
+            bond.governance_authority = ctx.accounts.governance_authority.key();
+            bond.escrow = ctx.accounts.bond_escrow.key();
+            bond.posted_at = current_time(&ctx.accounts.vault)?;
+            bond.bond_bump = ctx.bumps.governance_bond;
+        }
+        // A bond left behind by a rotated-out governance key must be withdrawn by that key first
+        require_keys_eq!(
+            bond.governance_authority,
+            ctx.accounts.governance_authority.key(),
+            BugBountyError::NotGovernanceAuthority
+        );
+        
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.governance_token_account.to_account_info(),
+                to: ctx.accounts.bond_escrow.to_account_info(),
+                authority: ctx.accounts.governance_authority.to_account_info(),
+            },
+        );
+        
+        token::transfer(cpi_ctx, amount)?;
+        
+        let vault = &mut ctx.accounts.vault;
+        vault.governance_bond_amount = vault.governance_bond_amount.checked_add(amount)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        
+        emit!(GovernanceBondUpdated {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            governance_authority: bond.governance_authority,
+            governance_bond_amount: vault.governance_bond_amount,
+            withdrawable_at: bond.withdrawable_at,
+        });
+        
+        msg!("🔏 Governance bond posted: {} (total {})", amount, vault.governance_bond_amount);
+        Ok(())
+    }
+
+    /// Start the cooldown before the governance bond can be withdrawn (only the bonded authority)
+    pub fn request_governance_bond_withdrawal(
+        ctx: Context<RequestGovernanceBondWithdrawal>,
+    ) -> Result<()> {
+        let bond = &mut ctx.accounts.governance_bond;
+        bond.withdrawable_at = current_time(&ctx.accounts.vault)?
+            .checked_add(GOVERNANCE_BOND_COOLDOWN_SECONDS)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        
+        let vault = &mut ctx.accounts.vault;
+        emit!(GovernanceBondUpdated {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            governance_authority: bond.governance_authority,
+            governance_bond_amount: vault.governance_bond_amount,
+            withdrawable_at: bond.withdrawable_at,
+        });
+        
+        msg!("🔏 Governance bond withdrawable at {}", bond.withdrawable_at);
+        Ok(())
+    }
+
+    /// Return the governance bond once its cooldown is over and no report is in arbitration
+    /// (only the bonded authority); closes the bond and its escrow
+    pub fn withdraw_governance_bond(
+        ctx: Context<WithdrawGovernanceBond>,
+    ) -> Result<()> {
+        let bond = &ctx.accounts.governance_bond;
+        let vault = &mut ctx.accounts.vault;
+        
+        require!(
+            bond.withdrawable_at != 0
+                && current_time(vault)? >= bond.withdrawable_at
+                && vault.open_arbitrations == 0,
+            BugBountyError::GovernanceBondLocked
+        );
+        
+        let vault_key = vault.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[GOVERNANCE_BOND_SEED, vault_key.as_ref(), &[bond.bond_bump]]];
+        let amount = ctx.accounts.bond_escrow.amount;
+        
+        if amount > 0 {
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.bond_escrow.to_account_info(),
+                    to: ctx.accounts.governance_token_account.to_account_info(),
+                    authority: bond.to_account_info(),
+                },
+                signer_seeds,
+            );
+            
+            token::transfer(cpi_ctx, amount)?;
+        }
+        
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::CloseAccount {
+                account: ctx.accounts.bond_escrow.to_account_info(),
+                destination: ctx.accounts.governance_authority.to_account_info(),
+                authority: bond.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::close_account(cpi_ctx)?;
+        
+        vault.governance_bond_amount = 0;
+        
+        emit!(GovernanceBondUpdated {
+            vault: vault_key,
+            sequence: vault.next_event_sequence()?,
+            governance_authority: bond.governance_authority,
+            governance_bond_amount: 0,
+            withdrawable_at: bond.withdrawable_at,
+        });
+        
+        msg!("🔏 Governance bond of {} withdrawn", amount);
+        Ok(())
+    }
+
+    /// Slash the governance bond back into the vault after arbitration found an approval
+    /// fraudulent (platform admin only); slashes at most what is bonded, once per report
+    pub fn slash_governance_bond(
+        ctx: Context<SlashGovernanceBond>,
+        amount: u64,
+        finding_hash: [u8; 32],
+    ) -> Result<()> {
+        let bond = &ctx.accounts.governance_bond;
+        let report = &mut ctx.accounts.report;
+        
+        require!(ctx.accounts.vault.arbitration_enabled, BugBountyError::ArbitrationNotEnabled);
+        require!(finding_hash != [0; 32], BugBountyError::EvidenceRequired);
+        require!(
+            matches!(report.status, ReportStatus::Approved | ReportStatus::Paid),
+            BugBountyError::ReportNotApproved
+        );
+        require!(report.approver == Some(bond.governance_authority), BugBountyError::NotBondedApproval);
+        require!(!report.bond_slashed, BugBountyError::BondAlreadySlashed);
+        
+        let slashed = amount.min(ctx.accounts.bond_escrow.amount);
+        require!(slashed > 0, BugBountyError::ZeroAmount);
+        
+        let vault_key = ctx.accounts.vault.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[GOVERNANCE_BOND_SEED, vault_key.as_ref(), &[bond.bond_bump]]];
+        
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.bond_escrow.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: bond.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::transfer(cpi_ctx, slashed)?;
+        report.bond_slashed = true;
+        
+        let vault = &mut ctx.accounts.vault;
+        vault.total_funded = vault.total_funded.checked_add(slashed)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        vault.governance_bond_amount = vault.governance_bond_amount.saturating_sub(slashed);
+        ctx.accounts.vault_token_account.reload()?;
+        assert_vault_invariants(vault, Some(ctx.accounts.vault_token_account.amount))?;
+        
+        emit!(GovernanceBondSlashed {
+            vault: vault_key,
+            sequence: vault.next_event_sequence()?,
+            report: report.key(),
+            slashed,
+            governance_bond_amount: vault.governance_bond_amount,
+            finding_hash,
+        });
+        
+        msg!("🔪 Governance bond slashed by {} into the vault", slashed);
+        Ok(())
+    }
+
+    /// Attach (or replace) a proof-of-concept transaction reference while the report awaits triage
+    pub fn attach_poc(
+        ctx: Context<AttachPoc>,
+        poc: PocReference,
+    ) -> Result<()> {
+        require!(ctx.accounts.report.status == ReportStatus::Pending, BugBountyError::InvalidReportStatus);
+        
+        set_poc(&mut ctx.accounts.vault, &mut ctx.accounts.report, poc)?;
+        
+        msg!("🔗 Proof-of-concept transaction attached to report");
+        Ok(())
+    }
+
+    /// Contest the severity an approved report was paid at (researcher only, once per report)
+    /// The payout is frozen until governance settles the final tier
+    pub fn dispute_severity(
+        ctx: Context<DisputeSeverity>,
+        claimed_severity: SeverityTier,
+        evidence_hash: [u8; 32],
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        require_feature(vault, FEATURE_SEVERITY_DISPUTES)?;
+        require!(!report.on_hold, BugBountyError::ReportOnHold);
+        require!(report.status == ReportStatus::Approved, BugBountyError::ReportNotApproved);
+        require!(report.disputed_severity.is_none(), BugBountyError::SeverityAlreadyDisputed);
+        require!(claimed_severity != report.severity, BugBountyError::InvalidSeverityDispute);
+        require!(evidence_hash != [0; 32], BugBountyError::InvalidSeverityDispute);
+        
+        report.disputed_severity = Some(claimed_severity);
+        report.dispute_evidence_hash = evidence_hash;
+        report.approved_severity = Some(report.severity);
+        vault.transition_report(report, ReportStatus::SeverityDisputed, ctx.accounts.researcher.key())?;
+        
+        let claimed = if report.confidential { EventSeverity::Unclassified } else { claimed_severity.into() };
+        emit!(SeverityDisputed {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            report: report.key(),
+            approved_severity: report.event_severity(),
+            claimed_severity: claimed,
+            evidence_hash,
+        });
+        
+        msg!("⚖️ Severity classification disputed");
+        Ok(())
+    }
+
+    /// Settle a severity dispute (governance authority only): the report moves to final_severity,
+    /// its payout is re-priced and re-reserved, and it returns to Approved with a fresh claim window
+    pub fn resolve_severity_dispute(
+        ctx: Context<ResolveSeverityDispute>,
+        final_severity: SeverityTier,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        require!(!report.on_hold, BugBountyError::ReportOnHold);
+        require!(report.status == ReportStatus::SeverityDisputed, BugBountyError::InvalidReportStatus);
+        
+        let previous_amount = report.payout_amount;
+        reprice_disputed_report(vault, report, final_severity)?;
+        let payout_amount = report.payout_amount;
+        require!(
+            payout_amount > 0 || final_severity == SeverityTier::Informational,
+            BugBountyError::ZeroAmount
+        );
+        
+        // Swap the old reservation for the new one
+        let mint_index = vault.mint_index(&report.payout_mint).ok_or(BugBountyError::UnknownRewardMint)?;
+        vault.release_payout(mint_index, previous_amount)?;
+        vault.commit_payout(mint_index, payout_amount)?;
+        // A larger payout may now cross the dual approval threshold
+        if payout_amount > previous_amount {
+            report.team_co_approved = false;
+        }
+        
+        let resolved_at = current_time(vault)?;
+        report.dispute_resolved_at = Some(resolved_at);
+        report.approved_at = Some(resolved_at);
+        vault.transition_report(report, ReportStatus::Approved, ctx.accounts.governance_authority.key())?;
+        
+        emit!(SeverityDisputeResolved {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            report: report.key(),
+            final_severity: report.event_severity(),
+            payout_amount: report.event_payout_amount(),
+            resolved_at,
+        });
+        
+        msg!("⚖️ Severity dispute resolved as {:?}", report.event_severity());
+        Ok(())
+    }
+
+    /// Let a researcher's reports up to a severity and amount approve on submission
+    /// (only governance authority); granting again replaces the limits
+    pub fn grant_trusted_researcher(
+        ctx: Context<GrantTrustedResearcher>,
+        researcher: Pubkey,
+        auto_approve_max_severity: SeverityTier,
+        auto_approve_max_amount: u64,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        require!(
+            vault.allow_self_approval || researcher != vault.governance_authority,
+            BugBountyError::SelfApprovalForbidden
+        );
+        
+        let trusted = &mut ctx.accounts.trusted_researcher;
+        trusted.vault = vault.key();
+        trusted.researcher = researcher;
+        trusted.auto_approve_max_severity = auto_approve_max_severity;
+        trusted.auto_approve_max_amount = auto_approve_max_amount;
+        trusted.granted_by = ctx.accounts.governance_authority.key();
+        trusted.granted_at = current_time(vault)?;
+        trusted.trusted_bump = ctx.bumps.trusted_researcher;
+        
+        emit!(TrustedResearcherUpdated {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            researcher,
+            trusted: true,
+            auto_approve_max_severity,
+            auto_approve_max_amount,
+        });
+        
+        msg!(
+            "🤝 Trusted researcher: auto-approving {:?} and below up to {}",
+            auto_approve_max_severity,
+            auto_approve_max_amount
+        );
+        Ok(())
+    }
+
+    /// Withdraw a researcher's trusted status (only governance authority)
+    /// Reports already auto-approved keep their approval
+    pub fn revoke_trusted_researcher(
+        ctx: Context<RevokeTrustedResearcher>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let trusted = &ctx.accounts.trusted_researcher;
+        
+        emit!(TrustedResearcherUpdated {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            researcher: trusted.researcher,
+            trusted: false,
+            auto_approve_max_severity: trusted.auto_approve_max_severity,
+            auto_approve_max_amount: trusted.auto_approve_max_amount,
+        });
+        
+        msg!("🤝 Trusted researcher status revoked");
+        Ok(())
+    }
+
+    /// Attest a researcher's track record on another platform (only platform admin);
+    /// attesting again replaces the previous attestation
+    pub fn attest_external_reputation(
+        ctx: Context<AttestExternalReputation>,
+        researcher: Pubkey,
+        source: u8,
+        score: u32,
+        expires_at: i64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(expires_at > now, BugBountyError::AttestationExpired);
+        validate_timestamp(expires_at, now)?;
+        
+        let attestation = &mut ctx.accounts.external_attestation;
+        attestation.researcher = researcher;
+        attestation.source = source;
+        attestation.score = score;
+        attestation.expires_at = expires_at;
+        attestation.issued_by = ctx.accounts.admin.key();
+        attestation.issued_at = now;
+        attestation.attestation_bump = ctx.bumps.external_attestation;
+        
+        if let Some(profile) = ctx.accounts.researcher_profile.as_mut() {
+            profile.external_source = source;
+            profile.external_score = score;
+        }
+        
+        emit!(ExternalAttestationUpdated {
+            researcher,
+            source,
+            score,
+            expires_at,
+            revoked: false,
+        });
+        
+        msg!("🌐 External reputation attested: score {} from source {}", score, source);
+        Ok(())
+    }
+
+    /// Withdraw an external attestation before it expires (only platform admin)
+    pub fn revoke_external_attestation(
+        ctx: Context<RevokeExternalAttestation>,
+    ) -> Result<()> {
+        let attestation = &ctx.accounts.external_attestation;
+        
+        if let Some(profile) = ctx.accounts.researcher_profile.as_mut() {
+            profile.external_source = 0;
+            profile.external_score = 0;
+        }
+        
+        emit!(ExternalAttestationUpdated {
+            researcher: attestation.researcher,
+            source: attestation.source,
+            score: attestation.score,
+            expires_at: attestation.expires_at,
+            revoked: true,
+        });
+        
+        msg!("🌐 External attestation revoked");
+        Ok(())
+    }
+
+    /// Send an unpaid auto-approved report back to triage (only governance authority)
+    pub fn revoke_auto_approval(
+        ctx: Context<RevokeAutoApproval>,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        require!(report.auto_approved, BugBountyError::NotAutoApproved);
+        require!(report.status == ReportStatus::Approved, BugBountyError::ReportNotApproved);
+        
+        let mint_index = vault.mint_index(&report.payout_mint).ok_or(BugBountyError::UnknownRewardMint)?;
+        vault.release_payout(mint_index, report.payout_amount)?;
+        vault.approved_reports = vault.approved_reports.saturating_sub(1);
+        
+        vault.transition_report(report, ReportStatus::Pending, ctx.accounts.governance_authority.key())?;
+        report.approver = None;
+        report.approved_at = None;
+        report.approved_policy_version = None;
+        report.auto_approved = false;
+        
+        emit!(AutoApprovalRevoked {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            report: report.key(),
+            revoked_by: ctx.accounts.governance_authority.key(),
+            reason_hash,
+        });
+        
+        msg!("↩️ Auto-approval revoked; report is back in triage");
+        Ok(())
+    }
+
+    /// Attach (or replace) the invoice reference of an approved report before it is paid
+    pub fn attach_invoice(
+        ctx: Context<AttachInvoice>,
+        invoice_hash: [u8; 32],
+    ) -> Result<()> {
+        let report = &mut ctx.accounts.report;
+        
+        // Paid reports are settled, so their invoice can no longer change
+        require!(report.status == ReportStatus::Approved, BugBountyError::ReportNotApproved);
+        require!(invoice_hash != [0; 32], BugBountyError::InvoiceRequired);
+        
+        report.invoice_hash = invoice_hash;
+        
+        emit!(InvoiceAttached {
+            vault: ctx.accounts.vault.key(),
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            report: report.key(),
+            invoice_hash,
+            attached_by: ctx.accounts.authority.key(),
+        });
+        
+        msg!("🧾 Invoice attached to report");
+        Ok(())
+    }
+
+    /// Execute automatic payout after approval
+    /// Boosts for the report are passed as remaining_accounts triples, see MAX_BOOSTS_PER_PAYOUT
+    pub fn execute_payout<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecutePayout<'info>>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        check_payout_signer(ctx.accounts.researcher.key(), report, ctx.accounts.firm_member.as_deref())?;
+        require!(!vault.circuit_breaker_tripped, BugBountyError::CircuitBreakerTripped);
+        
+        // Verify report is approved and not frozen
+        require!(!report.on_hold, BugBountyError::ReportOnHold);
+        require!(report.status != ReportStatus::SeverityDisputed, BugBountyError::SeverityDisputeOpen);
+        require!(report.status == ReportStatus::Approved, BugBountyError::ReportNotApproved);
+        if report.auto_approved {
+            let approved_at = report.approved_at.ok_or(BugBountyError::ReportNotApproved)?;
+            let unlocks_at = approved_at.checked_add(AUTO_APPROVAL_TIMELOCK_SECONDS)
+                .ok_or(BugBountyError::ArithmeticOverflow)?;
+            require!(current_time(vault)? >= unlocks_at, BugBountyError::AutoApprovalTimelockActive);
+        }
+        
+        let PayoutBreakdown {
+            payout_amount,
+            researcher_amount,
+            donation_amount,
+            retained_dust_amount,
+            co_approval_required,
+            ..
+        } = payout_breakdown(vault, report)?;
+        
+        require!(
+            !vault.invoice_required || report.invoice_hash != [0; 32],
+            BugBountyError::InvoiceRequired
+        );
+        
+        // Large payouts need the team's sign-off on top of governance approval
+        require!(
+            !co_approval_required || report.team_co_approved,
+            BugBountyError::CoApprovalRequired
+        );
+        
+        // Validate token accounts up front so failures don't surface as raw SPL errors
+        let mint_index = vault.mint_index(&report.payout_mint).ok_or(BugBountyError::UnknownRewardMint)?;
+        require_keys_eq!(
+            ctx.accounts.vault_token_account.key(),
+            vault.mint_token_account(mint_index)?,
+            BugBountyError::VaultTokenAccountMismatch
+        );
+        let vault_token = load_token_account(&ctx.accounts.vault_token_account)?;
+        require!(vault_token.amount >= payout_amount, BugBountyError::InsufficientVaultBalance);
+        
+        // Drain protection: one approval can't empty the vault, whatever the tier caps allow
+        require!(
+            !vault.exceeds_payout_fraction(payout_amount, vault_token.amount),
+            BugBountyError::PayoutFractionExceeded
+        );
+        
+        // A reported governance compromise holds back anything bigger than a Low payout
+        require!(
+            !vault.held_by_compromise_alert(&report.payout_mint, payout_amount),
+            BugBountyError::CompromiseAlertOpen
+        );
+        
+        let vault_token_account = ctx.accounts.vault_token_account.key();
+        let claim_escrow = ctx.accounts.claim_escrow.as_ref().map(|escrow| escrow.key());
+        let researcher_leg = claim_escrow.unwrap_or_else(|| ctx.accounts.researcher_token_account.key());
+        check_payout_destination(researcher_leg, vault_token_account, None)?;
+        
+        if ctx.accounts.claim_escrow.is_none() {
+            // Checked against the token account's owner field, so an off-curve recipient works
+            let researcher_token = load_token_account(&ctx.accounts.researcher_token_account)?;
+            require_keys_eq!(researcher_token.owner, report.payout_owner(), BugBountyError::TokenAccountOwnerMismatch);
+            require_keys_eq!(researcher_token.mint, vault_token.mint, BugBountyError::WrongRewardMint);
+            require_keys_eq!(
+                ctx.accounts.researcher_token_account.key(),
+                get_associated_token_address(&report.payout_owner(), &researcher_token.mint),
+                BugBountyError::DestinationNotAssociatedTokenAccount
+            );
+        }
+        
+        if donation_amount > 0 {
+            let donation_token_account = ctx.accounts.donation_token_account.as_ref()
+                .ok_or(BugBountyError::DonationAccountRequired)?;
+            require!(
+                Some(donation_token_account.owner) == report.donation_recipient,
+                BugBountyError::DonationRecipientMismatch
+            );
+            require_keys_eq!(donation_token_account.mint, vault_token.mint, BugBountyError::WrongRewardMint);
+            check_payout_destination(donation_token_account.key(), vault_token_account, claim_escrow)?;
+        }
+        
+        // The payout that would break a limit trips the breaker instead of paying. It returns
+        // Ok so the trip persists; the report stays Approved for after the team's review
+        let paid_at = current_time(vault)?;
+        let (hourly_payouts, hourly_outflow) = vault.hourly_payouts_with(payout_amount, paid_at)?;
+        if vault.breaks_circuit(hourly_payouts, hourly_outflow) {
+            vault.circuit_breaker_tripped = true;
+            
+            emit!(PayoutCircuitBreakerTripped {
+                vault: vault.key(),
+                sequence: vault.next_event_sequence()?,
+                report: report.key(),
+                payout_amount,
+                hourly_payouts,
+                hourly_outflow,
+                max_payouts_per_hour: vault.max_payouts_per_hour,
+                max_hourly_outflow: vault.max_hourly_outflow,
+                tripped_at: paid_at,
+                subscriber_count: vault.subscriber_count,
+            });
+            
+            msg!(
+                "🚨 CIRCUIT BREAKER TRIPPED: {} payouts / {} outflow in the last hour (limits {} / {}); payouts halted",
+                hourly_payouts,
+                hourly_outflow,
+                vault.max_payouts_per_hour,
+                vault.max_hourly_outflow
+            );
+            return Ok(());
+        }
+        vault.record_hourly_payout(payout_amount, paid_at)?;
+        
+        // Settle the report before any transfer: a second payout for it, even in the same
+        // slot, is serialized behind this write lock and then fails the status check.
+        // A failed transfer below fails the instruction, which reverts this write too.
+        // Retained dust never leaves, so it is released back to the available balance
+        let transferred = payout_amount.checked_sub(retained_dust_amount)
+            .ok_or(BugBountyError::ArithmeticUnderflow)?;
+        vault.settle_payout(mint_index, transferred)?;
+        vault.release_payout(mint_index, retained_dust_amount)?;
+        
+        vault.transition_report(report, ReportStatus::Paid, ctx.accounts.researcher.key())?;
+        report.paid_at = Some(paid_at);
+        report.embargo_until = Some(
+            report.remediated_at.unwrap_or(paid_at).checked_add(vault.embargo_seconds)
+                .ok_or(BugBountyError::ArithmeticOverflow)?
+        );
+        
+        release_open_report(report, ctx.accounts.submission_counter.as_mut())?;
+        if let Some(researcher_profile) = ctx.accounts.researcher_profile.as_mut() {
+            // Only the primary mint's decimals are known, so extra-mint payouts earn no tokens
+            let earned_tokens = if mint_index == 0 { whole_tokens(payout_amount, vault.reward_decimals) } else { 0 };
+            researcher_profile.record_payout(report.severity, payout_amount, earned_tokens)?;
+        }
+        
+        let seeds = vault.signer_seeds();
+        let signer_seeds: &[&[&[u8]]] = &[&seeds];
+        
+        // Route the researcher leg into the claim escrow when one is supplied
+        let destination = match ctx.accounts.claim_escrow.as_ref() {
+            Some(claim_escrow) => {
+                require!(
+                    Some(claim_escrow.key()) == report.claim_escrow,
+                    BugBountyError::InvalidClaimEscrow
+                );
+                report.claim_escrow_amount = report.claim_escrow_amount.checked_add(researcher_amount)
+                    .ok_or(BugBountyError::ArithmeticOverflow)?;
+                claim_escrow.to_account_info()
+            }
+            None => ctx.accounts.researcher_token_account.to_account_info(),
+        };
+        
+        // Informational reports pay nothing, so they are marked Paid without a transfer
+        if researcher_amount > 0 {
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_token_account.to_account_info(),
+                    to: destination,
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                signer_seeds,
+            );
+            
+            token::transfer(cpi_ctx, researcher_amount)?;
+        }
+        
+        if donation_amount > 0 {
+            let donation_token_account = ctx.accounts.donation_token_account.as_ref()
+                .ok_or(BugBountyError::DonationAccountRequired)?;
+            
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_token_account.to_account_info(),
+                    to: donation_token_account.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                signer_seeds,
+            );
+            
+            token::transfer(cpi_ctx, donation_amount)?;
+        }
+        
+        pay_boosts(
+            vault,
+            report,
+            ctx.remaining_accounts,
+            ctx.accounts.token_program.to_account_info(),
+            paid_at,
+        )?;
+        
+        // The token account only backs the primary books when paying in the primary mint
+        let token_balance = match mint_index {
+            0 => Some(load_token_account(&ctx.accounts.vault_token_account)?.amount),
+            _ => None,
+        };
+        assert_vault_invariants(vault, token_balance)?;
+        update_report_summary(vault, ctx.accounts.report_summary.as_mut(), report)?;
+        
+        let confidential = report.confidential;
+        emit!(PayoutReceipt {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            report: report.key(),
+            researcher: report.researcher,
+            researcher_amount: if confidential { 0 } else { researcher_amount },
+            donation_recipient: report.donation_recipient,
+            donation_amount: if confidential { 0 } else { donation_amount },
+            paid_at,
+            confidential,
+            mint: vault_token.mint,
+            invoice_hash: report.invoice_hash,
+            token_account_generation: vault.token_account_generation,
+            gross_amount: if confidential { 0 } else { payout_amount },
+            retained_dust_amount: if confidential { 0 } else { retained_dust_amount },
+        });
+        
+        if confidential {
+            msg!("💰 Confidential payout executed");
+        } else {
+            msg!("💰 Payout of {} executed to researcher", payout_amount);
+        }
+        Ok(())
+    }
+
+    /// Permissionless: award a milestone badge once the researcher's profile counters reach it
+    pub fn claim_milestone(
+        ctx: Context<ClaimMilestone>,
+        milestone_id: u8,
+    ) -> Result<()> {
+        let profile = &ctx.accounts.researcher_profile;
+        let milestone = MILESTONES.get(milestone_id as usize).ok_or(BugBountyError::UnknownMilestone)?;
+        require!(
+            profile.progress(milestone.metric) >= milestone.threshold,
+            BugBountyError::MilestoneNotReached
+        );
+        
+        let badge = &mut ctx.accounts.milestone_badge;
+        badge.researcher = profile.researcher;
+        badge.milestone_id = milestone_id;
+        badge.badge_bump = ctx.bumps.milestone_badge;
+        badge.claimed_at = Clock::get()?.unix_timestamp;
+        badge.rent_payer = ctx.accounts.payer.key();
+        
+        emit!(MilestoneClaimed {
+            researcher: profile.researcher,
+            milestone_id,
+            metric: milestone.metric,
+            threshold: milestone.threshold,
+        });
+        
+        msg!("🏅 Milestone {} claimed", milestone_id);
+        Ok(())
+    }
+
+    /// Read-only reward tiers a report was judged under: its approval's policy version once
+    /// approved, else its submission's. Pass that PolicyVersion, or the next one (whose
+    /// previous_rewards hold it); none is needed while the version is still current
+    pub fn get_report_policy(
+        ctx: Context<ReadReportPolicy>,
+    ) -> Result<ReportPolicy> {
+        let vault = &ctx.accounts.vault;
+        let report = &ctx.accounts.report;
+        let judged = report.approved_policy_version.unwrap_or(report.submitted_policy_version);
+        
+        let rewards = if judged == vault.policy_version {
+            [vault.critical_reward, vault.high_reward, vault.medium_reward, vault.low_reward]
+        } else {
+            let policy = ctx.accounts.policy_version.as_ref()
+                .ok_or(BugBountyError::PolicyVersionMismatch)?;
+            if policy.version == judged {
+                policy.rewards
+            } else if Some(policy.version) == judged.checked_add(1) {
+                policy.previous_rewards
+            } else {
+                return err!(BugBountyError::PolicyVersionMismatch);
+            }
+        };
+        
+        Ok(ReportPolicy {
+            submitted_policy_version: report.submitted_policy_version,
+            approved_policy_version: report.approved_policy_version,
+            judged_policy_version: judged,
+            rewards,
+        })
+    }
+
+    /// Read-only payout breakdown for clients to simulate before the token accounts exist
+    pub fn preview_payout(
+        ctx: Context<PreviewPayout>,
+    ) -> Result<PayoutBreakdown> {
+        payout_breakdown(&ctx.accounts.vault, &ctx.accounts.report)
+    }
+
+    /// Read-only budget and counters for a vault
+    pub fn get_vault_status(
+        ctx: Context<ReadVault>,
+    ) -> Result<VaultStatus> {
+        let vault = &ctx.accounts.vault;
+        Ok(VaultStatus {
+            vault_active: vault.vault_active,
+            submissions_open: vault.submissions_open,
+            recorded_balance: vault.recorded_balance()?,
+            committed_amount: vault.committed_amount,
+            insurance_reserve: vault.insurance_reserve,
+            available_balance: vault.available_balance()?,
+            total_reports: vault.total_reports,
+            approved_reports: vault.approved_reports,
+            rejected_reports: vault.rejected_reports,
+            withdrawn_reports: vault.withdrawn_reports,
+            total_paid_out: vault.total_paid_out,
+            paused_tiers: vault.paused_tiers,
+            first_responses: vault.first_responses,
+            total_first_response_seconds: vault.total_first_response_seconds,
+            pending_reports: vault.pending_reports,
+            max_pending_reports: vault.max_pending_reports,
+            rent_subsidy_budget: vault.rent_subsidy_budget,
+        })
+    }
+
+    /// Read-only payout per severity for reports submitted now
+    pub fn get_effective_rewards(
+        ctx: Context<ReadVault>,
+    ) -> Result<EffectiveRewards> {
+        let vault = &ctx.accounts.vault;
+        
+        // What a report submitted now would be worth
+        let now = current_time(vault)?;
+        let multiplier_bps = vault.decay_config
+            .map_or(BPS_DENOMINATOR as u16, |decay| decay.multiplier_bps_at(now));
+        let effective = |severity: SeverityTier| bps_of(severity.reward_for(vault), multiplier_bps);
+        Ok(EffectiveRewards {
+            critical: effective(SeverityTier::Critical)?,
+            high: effective(SeverityTier::High)?,
+            medium: effective(SeverityTier::Medium)?,
+            low: effective(SeverityTier::Low)?,
+            informational: effective(SeverityTier::Informational)?,
+        })
+    }
+
+    /// Read-only check of whether submit_report would accept the researcher right now
+    pub fn can_submit(
+        ctx: Context<CanSubmit>,
+        _researcher: Pubkey,
+    ) -> Result<SubmitEligibility> {
+        let vault = &ctx.accounts.vault;
+        let counter = ctx.accounts.submission_counter.as_deref();
+        let open_reports = counter.map(|counter| counter.open_reports);
+        
+        let error_code = match check_can_submit(vault, counter, current_time(vault)?) {
+            Ok(()) => None,
+            Err(Error::AnchorError(error)) => Some(error.error_code_number),
+            Err(error) => return Err(error),
+        };
+        
+        Ok(SubmitEligibility {
+            allowed: error_code.is_none(),
+            error_code,
+            open_reports: open_reports.unwrap_or(0),
+            max_open_reports: vault.max_open_reports_per_researcher,
+        })
+    }
+
+    /// Route part of a future payout to a donation address (researcher only)
+    /// Setting donation_bps to 0 clears the split
+    pub fn set_donation(
+        ctx: Context<SetDonation>,
+        donation_bps: u16,
+        donation_recipient: Option<Pubkey>,
+    ) -> Result<()> {
+        let report = &mut ctx.accounts.report;
+        
+        require!(!report.on_hold, BugBountyError::ReportOnHold);
+        require!(
+            report.status == ReportStatus::Pending || report.status == ReportStatus::Approved,
+            BugBountyError::InvalidReportStatus
+        );
+        require!(donation_bps as u64 <= BPS_DENOMINATOR, BugBountyError::InvalidDonationBps);
+        
+        if donation_bps == 0 {
+            report.donation_bps = 0;
+            report.donation_recipient = None;
+        } else {
+            let recipient = donation_recipient.ok_or(BugBountyError::DonationRecipientMismatch)?;
+            report.donation_bps = donation_bps;
+            report.donation_recipient = Some(recipient);
+        }
+        
+        msg!("🎗️ Donation split set to {} bps", report.donation_bps);
+        Ok(())
+    }
+
+    /// Pay a report out to another owner's token account, e.g. the firm's multisig vault
+    /// (researcher only); None pays the researcher again
+    pub fn set_payout_recipient(
+        ctx: Context<SetPayoutRecipient>,
+        payout_recipient: Option<Pubkey>,
+    ) -> Result<()> {
+        let report = &mut ctx.accounts.report;
+        
+        require!(!report.on_hold, BugBountyError::ReportOnHold);
+        require!(
+            report.status == ReportStatus::Pending || report.status == ReportStatus::Approved,
+            BugBountyError::InvalidReportStatus
+        );
+        
+        report.payout_recipient = payout_recipient.filter(|recipient| *recipient != report.researcher);
+        
+        msg!("🏢 Payout recipient set to {}", report.payout_owner());
+        Ok(())
+    }
+
+    /// Let a member crank payouts for reports paying out to the signing recipient
+    pub fn register_firm_member(
+        ctx: Context<RegisterFirmMember>,
+        member: Pubkey,
+    ) -> Result<()> {
+        let firm_member = &mut ctx.accounts.firm_member;
+        firm_member.recipient = ctx.accounts.recipient.key();
+        firm_member.member = member;
+        firm_member.registered_at = Clock::get()?.unix_timestamp;
+        firm_member.rent_payer = ctx.accounts.payer.key();
+        firm_member.firm_member_bump = ctx.bumps.firm_member;
+        
+        emit!(FirmMemberUpdated {
+            recipient: firm_member.recipient,
+            member,
+            registered: true,
+        });
+        
+        msg!("🏢 Firm member registered");
+        Ok(())
+    }
+
+    /// Withdraw a member's payout rights (recipient only); rent returns to whoever paid it
+    pub fn remove_firm_member(
+        ctx: Context<RemoveFirmMember>,
+    ) -> Result<()> {
+        emit!(FirmMemberUpdated {
+            recipient: ctx.accounts.firm_member.recipient,
+            member: ctx.accounts.firm_member.member,
+            registered: false,
+        });
+        
+        msg!("🏢 Firm member removed");
+        Ok(())
+    }
+
+    /// Open a per-report claim escrow so the payout can later be claimed in another token
+    pub fn open_claim_escrow(
+        ctx: Context<OpenClaimEscrow>,
+    ) -> Result<()> {
+        let report = &mut ctx.accounts.report;
+        
+        require!(report.claim_escrow.is_none(), BugBountyError::InvalidClaimEscrow);
+        require!(
+            report.status == ReportStatus::Pending || report.status == ReportStatus::Approved,
+            BugBountyError::InvalidReportStatus
+        );
+        
+        report.claim_escrow = Some(ctx.accounts.claim_escrow.key());
+        
+        msg!("🔐 Claim escrow opened for report");
+        Ok(())
+    }
+
+    /// Claim the escrowed payout in the original token
+    pub fn claim_escrow(
+        ctx: Context<ClaimEscrow>,
+    ) -> Result<()> {
+        let report = &mut ctx.accounts.report;
+        let amount = ctx.accounts.claim_escrow.amount;
+        
+        require!(amount > 0, BugBountyError::EscrowEmpty);
+        
+        let report_key = report.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            ESCROW_AUTHORITY_SEED,
+            report_key.as_ref(),
+            &[ctx.bumps.escrow_authority],
+        ]];
+        
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.claim_escrow.to_account_info(),
+                to: ctx.accounts.destination_token_account.to_account_info(),
+                authority: ctx.accounts.escrow_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::transfer(cpi_ctx, amount)?;
+        
+        report.claim_escrow_amount = 0;
+        
+        emit!(EscrowClaimed {
+            vault: ctx.accounts.vault.key(),
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            report: report_key,
+            researcher: report.researcher,
+            amount_in: amount,
+            amount_out: amount,
+            output_mint: ctx.accounts.destination_token_account.mint,
+        });
+        
+        msg!("📤 Escrow of {} claimed in original token", amount);
+        Ok(())
+    }
+
+    /// Claim the escrowed payout through a whitelisted swap program
+    /// Route accounts are passed through remaining_accounts; if the swap fails the
+    /// transaction reverts and the escrow stays claimable in the original token
+    pub fn claim_as<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimAs<'info>>,
+        min_amount_out: u64,
+        route_data: Vec<u8>,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.platform_config.is_whitelisted_swap_program(&ctx.accounts.swap_program.key()),
+            BugBountyError::SwapProgramNotWhitelisted
+        );
+        require!(ctx.accounts.claim_escrow.amount > 0, BugBountyError::EscrowEmpty);
+        
+        let report_key = ctx.accounts.report.key();
+        let escrow_authority_key = ctx.accounts.escrow_authority.key();
+        let escrow_before = ctx.accounts.claim_escrow.amount;
+        let destination_before = ctx.accounts.destination_token_account.amount;
+        
+        let metas: Vec<AccountMeta> = ctx.remaining_accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.key(),
+                is_signer: account.is_signer || account.key() == escrow_authority_key,
+                is_writable: account.is_writable,
+            })
+            .collect();
+        
+        let swap_ix = Instruction {
+            program_id: ctx.accounts.swap_program.key(),
+            accounts: metas,
+            data: route_data,
+        };
+        
+        let mut account_infos = ctx.remaining_accounts.to_vec();
+        account_infos.push(ctx.accounts.escrow_authority.to_account_info());
+        
+        invoke_signed(
+            &swap_ix,
+            &account_infos,
+            &[&[
+                ESCROW_AUTHORITY_SEED,
+                report_key.as_ref(),
+                &[ctx.bumps.escrow_authority],
+            ]],
+        )?;
+        
+        ctx.accounts.claim_escrow.reload()?;
+        ctx.accounts.destination_token_account.reload()?;
+        
+        let amount_in = escrow_before
+            .checked_sub(ctx.accounts.claim_escrow.amount)
+            .ok_or(BugBountyError::ArithmeticUnderflow)?;
+        let amount_out = ctx.accounts.destination_token_account.amount
+            .checked_sub(destination_before)
+            .ok_or(BugBountyError::ArithmeticUnderflow)?;
+        
+        require!(amount_out >= min_amount_out, BugBountyError::SlippageExceeded);
+        
+        let report = &mut ctx.accounts.report;
+        report.claim_escrow_amount = ctx.accounts.claim_escrow.amount;
+        
+        emit!(EscrowClaimed {
+            vault: ctx.accounts.vault.key(),
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            report: report_key,
+            researcher: report.researcher,
+            amount_in,
+            amount_out,
+            output_mint: ctx.accounts.destination_token_account.mint,
+        });
+        
+        msg!("🔄 Escrow swapped: {} in, {} out", amount_in, amount_out);
+        Ok(())
+    }
+
+    /// Close an emptied claim escrow once the report is settled, returning its rent to the researcher
+    pub fn close_claim_escrow(
+        ctx: Context<CloseClaimEscrow>,
+    ) -> Result<()> {
+        let report = &mut ctx.accounts.report;
+        
+        require!(
+            matches!(
+                report.status,
+                ReportStatus::Paid | ReportStatus::Rejected | ReportStatus::Withdrawn | ReportStatus::Expired
+            ),
+            BugBountyError::InvalidReportStatus
+        );
+        require!(ctx.accounts.claim_escrow.amount == 0, BugBountyError::EscrowNotEmpty);
+        
+        let report_key = report.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            ESCROW_AUTHORITY_SEED,
+            report_key.as_ref(),
+            &[ctx.bumps.escrow_authority],
+        ]];
+        let lamports = ctx.accounts.claim_escrow.to_account_info().lamports();
+        
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::CloseAccount {
+                account: ctx.accounts.claim_escrow.to_account_info(),
+                destination: ctx.accounts.researcher.to_account_info(),
+                authority: ctx.accounts.escrow_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::close_account(cpi_ctx)?;
+        
+        report.claim_escrow = None;
+        report.claim_escrow_amount = 0;
+        
+        emit!(ClaimEscrowClosed {
+            vault: ctx.accounts.vault.key(),
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            report: report_key,
+            researcher: report.researcher,
+            lamports,
+        });
+        
+        msg!("🗑️ Claim escrow closed, {} lamports returned to researcher", lamports);
+        Ok(())
+    }
+
+    /// Escrow a sponsor's tokens as an extra reward for reports of the selected severities,
+    /// paid out alongside matching payouts until it runs dry or expires
+    pub fn create_bounty_boost(
+        ctx: Context<CreateBountyBoost>,
+        boost_id: u64,
+        amount: u64,
+        severity_mask: u8,
+        max_per_report: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        require_feature(&ctx.accounts.vault, FEATURE_BOUNTY_BOOSTS)?;
+        require!(amount > 0, BugBountyError::ZeroAmount);
+        require!(
+            severity_mask != 0 && severity_mask & !ALL_SEVERITY_TIERS == 0,
+            BugBountyError::InvalidBoostConfig
+        );
+        validate_timestamp(expires_at, current_time(&ctx.accounts.vault)?)?;
+        
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.sponsor_token_account.to_account_info(),
+                to: ctx.accounts.boost_escrow.to_account_info(),
+                authority: ctx.accounts.sponsor.to_account_info(),
+            },
+        );
+        
+        token::transfer(cpi_ctx, amount)?;
+        
+        let boost = &mut ctx.accounts.boost;
+        boost.vault = ctx.accounts.vault.key();
+        boost.sponsor = ctx.accounts.sponsor.key();
+        boost.boost_id = boost_id;
+        boost.mint = ctx.accounts.mint.key();
+        boost.escrow = ctx.accounts.boost_escrow.key();
+        boost.severity_mask = severity_mask;
+        boost.max_per_report = max_per_report;
+        boost.expires_at = expires_at;
+        boost.total_deposited = amount;
+        boost.total_paid = 0;
+        boost.boost_bump = ctx.bumps.boost;
+        
+        emit!(BountyBoostCreated {
+            vault: boost.vault,
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            boost: boost.key(),
+            sponsor: boost.sponsor,
+            mint: boost.mint,
+            amount,
+            severity_mask,
+            max_per_report,
+            expires_at,
+            subscriber_count: ctx.accounts.vault.subscriber_count,
+        });
+        
+        msg!("🚀 Bounty boost of {} tokens created", amount);
+        Ok(())
+    }
+
+    /// Return an expired boost's unused tokens to its sponsor and close it (sponsor only)
+    pub fn refund_bounty_boost(
+        ctx: Context<RefundBountyBoost>,
+    ) -> Result<()> {
+        let boost = &ctx.accounts.boost;
+        require!(current_time(&ctx.accounts.vault)? > boost.expires_at, BugBountyError::BoostNotExpired);
+        
+        let boost_id = boost.boost_id.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            BOUNTY_BOOST_SEED,
+            boost.vault.as_ref(),
+            boost.sponsor.as_ref(),
+            &boost_id,
+            &[boost.boost_bump],
+        ]];
+        
+        let amount = ctx.accounts.boost_escrow.amount;
+        if amount > 0 {
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.boost_escrow.to_account_info(),
+                    to: ctx.accounts.sponsor_token_account.to_account_info(),
+                    authority: boost.to_account_info(),
+                },
+                signer_seeds,
+            );
+            
+            token::transfer(cpi_ctx, amount)?;
+        }
+        
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::CloseAccount {
+                account: ctx.accounts.boost_escrow.to_account_info(),
+                destination: ctx.accounts.sponsor.to_account_info(),
+                authority: boost.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::close_account(cpi_ctx)?;
+        
+        emit!(BountyBoostRefunded {
+            vault: boost.vault,
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            boost: boost.key(),
+            sponsor: boost.sponsor,
+            amount,
+        });
+        
+        msg!("↩️ Bounty boost refunded {} tokens to its sponsor", amount);
+        Ok(())
+    }
+
+    /// Initialize the platform config (only the program upgrade authority)
+    pub fn initialize_platform_config(
+        ctx: Context<InitializePlatformConfig>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+        
+        config.admin = ctx.accounts.admin.key();
+        config.config_bump = ctx.bumps.platform_config;
+        config.swap_programs = [Pubkey::default(); MAX_SWAP_PROGRAMS];
+        config.swap_program_count = 0;
+        config.reputation_tree = Pubkey::default();
+        config.registry_page_count = 0;
+        config.treasury = ctx.accounts.admin.key();
+        config.handle_fee_lamports = DEFAULT_HANDLE_FEE_LAMPORTS;
+        
+        msg!("🛠️ Platform config initialized with admin {}", config.admin);
+        Ok(())
+    }
+
+    /// Replace the swap program whitelist used by claim_as (only platform admin)
+    pub fn set_swap_programs(
+        ctx: Context<UpdatePlatformConfig>,
+        swap_programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        require!(swap_programs.len() <= MAX_SWAP_PROGRAMS, BugBountyError::TooManySwapPrograms);
+        
+        let config = &mut ctx.accounts.platform_config;
+        config.swap_programs = [Pubkey::default(); MAX_SWAP_PROGRAMS];
+        config.swap_programs[..swap_programs.len()].copy_from_slice(&swap_programs);
+        config.swap_program_count = swap_programs.len() as u8;
+        
+        msg!("⚙️ Swap program whitelist updated ({} entries)", config.swap_program_count);
+        Ok(())
+    }
+
+    /// Set the handle registration fee and the treasury receiving it (admin only)
+    pub fn set_handle_fee(
+        ctx: Context<UpdatePlatformConfig>,
+        handle_fee_lamports: u64,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+        config.handle_fee_lamports = handle_fee_lamports;
+        config.treasury = treasury;
+        
+        msg!("⚙️ Handle fee set to {} lamports, paid to {}", handle_fee_lamports, treasury);
+        Ok(())
+    }
+
+    /// Claim a unique handle for the signing researcher, paying the registration fee
+    pub fn register_handle(
+        ctx: Context<RegisterHandle>,
+        name: String,
+    ) -> Result<()> {
+        validate_handle(&name)?;
+        
+        let profile = &mut ctx.accounts.researcher_profile;
+        require!(profile.handle.is_none(), BugBountyError::HandleAlreadySet);
+        
+        let fee = ctx.accounts.platform_config.handle_fee_lamports;
+        if fee > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.researcher.to_account_info(),
+                        to: ctx.accounts.treasury.to_account_info(),
+                    },
+                ),
+                fee,
+            )?;
+        }
+        
+        let handle = &mut ctx.accounts.handle;
+        handle.researcher = ctx.accounts.researcher.key();
+        handle.handle_bump = ctx.bumps.handle;
+        handle.registered_at = Clock::get()?.unix_timestamp;
+        handle.name = name;
+        
+        profile.researcher = ctx.accounts.researcher.key();
+        profile.profile_bump = ctx.bumps.researcher_profile;
+        profile.handle = Some(handle.key());
+        
+        emit!(HandleRegistered {
+            researcher: handle.researcher,
+            handle: handle.key(),
+            name: handle.name.clone(),
+        });
+        
+        msg!("🪪 Handle @{} registered", handle.name);
+        Ok(())
+    }
+
+    /// Give up the signer's handle; the rent comes back, the fee does not
+    pub fn release_handle(
+        ctx: Context<ReleaseHandle>,
+    ) -> Result<()> {
+        ctx.accounts.researcher_profile.handle = None;
+        
+        emit!(HandleReleased {
+            researcher: ctx.accounts.researcher.key(),
+            handle: ctx.accounts.handle.key(),
+            name: ctx.accounts.handle.name.clone(),
+            reclaimed: false,
+        });
+        
+        msg!("🪪 Handle @{} released", ctx.accounts.handle.name);
+        Ok(())
+    }
+
+    /// Take a handle away from its holder to settle an impersonation dispute (admin only)
+    /// Rent goes back to the holder
+    pub fn reclaim_handle(
+        ctx: Context<ReclaimHandle>,
+    ) -> Result<()> {
+        ctx.accounts.researcher_profile.handle = None;
+        
+        emit!(HandleReleased {
+            researcher: ctx.accounts.researcher.key(),
+            handle: ctx.accounts.handle.key(),
+            name: ctx.accounts.handle.name.clone(),
+            reclaimed: true,
+        });
+        
+        msg!("🪪 Handle @{} reclaimed by the platform", ctx.accounts.handle.name);
+        Ok(())
+    }
+
+    /// Watch a vault for tier changes, boosts and pauses; the researcher pays the rent
+    pub fn subscribe_vault(
+        ctx: Context<SubscribeVault>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        vault.subscriber_count = vault.subscriber_count.checked_add(1)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        
+        let subscription = &mut ctx.accounts.subscription;
+        subscription.vault = vault.key();
+        subscription.researcher = ctx.accounts.researcher.key();
+        subscription.subscribed_at = current_time(vault)?;
+        subscription.subscription_bump = ctx.bumps.subscription;
+        
+        emit!(VaultSubscriptionChanged {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            researcher: subscription.researcher,
+            subscribed: true,
+            subscriber_count: vault.subscriber_count,
+        });
+        
+        msg!("🔔 Subscribed to vault ({} subscribers)", vault.subscriber_count);
+        Ok(())
+    }
+
+    /// Stop watching a vault; the subscription's rent goes back to the researcher
+    pub fn unsubscribe_vault(
+        ctx: Context<UnsubscribeVault>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        vault.subscriber_count = vault.subscriber_count.checked_sub(1)
+            .ok_or(BugBountyError::ArithmeticUnderflow)?;
+        
+        emit!(VaultSubscriptionChanged {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            researcher: ctx.accounts.researcher.key(),
+            subscribed: false,
+            subscriber_count: vault.subscriber_count,
+        });
+        
+        msg!("🔕 Unsubscribed from vault ({} subscribers)", vault.subscriber_count);
+        Ok(())
+    }
+
+    /// Open the next vault registry page (permissionless, paid by the caller)
+    /// Only allowed for the first page or once the previous page is full
+    pub fn open_registry_page(
+        ctx: Context<OpenRegistryPage>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+        let page_index = config.registry_page_count;
+        
+        if page_index > 0 {
+            let previous_page = ctx.accounts.previous_page
+                .as_ref()
+                .ok_or(BugBountyError::PreviousRegistryPageRequired)?
+                .load()?;
+            require!(previous_page.page_index == page_index - 1, BugBountyError::PreviousRegistryPageRequired);
+            require!(previous_page.is_full(), BugBountyError::RegistryPageNotFull);
+        }
+        
+        let mut page = ctx.accounts.vault_registry.load_init()?;
+        page.page_index = page_index;
+        page.count = 0;
+        page.page_bump = ctx.bumps.vault_registry;
+        
+        config.registry_page_count = page_index.checked_add(1)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        
+        msg!("📒 Vault registry page {} opened", page_index);
+        Ok(())
+    }
+
+    /// Hide a vault from the registry (admin only)
+    pub fn delist_vault(
+        ctx: Context<ManageVaultRegistry>,
+    ) -> Result<()> {
+        let vault = ctx.accounts.vault.key();
+        let mut page = ctx.accounts.vault_registry.load_mut()?;
+        let page_index = page.page_index;
+        let entry = page.entry_mut(&vault)?;
+        entry.listed = 0;
+        
+        emit!(VaultRegistryUpdated {
+            vault,
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            page_index,
+            listed: false,
+            verified: entry.verified != 0,
+        });
+        
+        msg!("📒 Vault {} delisted", vault);
+        Ok(())
+    }
+
+    /// Mark a registry entry as vetted, or withdraw that mark (admin only)
+    pub fn set_vault_verified(
+        ctx: Context<ManageVaultRegistry>,
+        verified: bool,
+    ) -> Result<()> {
+        let vault = ctx.accounts.vault.key();
+        let mut page = ctx.accounts.vault_registry.load_mut()?;
+        let page_index = page.page_index;
+        let entry = page.entry_mut(&vault)?;
+        entry.verified = verified as u8;
+        
+        emit!(VaultRegistryUpdated {
+            vault,
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            page_index,
+            listed: entry.listed != 0,
+            verified,
+        });
+        
+        msg!("📒 Vault {} verified: {}", vault, verified);
+        Ok(())
+    }
+
+    /// Set the merkle tree compressed reputation badges are minted into (admin only)
+    pub fn set_reputation_tree(
+        ctx: Context<UpdatePlatformConfig>,
+        reputation_tree: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.platform_config.reputation_tree = reputation_tree;
+        
+        msg!("⚙️ Reputation tree set to {}", reputation_tree);
+        Ok(())
+    }
+
+    /// Mint reputation NFT for paid reports (optional). The team signs and pays when
+    /// the vault has `team_mints_reputation` set, otherwise the researcher does.
+    pub fn mint_reputation_nft(ctx: Context<MintReputationNFT>) -> Result<()> {
+        let vault = &ctx.accounts.vault;
+        let report = &ctx.accounts.report;
+        
+        let minted_at_approval = report.status == ReportStatus::Approved && vault.reputation_on_approval;
+        require!(
+            report.status == ReportStatus::Paid || minted_at_approval,
+            BugBountyError::ReportNotPaid
+        );
+        require!(!vault.project_name.is_empty(), BugBountyError::ProjectNameNotSet);
+        
+        let expected_minter = if vault.team_mints_reputation {
+            vault.program_team
+        } else {
+            report.researcher
+        };
+        require_keys_eq!(ctx.accounts.minter.key(), expected_minter, BugBountyError::UnauthorizedMinter);
+        
+        let mut leaf_index = None;
+        if vault.compressed_reputation {
+            let accounts = &ctx.accounts;
+            let (
+                Some(platform_config),
+                Some(leaf_owner),
+                Some(tree_config),
+                Some(merkle_tree),
+                Some(bubblegum_program),
+                Some(log_wrapper),
+                Some(compression_program),
+            ) = (
+                accounts.platform_config.as_ref(),
+                accounts.leaf_owner.as_ref(),
+                accounts.tree_config.as_ref(),
+                accounts.merkle_tree.as_ref(),
+                accounts.bubblegum_program.as_ref(),
+                accounts.log_wrapper.as_ref(),
+                accounts.compression_program.as_ref(),
+            ) else {
+                return err!(BugBountyError::MissingCompressionAccounts);
+            };
+            
+            require!(platform_config.reputation_tree != Pubkey::default(), BugBountyError::ReputationTreeNotSet);
+            require_keys_eq!(merkle_tree.key(), platform_config.reputation_tree, BugBountyError::ReputationTreeMismatch);
+            let (expected_tree_config, _) = Pubkey::find_program_address(&[merkle_tree.key().as_ref()], &BUBBLEGUM_PROGRAM_ID);
+            require_keys_eq!(tree_config.key(), expected_tree_config, BugBountyError::ReputationTreeMismatch);
+            require_keys_eq!(leaf_owner.key(), report.researcher, BugBountyError::UnauthorizedResearcher);
+            
+            // The new leaf lands at the tree's current mint count
+            let next_leaf = {
+                let data = tree_config.try_borrow_data()?;
+                let bytes = data
+                    .get(TREE_CONFIG_NUM_MINTED_OFFSET..TREE_CONFIG_NUM_MINTED_OFFSET + 8)
+                    .ok_or(BugBountyError::ReputationTreeMismatch)?;
+                u64::from_le_bytes(bytes.try_into().unwrap())
+            };
+            
+            let metadata = compressed_badge_metadata(&vault.project_name, vault.key(), report.key(), report.severity);
+            let mut data = BUBBLEGUM_MINT_V1_DISCRIMINATOR.to_vec();
+            metadata.serialize(&mut data)?;
+            
+            let mint_ix = Instruction {
+                program_id: BUBBLEGUM_PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(tree_config.key(), false),
+                    AccountMeta::new_readonly(leaf_owner.key(), false),
+                    AccountMeta::new_readonly(leaf_owner.key(), false),
+                    AccountMeta::new(merkle_tree.key(), false),
+                    AccountMeta::new(accounts.minter.key(), true),
+                    AccountMeta::new_readonly(platform_config.key(), true),
+                    AccountMeta::new_readonly(log_wrapper.key(), false),
+                    AccountMeta::new_readonly(compression_program.key(), false),
+                    AccountMeta::new_readonly(accounts.system_program.key(), false),
+                ],
+                data,
+            };
+            
+            invoke_signed(
+                &mint_ix,
+                &[
+                    tree_config.to_account_info(),
+                    leaf_owner.to_account_info(),
+                    merkle_tree.to_account_info(),
+                    accounts.minter.to_account_info(),
+                    platform_config.to_account_info(),
+                    log_wrapper.to_account_info(),
+                    compression_program.to_account_info(),
+                    accounts.system_program.to_account_info(),
+                    bubblegum_program.to_account_info(),
+                ],
+                &[&[PLATFORM_CONFIG_SEED, &[platform_config.config_bump]]],
+            )?;
+            
+            leaf_index = Some(next_leaf);
+        }
+        
+        let reputation_nft = &mut ctx.accounts.reputation_nft;
+        reputation_nft.researcher = report.researcher;
+        reputation_nft.vault = report.vault;
+        reputation_nft.report = report.key();
+        reputation_nft.severity = report.severity;
+        reputation_nft.project_name = vault.project_name.clone();
+        reputation_nft.minted_at = current_time(vault)?;
+        reputation_nft.disclosed_report_hash = report.public_ipfs_hash;
+        reputation_nft.revoked = false;
+        reputation_nft.revoked_reason_hash = [0u8; 32];
+        reputation_nft.leaf_index = leaf_index;
+        reputation_nft.minted_at_approval = minted_at_approval;
+        reputation_nft.rent_payer = ctx.accounts.minter.key();
+        
+        msg!("🏆 Reputation NFT minted for researcher");
+        Ok(())
+    }
+
+    /// Publicly mark a badge as bad when its payout turns out to be fraudulent
+    /// (governance authority of the issuing vault)
+    pub fn revoke_reputation(
+        ctx: Context<RevokeReputation>,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        let reputation_nft = &mut ctx.accounts.reputation_nft;
+        
+        require!(!reputation_nft.revoked, BugBountyError::ReputationAlreadyRevoked);
+        
+        reputation_nft.revoked = true;
+        reputation_nft.revoked_reason_hash = reason_hash;
+        
+        emit!(ReputationRevoked {
+            vault: reputation_nft.vault,
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            reputation_nft: reputation_nft.key(),
+            report: reputation_nft.report,
+            researcher: reputation_nft.researcher,
+            reason_hash,
+            revoked_at: current_time(&ctx.accounts.vault)?,
+        });
+        
+        msg!("🚫 Reputation NFT revoked");
+        Ok(())
+    }
+
+    /// Undo a revocation (platform admin only, so the team and researcher cannot
+    /// toggle a badge between themselves)
+    pub fn restore_reputation(ctx: Context<RestoreReputation>) -> Result<()> {
+        let reputation_nft = &mut ctx.accounts.reputation_nft;
+        
+        require!(reputation_nft.revoked, BugBountyError::ReputationNotRevoked);
+        
+        reputation_nft.revoked = false;
+        reputation_nft.revoked_reason_hash = [0u8; 32];
+        
+        emit!(ReputationRestored {
+            vault: reputation_nft.vault,
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            reputation_nft: reputation_nft.key(),
+            report: reputation_nft.report,
+            researcher: reputation_nft.researcher,
+            restored_at: current_time(&ctx.accounts.vault)?,
+        });
+        
+        msg!("♻️ Reputation NFT restored");
+        Ok(())
+    }
+
+    /// Add lamports to the vault's report rent subsidy (anyone may fund)
+    pub fn fund_rent_subsidy(
+        ctx: Context<FundRentSubsidy>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, BugBountyError::ZeroAmount);
+        
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.funder.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        
+        let vault = &mut ctx.accounts.vault;
+        vault.rent_subsidy_budget = vault.rent_subsidy_budget.checked_add(amount)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        
+        emit!(RentSubsidyFunded {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            funder: ctx.accounts.funder.key(),
+            amount,
+            rent_subsidy_budget: vault.rent_subsidy_budget,
+        });
+        
+        msg!("🏠 Rent subsidy funded with {} lamports", amount);
+        Ok(())
+    }
+
+    /// Allow vault to receive additional funding
+    pub fn fund_vault(
+        ctx: Context<FundVault>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, BugBountyError::ZeroAmount);
+        
+        let vault = &mut ctx.accounts.vault;
+        
+        // The primary vault token account, or the token account of an extra reward mint
+        let vault_token_key = ctx.accounts.vault_token_account.key();
+        let extra = vault.extra_token_accounts.iter().zip(vault.extra_reward_mints.iter())
+            .position(|(account, mint)| *account == vault_token_key && *mint != Pubkey::default());
+        require!(
+            vault_token_key == vault.vault_token_account || extra.is_some(),
+            BugBountyError::VaultTokenAccountMismatch
+        );
+        let vault_token = load_token_account(&ctx.accounts.vault_token_account)?;
+        let funder_token = load_token_account(&ctx.accounts.funder_token_account)?;
+        require_keys_eq!(funder_token.owner, ctx.accounts.funder.key(), BugBountyError::TokenAccountOwnerMismatch);
+        require_keys_eq!(funder_token.mint, vault_token.mint, BugBountyError::WrongRewardMint);
+        
+        // Extra mints keep their own totals; the cap and insurance reserve cover the primary mint
+        if let Some(i) = extra {
+            let cpi_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.funder_token_account.to_account_info(),
+                    to: ctx.accounts.vault_token_account.to_account_info(),
+                    authority: ctx.accounts.funder.to_account_info(),
+                },
+            );
+            
+            token::transfer(cpi_ctx, amount)?;
+            
+            vault.extra_funded[i] = vault.extra_funded[i].checked_add(amount)
+                .ok_or(BugBountyError::ArithmeticOverflow)?;
+            assert_vault_invariants(vault, None)?;
+            
+            msg!("💸 Vault funded with additional {} tokens of {}", amount, vault_token.mint);
+            return Ok(());
+        }
+        
+        if vault.max_vault_balance > 0 {
+            let new_balance = vault_token.amount.checked_add(amount)
+                .ok_or(BugBountyError::ArithmeticOverflow)?;
+            require!(new_balance <= vault.max_vault_balance, BugBountyError::FundingCapExceeded);
+        }
+        
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.funder_token_account.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: ctx.accounts.funder.to_account_info(),
+            },
+        );
+        
+        token::transfer(cpi_ctx, amount)?;
+        
+        // Carve the insurance share out of the round; rounding favors the general pool
+        let reserve_share = bps_of(amount, vault.insurance_bps)?;
+        
+        vault.total_funded = vault.total_funded.checked_add(amount)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        vault.insurance_reserve = vault.insurance_reserve.checked_add(reserve_share)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        assert_vault_invariants(vault, Some(load_token_account(&ctx.accounts.vault_token_account)?.amount))?;
+        
+        msg!("💸 Vault funded with additional {} tokens ({} to insurance reserve)", amount, reserve_share);
+        Ok(())
+    }
+
+    /// Freeze the vault's metrics for a reporting period (permissionless, once per period_id)
+    pub fn snapshot_vault(
+        ctx: Context<SnapshotVault>,
+        period_id: u32,
+    ) -> Result<()> {
+        let vault = &ctx.accounts.vault;
+        let snapshot = &mut ctx.accounts.snapshot;
+        
+        snapshot.vault = vault.key();
+        snapshot.period_id = period_id;
+        snapshot.taken_at = current_time(vault)?;
+        snapshot.snapshot_bump = ctx.bumps.snapshot;
+        
+        snapshot.total_funded = vault.total_funded;
+        snapshot.total_paid_out = vault.total_paid_out;
+        snapshot.total_withdrawn = vault.total_withdrawn;
+        snapshot.committed_amount = vault.committed_amount;
+        snapshot.insurance_reserve = vault.insurance_reserve;
+        snapshot.total_reports = vault.total_reports;
+        snapshot.approved_reports = vault.approved_reports;
+        snapshot.rejected_reports = vault.rejected_reports;
+        snapshot.withdrawn_reports = vault.withdrawn_reports;
+        snapshot.token_balance = ctx.accounts.vault_token_account.amount;
+        
+        emit!(VaultSnapshotTaken {
+            vault: snapshot.vault,
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            period_id,
+            taken_at: snapshot.taken_at,
+        });
+        
+        msg!("📸 Vault snapshot taken for period {}", period_id);
+        Ok(())
+    }
+
+    /// Reclaim a snapshot's rent once its retention window has passed (only program team)
+    pub fn close_snapshot(
+        ctx: Context<CloseSnapshot>,
+    ) -> Result<()> {
+        let snapshot = &ctx.accounts.snapshot;
+        
+        let retained_until = snapshot.taken_at.checked_add(SNAPSHOT_RETENTION_SECONDS)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        require!(
+            current_time(&ctx.accounts.vault)? >= retained_until,
+            BugBountyError::SnapshotRetentionActive
+        );
+        
+        msg!("🗑️ Snapshot for period {} closed", snapshot.period_id);
+        Ok(())
+    }
+
+    /// Spotlight a paid report in the vault's hall of fame (program team only)
+    pub fn add_hall_of_fame_entry(
+        ctx: Context<AddHallOfFameEntry>,
+        note_hash: [u8; 32],
+    ) -> Result<()> {
+        let report = &ctx.accounts.report;
+        
+        require!(report.status == ReportStatus::Paid, BugBountyError::ReportNotPaid);
+        
+        let entry = HallOfFameEntry {
+            researcher: report.researcher,
+            report: report.key(),
+            note_hash,
+        };
+        
+        let hall_of_fame = &mut ctx.accounts.hall_of_fame;
+        hall_of_fame.vault = ctx.accounts.vault.key();
+        hall_of_fame.hall_bump = ctx.bumps.hall_of_fame;
+        hall_of_fame.add(entry)?;
+        
+        emit!(HallOfFameEntryAdded {
+            vault: hall_of_fame.vault,
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            researcher: entry.researcher,
+            report: entry.report,
+            note_hash,
+        });
+        
+        msg!("🏛️ Report added to the hall of fame ({} entries)", hall_of_fame.entry_count);
+        Ok(())
+    }
+
+    /// Publicly thank the researcher of a paid report (program team only, once per report)
+    pub fn post_acknowledgment(
+        ctx: Context<PostAcknowledgment>,
+        message_hash: [u8; 32],
+        display_name_override: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let report = &ctx.accounts.report;
+        
+        require!(report.status == ReportStatus::Paid, BugBountyError::ReportNotPaid);
+        
+        let acknowledgment = &mut ctx.accounts.acknowledgment;
+        acknowledgment.vault = report.vault;
+        acknowledgment.report = report.key();
+        acknowledgment.researcher = report.researcher;
+        acknowledgment.message_hash = message_hash;
+        acknowledgment.display_name_override = display_name_override;
+        acknowledgment.posted_at = current_time(&ctx.accounts.vault)?;
+        acknowledgment.hidden = false;
+        acknowledgment.acknowledgment_bump = ctx.bumps.acknowledgment;
+        
+        emit!(AcknowledgmentPosted {
+            vault: acknowledgment.vault,
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            report: acknowledgment.report,
+            researcher: acknowledgment.researcher,
+            message_hash,
+            display_name_override,
+        });
+        
+        msg!("🙏 Acknowledgment posted for report {}", acknowledgment.report);
+        Ok(())
+    }
+
+    /// Keep the researcher's name off an acknowledgment (researcher only)
+    pub fn hide_acknowledgment(
+        ctx: Context<HideAcknowledgment>,
+    ) -> Result<()> {
+        let acknowledgment = &mut ctx.accounts.acknowledgment;
+        acknowledgment.hidden = true;
+        
+        emit!(AcknowledgmentHidden {
+            vault: acknowledgment.vault,
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            report: acknowledgment.report,
+        });
+        
+        msg!("🙈 Acknowledgment hidden");
+        Ok(())
+    }
+
+    /// Take a report out of the vault's hall of fame (program team only)
+    pub fn remove_hall_of_fame_entry(
+        ctx: Context<RemoveHallOfFameEntry>,
+        report: Pubkey,
+    ) -> Result<()> {
+        let hall_of_fame = &mut ctx.accounts.hall_of_fame;
+        let removed = hall_of_fame.remove(&report)?;
+        
+        emit!(HallOfFameEntryRemoved {
+            vault: hall_of_fame.vault,
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            researcher: removed.researcher,
+            report,
+        });
+        
+        msg!("🏛️ Report removed from the hall of fame ({} entries)", hall_of_fame.entry_count);
+        Ok(())
+    }
+
+    /// Permissionless: create or refresh a report's summary, covering status changes made
+    /// without one and reports filed before the vault maintained summaries
+    pub fn sync_report_summary(
+        ctx: Context<SyncReportSummary>,
+    ) -> Result<()> {
+        let summary = &mut ctx.accounts.report_summary;
+        
+        // Only a vault that maintains summaries gets new ones
+        if summary.report == Pubkey::default() {
+            require!(ctx.accounts.vault.maintain_summaries, BugBountyError::ReportSummariesDisabled);
+            summary.summary_bump = ctx.bumps.report_summary;
+        }
+        summary.sync(&ctx.accounts.report, current_time(&ctx.accounts.vault)?);
+        
+        msg!("📇 Report summary synced at {:?}", summary.status);
+        Ok(())
+    }
+
+    /// Reconcile total_funded with the actual vault token balance (permissionless)
+    /// Tokens sent straight to the vault token account are recorded as funding;
+    /// total_funded is never decreased
+    pub fn sync_vault_balance(
+        ctx: Context<SyncVaultBalance>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let balance = ctx.accounts.vault_token_account.amount;
+        
+        let recorded_balance = vault.recorded_balance()?;
+        
+        if balance > recorded_balance {
+            let delta = balance.checked_sub(recorded_balance)
+                .ok_or(BugBountyError::ArithmeticUnderflow)?;
+            vault.total_funded = vault.total_funded.checked_add(delta)
+                .ok_or(BugBountyError::ArithmeticOverflow)?;
+            
+            emit!(DonationDetected {
+                vault: vault.key(),
+                sequence: vault.next_event_sequence()?,
+                delta,
+                total_funded: vault.total_funded,
+                over_cap: vault.max_vault_balance > 0 && balance > vault.max_vault_balance,
+            });
+            
+            msg!("🔁 Vault balance synced, {} untracked tokens recorded", delta);
+        } else {
+            msg!("🔁 Vault balance already in sync");
+        }
+        assert_vault_invariants(vault, Some(balance))?;
+        
+        Ok(())
+    }
+
+    /// Move plain SOL sent to the vault PDA into the reward pool when the vault pays in wSOL,
+    /// else to the program team (permissionless; the vault decides the destination)
+    pub fn sweep_lamports(
+        ctx: Context<SweepLamports>,
+    ) -> Result<()> {
+        let vault_info = ctx.accounts.vault.to_account_info();
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(vault_info.data_len());
+        let amount = ctx.accounts.vault.sweepable_lamports(vault_info.lamports(), rent_exempt_minimum);
+        require!(amount > 0, BugBountyError::ZeroAmount);
+        
+        // Program-owned, so the vault's lamports are debited directly
+        let into_reward_pool = ctx.accounts.vault.pays_in_wrapped_sol();
+        let destination = if into_reward_pool {
+            let vault_token_account = ctx.accounts.vault_token_account.as_ref()
+                .ok_or(BugBountyError::VaultTokenAccountMismatch)?;
+            vault_info.sub_lamports(amount)?;
+            vault_token_account.add_lamports(amount)?;
+            
+            token::sync_native(CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::SyncNative { account: vault_token_account.to_account_info() },
+            ))?;
+            vault_token_account.key()
+        } else {
+            let program_team = ctx.accounts.program_team.as_ref()
+                .ok_or(BugBountyError::UnauthorizedTeam)?;
+            vault_info.sub_lamports(amount)?;
+            program_team.add_lamports(amount)?;
+            program_team.key()
+        };
+        
+        let vault = &mut ctx.accounts.vault;
+        if into_reward_pool {
+            vault.total_funded = vault.total_funded.checked_add(amount)
+                .ok_or(BugBountyError::ArithmeticOverflow)?;
+            let vault_token_account = ctx.accounts.vault_token_account.as_mut()
+                .ok_or(BugBountyError::VaultTokenAccountMismatch)?;
+            vault_token_account.reload()?;
+            assert_vault_invariants(vault, Some(vault_token_account.amount))?;
+        }
+        
+        emit!(LamportsSwept {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            amount,
+            destination,
+            into_reward_pool,
+            total_funded: vault.total_funded,
+        });
+        
+        msg!("🧹 {} stray lamports swept to {}", amount, destination);
+        Ok(())
+    }
+
+    /// Stake tokens on a pending report to flag it for the priority triage queue
+    pub fn stake_for_priority(
+        ctx: Context<StakeForPriority>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let report = &mut ctx.accounts.report;
+        
+        require_feature(vault, FEATURE_PRIORITY_STAKE)?;
+        require!(vault.vault_active, BugBountyError::VaultInactive);
+        require!(vault.priority_stake > 0, BugBountyError::PriorityStakeDisabled);
+        require!(report.status == ReportStatus::Pending, BugBountyError::InvalidReportStatus);
+        require!(!report.priority, BugBountyError::AlreadyStaked);
+        
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.researcher_token_account.to_account_info(),
+                to: ctx.accounts.stake_escrow.to_account_info(),
+                authority: ctx.accounts.researcher.to_account_info(),
+            },
+        );
+        
+        token::transfer(cpi_ctx, vault.priority_stake)?;
+        
+        report.priority = true;
+        report.priority_stake_amount = vault.priority_stake;
+        report.stake_escrow = Some(ctx.accounts.stake_escrow.key());
+        vault.open_priority_stakes = vault.open_priority_stakes.checked_add(1)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        
+        msg!("⚡ Report flagged priority with stake of {}", vault.priority_stake);
+        Ok(())
+    }
+
+    /// Settle a priority stake once the report is resolved (permissionless)
+    /// Spam rejections forfeit the stake to the vault, anything else refunds it;
+    /// the stake escrow is closed and its rent returned to the researcher
+    pub fn settle_priority_stake(
+        ctx: Context<SettlePriorityStake>,
+    ) -> Result<()> {
+        let report = &mut ctx.accounts.report;
+        
+        require!(report.status != ReportStatus::Pending, BugBountyError::StakeNotResolvable);
+        
+        let amount = ctx.accounts.stake_escrow.amount;
+        let forfeited = report.is_spam;
+        
+        let report_key = report.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            ESCROW_AUTHORITY_SEED,
+            report_key.as_ref(),
+            &[ctx.bumps.escrow_authority],
+        ]];
+        
+        if amount > 0 {
+            let destination = if forfeited {
+                ctx.accounts.vault_token_account.to_account_info()
+            } else {
+                ctx.accounts.researcher_token_account.to_account_info()
+            };
+            
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.stake_escrow.to_account_info(),
+                    to: destination,
+                    authority: ctx.accounts.escrow_authority.to_account_info(),
+                },
+                signer_seeds,
+            );
+            
+            token::transfer(cpi_ctx, amount)?;
+            
+            if forfeited {
+                let vault = &mut ctx.accounts.vault;
+                vault.total_funded = vault.total_funded.checked_add(amount)
+                    .ok_or(BugBountyError::ArithmeticOverflow)?;
+                ctx.accounts.vault_token_account.reload()?;
+                assert_vault_invariants(vault, Some(ctx.accounts.vault_token_account.amount))?;
+            }
+        }
+        
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::CloseAccount {
+                account: ctx.accounts.stake_escrow.to_account_info(),
+                destination: ctx.accounts.researcher.to_account_info(),
+                authority: ctx.accounts.escrow_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::close_account(cpi_ctx)?;
+        
+        report.priority_stake_amount = 0;
+        report.stake_escrow = None;
+        // Saturating for stakes placed before the count existed
+        let vault = &mut ctx.accounts.vault;
+        vault.open_priority_stakes = vault.open_priority_stakes.saturating_sub(1);
+        
+        emit!(PriorityStakeSettled {
+            vault: ctx.accounts.vault.key(),
+            sequence: ctx.accounts.vault.next_event_sequence()?,
+            report: report_key,
+            researcher: report.researcher,
+            amount,
+            forfeited,
+        });
+        
+        msg!("⚖️ Priority stake of {} settled (forfeited: {})", amount, forfeited);
+        Ok(())
+    }
+
+    /// Set the stake required for priority triage (only program team, 0 disables)
+    pub fn set_priority_stake(
+        ctx: Context<UpdateRewardTiers>,
+        priority_stake: u64,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.priority_stake = priority_stake;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_PRIORITY_STAKE,
+            ctx.accounts.program_team.key(),
+            priority_stake,
+        )?;
+        
+        msg!("⚙️ Priority stake set to {}", priority_stake);
+        Ok(())
+    }
+
+    /// Hand governance over to a new authority (only current governance authority)
+    pub fn update_governance_authority(
+        ctx: Context<UpdateGovernanceAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require!(
+            vault.allow_same_authority || new_authority != vault.program_team,
+            BugBountyError::AuthoritiesMustDiffer
+        );
+        
+        vault.governance_authority = new_authority;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_ROTATE_GOVERNANCE,
+            ctx.accounts.governance_authority.key(),
+            0,
+        )?;
+        
+        msg!("🔑 Governance authority updated to {}", new_authority);
+        Ok(())
+    }
+
+    /// Start replacing a lost governance key (only program team)
+    /// The current governance authority can cancel until the recovery delay has passed
+    pub fn initiate_governance_recovery(
+        ctx: Context<GovernanceRecovery>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require!(vault.governance_recovery_delay_seconds > 0, BugBountyError::GovernanceRecoveryDisabled);
+        require!(vault.pending_governance_authority.is_none(), BugBountyError::GovernanceRecoveryPending);
+        require!(
+            vault.allow_same_authority || new_authority != vault.program_team,
+            BugBountyError::AuthoritiesMustDiffer
+        );
+        
+        let executable_at = current_time(vault)?
+            .checked_add(vault.governance_recovery_delay_seconds)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        vault.pending_governance_authority = Some(new_authority);
+        vault.governance_recovery_executable_at = executable_at;
+        
+        emit!(GovernanceRecoveryInitiated {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            current_authority: vault.governance_authority,
+            new_authority,
+            executable_at,
+        });
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_INITIATE_GOVERNANCE_RECOVERY,
+            ctx.accounts.program_team.key(),
+            executable_at as u64,
+        )?;
+        
+        msg!("🚨 Governance recovery to {} initiated, executable at {}", new_authority, executable_at);
+        Ok(())
+    }
+
+    /// Stop a pending governance recovery (only current governance authority)
+    pub fn cancel_governance_recovery(
+        ctx: Context<CancelGovernanceRecovery>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        let new_authority = vault.pending_governance_authority
+            .ok_or(BugBountyError::NoGovernanceRecoveryPending)?;
+        vault.pending_governance_authority = None;
+        vault.governance_recovery_executable_at = 0;
+        
+        emit!(GovernanceRecoveryCancelled {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            new_authority,
+        });
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_CANCEL_GOVERNANCE_RECOVERY,
+            ctx.accounts.governance_authority.key(),
+            0,
+        )?;
+        
+        msg!("🛑 Governance recovery to {} cancelled", new_authority);
+        Ok(())
+    }
+
+    /// Swap in the recovered governance authority once the delay has passed (only program team)
+    pub fn finalize_governance_recovery(
+        ctx: Context<GovernanceRecovery>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        let new_authority = vault.pending_governance_authority
+            .ok_or(BugBountyError::NoGovernanceRecoveryPending)?;
+        require!(
+            current_time(vault)? >= vault.governance_recovery_executable_at,
+            BugBountyError::GovernanceRecoveryTimelockActive
+        );
+        
+        let old_authority = vault.governance_authority;
+        vault.governance_authority = new_authority;
+        vault.pending_governance_authority = None;
+        vault.governance_recovery_executable_at = 0;
+        
+        emit!(GovernanceRecoveryFinalized {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            old_authority,
+            new_authority,
+        });
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_FINALIZE_GOVERNANCE_RECOVERY,
+            ctx.accounts.program_team.key(),
+            0,
+        )?;
+        
+        msg!("🚨 Governance authority recovered: {} replaced by {}", old_authority, new_authority);
+        Ok(())
+    }
+
+    /// Queue a timelocked withdrawal of uncommitted funds (only program team)
+    pub fn request_withdrawal(
+        ctx: Context<ManageWithdrawal>,
+        amount: u64,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_FUND_WITHDRAW,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        require!(!vault.withdrawal_pending, BugBountyError::WithdrawalAlreadyPending);
+        require!(amount > 0 && amount <= vault.available_balance()?, BugBountyError::InsufficientVaultFunds);
+        
+        let executable_at = current_time(vault)?
+            .checked_add(vault.withdrawal_delay_seconds)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        
+        vault.withdrawal_pending = true;
+        vault.pending_withdrawal_amount = amount;
+        vault.withdrawal_executable_at = executable_at;
+        
+        emit!(WithdrawalRequested {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            amount,
+            executable_at,
+        });
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_REQUEST_WITHDRAWAL,
+            ctx.accounts.program_team.key(),
+            amount,
+        )?;
+        
+        msg!("⏳ Withdrawal of {} queued, executable at {}", amount, executable_at);
+        Ok(())
+    }
+
+    /// Execute a queued withdrawal once its timelock has passed (only program team)
+    /// Funds committed to approvals made during the timelock are never withdrawn
+    pub fn execute_withdrawal(
+        ctx: Context<ExecuteWithdrawal>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_FUND_WITHDRAW,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        require!(vault.withdrawal_pending, BugBountyError::NoPendingWithdrawal);
+        require!(
+            current_time(vault)? >= vault.withdrawal_executable_at,
+            BugBountyError::WithdrawalTimelockActive
+        );
+        
+        let amount = vault.pending_withdrawal_amount;
+        require!(amount <= vault.available_balance()?, BugBountyError::InsufficientVaultFunds);
+        
+        let seeds = vault.signer_seeds();
+        let signer_seeds: &[&[&[u8]]] = &[&seeds];
+        
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                to: ctx.accounts.program_team_token_account.to_account_info(),
+                authority: vault.to_account_info(),
+            },
+            signer_seeds,
+        );
+        
+        token::transfer(cpi_ctx, amount)?;
+        
+        vault.total_withdrawn = vault.total_withdrawn.checked_add(amount)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        vault.withdrawal_pending = false;
+        vault.pending_withdrawal_amount = 0;
+        vault.withdrawal_executable_at = 0;
+        ctx.accounts.vault_token_account.reload()?;
+        assert_vault_invariants(vault, Some(ctx.accounts.vault_token_account.amount))?;
+        
+        emit!(WithdrawalExecuted {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            amount,
+        });
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_EXECUTE_WITHDRAWAL,
+            ctx.accounts.program_team.key(),
+            amount,
+        )?;
+        
+        msg!("🏧 Withdrawal of {} executed to program team", amount);
+        Ok(())
+    }
+
+    /// Cancel a queued withdrawal (only program team)
+    pub fn cancel_withdrawal(
+        ctx: Context<ManageWithdrawal>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_FUND_WITHDRAW,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        require!(vault.withdrawal_pending, BugBountyError::NoPendingWithdrawal);
+        
+        let amount = vault.pending_withdrawal_amount;
+        vault.withdrawal_pending = false;
+        vault.pending_withdrawal_amount = 0;
+        vault.withdrawal_executable_at = 0;
+        
+        emit!(WithdrawalCancelled {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            amount,
+        });
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_CANCEL_WITHDRAWAL,
+            ctx.accounts.program_team.key(),
+            amount,
+        )?;
+        
+        msg!("🚫 Withdrawal of {} cancelled", amount);
+        Ok(())
+    }
+
+    /// Set the withdrawal timelock (only program team, not while a withdrawal is queued)
+    pub fn set_withdrawal_delay(
+        ctx: Context<UpdateTeamSetting>,
+        withdrawal_delay_seconds: i64,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require!(!vault.withdrawal_pending, BugBountyError::WithdrawalAlreadyPending);
+        require!(withdrawal_delay_seconds >= 0, BugBountyError::InvalidWithdrawalDelay);
+        validate_duration(withdrawal_delay_seconds)?;
+        
+        vault.withdrawal_delay_seconds = withdrawal_delay_seconds;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_WITHDRAWAL_DELAY,
+            ctx.accounts.program_team.key(),
+            withdrawal_delay_seconds as u64,
+        )?;
+        
+        msg!("⚙️ Withdrawal delay set to {} seconds", withdrawal_delay_seconds);
+        Ok(())
+    }
+
+    /// Set the share of future funding routed to the insurance reserve (only program team)
+    pub fn set_insurance_bps(
+        ctx: Context<UpdateRewardTiers>,
+        insurance_bps: u16,
+    ) -> Result<()> {
+        require!(insurance_bps as u64 <= BPS_DENOMINATOR, BugBountyError::InvalidInsuranceBps);
+        
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.insurance_bps = insurance_bps;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_INSURANCE_BPS,
+            ctx.accounts.program_team.key(),
+            insurance_bps as u64,
+        )?;
+        
+        msg!("⚙️ Insurance reserve share set to {} bps", insurance_bps);
+        Ok(())
+    }
+
+    /// Release insurance reserve funds into the general pool
+    /// Requires both the program team and the governance authority
+    pub fn release_insurance(
+        ctx: Context<ReleaseInsurance>,
+        amount: u64,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        vault.insurance_reserve = vault.insurance_reserve.checked_sub(amount)
+            .ok_or(BugBountyError::InsufficientInsuranceReserve)?;
+        assert_vault_invariants(vault, None)?;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_RELEASE_INSURANCE,
+            ctx.accounts.program_team.key(),
+            amount,
+        )?;
+        
+        msg!("🛟 {} released from the insurance reserve", amount);
+        Ok(())
+    }
+
+    /// Configure automatic submission closing under spam (only program team, 0 disables)
+    pub fn set_spam_guard(
+        ctx: Context<UpdateRewardTiers>,
+        spam_threshold: u32,
+        spam_window_seconds: i64,
+    ) -> Result<()> {
+        require!(spam_window_seconds >= 0, BugBountyError::InvalidSpamWindow);
+        validate_duration(spam_window_seconds)?;
+        
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.spam_threshold = spam_threshold;
+        vault.spam_window_seconds = spam_window_seconds;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_SPAM_GUARD,
+            ctx.accounts.program_team.key(),
+            spam_threshold as u64,
+        )?;
+        
+        msg!("⚙️ Spam guard set to {} reports per {} seconds", spam_threshold, spam_window_seconds);
+        Ok(())
+    }
+
+    /// Re-open submissions after an automatic spam pause (only program team)
+    pub fn reopen_submissions(
+        ctx: Context<UpdateRewardTiers>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_PAUSE,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.submissions_open = true;
+        vault.recent_spam_count = 0;
+        vault.spam_window_start = 0;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_REOPEN_SUBMISSIONS,
+            ctx.accounts.program_team.key(),
+            0,
+        )?;
+        
+        msg!("🔓 Submissions re-opened");
+        Ok(())
+    }
+
+    /// Configure the payout circuit breaker (only program team, 0 disables a limit)
+    pub fn set_circuit_breaker(
+        ctx: Context<UpdateRewardTiers>,
+        max_payouts_per_hour: u32,
+        max_hourly_outflow: u64,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.max_payouts_per_hour = max_payouts_per_hour;
+        vault.max_hourly_outflow = max_hourly_outflow;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_CIRCUIT_BREAKER,
+            ctx.accounts.program_team.key(),
+            max_hourly_outflow,
+        )?;
+        
+        msg!("⚙️ Circuit breaker set to {} payouts and {} outflow per hour", max_payouts_per_hour, max_hourly_outflow);
+        Ok(())
+    }
+
+    /// Require a minimum reputation for Critical submissions (only program team, 0 disables),
+    /// optionally counting external attestations
+    pub fn set_reputation_gate(
+        ctx: Context<UpdateRewardTiers>,
+        min_critical_reputation: u32,
+        accept_external_attestations: bool,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.min_critical_reputation = min_critical_reputation;
+        vault.accept_external_attestations = accept_external_attestations;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_REPUTATION_GATE,
+            ctx.accounts.program_team.key(),
+            min_critical_reputation as u64,
+        )?;
+        
+        msg!(
+            "⚙️ Critical submissions need reputation {} (external attestations {})",
+            min_critical_reputation,
+            if accept_external_attestations { "accepted" } else { "ignored" }
+        );
+        Ok(())
+    }
+
+    /// Cap the share of the vault's balance a single payout may take (only program team,
+    /// 10,000 disables the cap)
+    pub fn set_max_payout_fraction(
+        ctx: Context<UpdateRewardTiers>,
+        max_payout_fraction_bps: u16,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        require!(
+            max_payout_fraction_bps > 0 && max_payout_fraction_bps as u64 <= BPS_DENOMINATOR,
+            BugBountyError::InvalidPayoutFractionBps
+        );
+        
+        vault.max_payout_fraction_bps = max_payout_fraction_bps;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_MAX_PAYOUT_FRACTION,
+            ctx.accounts.program_team.key(),
+            max_payout_fraction_bps as u64,
+        )?;
+        
+        msg!("⚙️ Single payouts capped at {} bps of the vault balance", max_payout_fraction_bps);
+        Ok(())
+    }
+
+    /// Choose who keeps rounding dust from split payouts (only program team)
+    pub fn set_dust_policy(
+        ctx: Context<UpdateRewardTiers>,
+        dust_policy: DustPolicy,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.dust_policy = dust_policy;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_DUST_POLICY,
+            ctx.accounts.program_team.key(),
+            dust_policy as u64,
+        )?;
+        
+        msg!("⚙️ Payout rounding dust goes to the {:?}", dust_policy);
+        Ok(())
+    }
+
+    /// Require a ReportSummary with every new report (only program team)
+    pub fn set_maintain_summaries(
+        ctx: Context<UpdateRewardTiers>,
+        maintain_summaries: bool,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.maintain_summaries = maintain_summaries;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_MAINTAIN_SUMMARIES,
+            ctx.accounts.program_team.key(),
+            maintain_summaries as u64,
+        )?;
+        
+        msg!("⚙️ Report summaries {}", if maintain_summaries { "maintained" } else { "off" });
+        Ok(())
+    }
+
+    /// Pin a vault's clock so timelocks can be exercised on a local validator (test authority
+    /// only, test-clock builds only); 0 returns the vault to the cluster clock
+    #[cfg(feature = "test-clock")]
+    pub fn set_test_clock(
+        ctx: Context<SetTestClock>,
+        clock_override: i64,
+    ) -> Result<()> {
+        ctx.accounts.vault.clock_override = clock_override;
+        
+        msg!("⏱️ Vault clock pinned at {}", clock_override);
+        Ok(())
+    }
+
+    /// Report the vault's governance key as compromised (anyone, with a refundable deposit);
+    /// payouts above the Low reward are held until the program team resolves the alert
+    pub fn report_governance_compromise(
+        ctx: Context<ReportGovernanceCompromise>,
+        evidence_hash: [u8; 32],
+    ) -> Result<()> {
+        require!(evidence_hash != [0; 32], BugBountyError::EvidenceRequired);
+        
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.reporter.to_account_info(),
+                    to: ctx.accounts.compromise_alert.to_account_info(),
+                },
+            ),
+            COMPROMISE_ALERT_DEPOSIT_LAMPORTS,
+        )?;
+        
+        let vault = &mut ctx.accounts.vault;
+        let raised_at = current_time(vault)?;
+        
+        let alert = &mut ctx.accounts.compromise_alert;
+        alert.vault = vault.key();
+        alert.reporter = ctx.accounts.reporter.key();
+        alert.evidence_hash = evidence_hash;
+        alert.deposit_lamports = COMPROMISE_ALERT_DEPOSIT_LAMPORTS;
+        alert.raised_at = raised_at;
+        alert.alert_bump = ctx.bumps.compromise_alert;
+        
+        vault.compromise_alert_open = true;
+        
+        emit!(GovernanceCompromiseReported {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            alert: alert.key(),
+            reporter: alert.reporter,
+            governance_authority: vault.governance_authority,
+            evidence_hash,
+            raised_at,
+            subscriber_count: vault.subscriber_count,
+        });
+        
+        msg!("🚨 GOVERNANCE COMPROMISE REPORTED: payouts above {} held until the team resolves it", vault.low_reward);
+        Ok(())
+    }
+
+    /// Close a governance-compromise alert (only program team). A valid alert refunds the
+    /// deposit and trips the payout circuit breaker until governance is rotated; a false
+    /// one forfeits the deposit to the team. Rent always goes back to the reporter
+    pub fn resolve_compromise_alert(
+        ctx: Context<ResolveCompromiseAlert>,
+        valid: bool,
+    ) -> Result<()> {
+        let alert = &ctx.accounts.compromise_alert;
+        if !valid {
+            alert.sub_lamports(alert.deposit_lamports)?;
+            ctx.accounts.program_team.add_lamports(alert.deposit_lamports)?;
+        }
+        
+        let vault = &mut ctx.accounts.vault;
+        vault.compromise_alert_open = false;
+        if valid {
+            vault.circuit_breaker_tripped = true;
+        }
+        
+        emit!(CompromiseAlertResolved {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            alert: alert.key(),
+            reporter: alert.reporter,
+            valid,
+            deposit_returned: valid,
+            resolved_by: ctx.accounts.program_team.key(),
+        });
+        
+        if valid {
+            msg!("🚨 Governance compromise confirmed; payouts halted until the circuit breaker is reset");
+        } else {
+            msg!("✅ Compromise alert dismissed; deposit forfeited");
+        }
+        Ok(())
+    }
+
+    /// Resume payouts after the circuit breaker tripped (only program team); the window
+    /// restarts empty so the refused payout can go through
+    pub fn reset_circuit_breaker(
+        ctx: Context<UpdateRewardTiers>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_PAUSE,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        vault.circuit_breaker_tripped = false;
+        vault.payout_buckets = [PayoutBucket::default(); CIRCUIT_BREAKER_BUCKETS];
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_RESET_CIRCUIT_BREAKER,
+            ctx.accounts.program_team.key(),
+            0,
+        )?;
+        
+        emit!(PayoutCircuitBreakerReset {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            reset_by: ctx.accounts.program_team.key(),
+        });
+        
+        msg!("🔓 Payout circuit breaker reset");
+        Ok(())
+    }
+
+    /// Pause/unpause the vault (only program team)
+    ///
+    /// While paused, new intake and new commitments stop; everything that winds down
+    /// existing obligations or adds funds keeps working:
+    ///
+    /// | Instruction                        | Paused                      |
+    /// |------------------------------------|-----------------------------|
+    /// | submit_report, stake_for_priority  | blocked (`VaultInactive`)   |
+    /// | approve_report                     | blocked (`ApprovalsPaused`) |
+    /// | reject_report, reject_as_spam      | allowed                     |
+    /// | execute_payout (prior approvals)   | allowed                     |
+    /// | fund_vault, sync_vault_balance     | allowed                     |
+    /// | mint_reputation_nft                | allowed                     |
+    /// | withdraw_report, hold/release      | allowed                     |
+    pub fn toggle_vault_status(
+        ctx: Context<ToggleVaultStatus>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_PAUSE,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        require!(vault.wind_down_phase != WindDownPhase::WoundDown, BugBountyError::InvalidWindDownPhase);
+        
+        vault.vault_active = !vault.vault_active;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_TOGGLE_VAULT_STATUS,
+            ctx.accounts.program_team.key(),
+            vault.vault_active as u64,
+        )?;
+        
+        msg!("🔄 Vault status toggled: {}", vault.vault_active);
+        Ok(())
+    }
+
+    /// Stop or resume new submissions of a single severity tier (only program team)
+    /// Reports already filed in the tier move through triage and payout as usual
+    pub fn set_tier_pause(
+        ctx: Context<ToggleVaultStatus>,
+        tier: SeverityTier,
+        paused: bool,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_PAUSE,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        
+        if paused {
+            vault.paused_tiers |= tier_bit(tier);
+        } else {
+            vault.paused_tiers &= !tier_bit(tier);
+        }
+        
+        emit!(TierPauseUpdated {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            tier,
+            paused,
+            paused_tiers: vault.paused_tiers,
+            subscriber_count: vault.subscriber_count,
+        });
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_SET_TIER_PAUSE,
+            ctx.accounts.program_team.key(),
+            vault.paused_tiers as u64,
+        )?;
+        
+        msg!("⏸️ {:?} submissions {}", tier, if paused { "paused" } else { "resumed" });
+        Ok(())
+    }
+
+    /// Start shutting the vault down (program team and governance together)
+    /// Submissions stop now, approvals stop after triage_deadline, payouts continue throughout
+    pub fn begin_wind_down(
+        ctx: Context<BeginWindDown>,
+        triage_deadline: i64,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require!(vault.wind_down_phase == WindDownPhase::Live, BugBountyError::InvalidWindDownPhase);
+        let now = current_time(vault)?;
+        validate_timestamp(triage_deadline, now)?;
+        
+        vault.wind_down_phase = WindDownPhase::WindingDown;
+        vault.triage_deadline = triage_deadline;
+        vault.submissions_open = false;
+        
+        emit!(WindDownBegun {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            triage_deadline,
+            unresolved_reports: vault.unresolved_reports,
+        });
+        
+        msg!("🌅 Vault winding down, triage closes at {}", triage_deadline);
+        Ok(())
+    }
+
+    /// Close out a wind-down once triage is over and every report is resolved: sweep the
+    /// primary token account to the program team and deactivate the vault for good
+    pub fn finalize_wind_down(
+        ctx: Context<FinalizeWindDown>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require!(vault.wind_down_phase == WindDownPhase::WindingDown, BugBountyError::InvalidWindDownPhase);
+        let now = current_time(vault)?;
+        require!(now > vault.triage_deadline, BugBountyError::TriageWindowOpen);
+        require!(
+            vault.unresolved_reports == 0 && vault.committed_amount == 0,
+            BugBountyError::UnresolvedReportsRemain
+        );
+        // Only the primary token account is swept here; extra mints go through remove_reward_mint
+        require!(
+            vault.extra_reward_mints.iter().all(|mint| *mint == Pubkey::default()),
+            BugBountyError::RewardMintInUse
+        );
+        
+        // Sweep the whole balance, including donations and dust the books never saw
+        let swept_amount = ctx.accounts.vault_token_account.amount;
+        if swept_amount > 0 {
+            let seeds = vault.signer_seeds();
+            let signer_seeds: &[&[&[u8]]] = &[&seeds];
+            
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_token_account.to_account_info(),
+                    to: ctx.accounts.program_team_token_account.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+                signer_seeds,
+            );
+            
+            token::transfer(cpi_ctx, swept_amount)?;
+        }
+        
+        vault.total_withdrawn = vault.total_withdrawn.checked_add(vault.recorded_balance()?)
+            .ok_or(BugBountyError::ArithmeticOverflow)?;
+        vault.withdrawal_pending = false;
+        vault.pending_withdrawal_amount = 0;
+        vault.insurance_reserve = 0;
+        vault.vault_active = false;
+        vault.wind_down_phase = WindDownPhase::WoundDown;
+        ctx.accounts.vault_token_account.reload()?;
+        assert_vault_invariants(vault, Some(ctx.accounts.vault_token_account.amount))?;
+        
+        emit!(WindDownFinalized {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            swept_amount,
+            finalized_at: now,
+        });
+        
+        msg!("🌇 Vault wound down, {} tokens returned to program team", swept_amount);
+        Ok(())
+    }
+
+    /// Register an extra reward mint and its vault-owned token account (only program team)
+    /// Tiers start paying in it once update_reward_tiers maps them to the mint
+    pub fn add_reward_mint(
+        ctx: Context<AddRewardMint>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let mint = ctx.accounts.mint.key();
+        
+        require!(
+            Some(mint) != vault.reward_token_mint && !vault.extra_reward_mints.contains(&mint),
+            BugBountyError::RewardMintAlreadyAdded
+        );
+        let i = vault.extra_reward_mints.iter().position(|m| *m == Pubkey::default())
+            .ok_or(BugBountyError::RewardMintSlotsFull)?;
+        
+        vault.extra_reward_mints[i] = mint;
+        vault.extra_token_accounts[i] = ctx.accounts.vault_token_account.key();
+        vault.extra_funded[i] = 0;
+        vault.extra_paid_out[i] = 0;
+        vault.extra_committed[i] = 0;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_ADD_REWARD_MINT,
+            ctx.accounts.program_team.key(),
+            i as u64 + 1,
+        )?;
+        
+        msg!("🪙 Reward mint {} added", mint);
+        Ok(())
+    }
+
+    /// Retire an extra reward mint and return its token balance to the team (only program team)
+    /// No tier may still pay in the mint and no approved payout may be reserved in it
+    pub fn remove_reward_mint(
+        ctx: Context<RemoveRewardMint>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        let i = vault.extra_token_accounts.iter().zip(vault.extra_reward_mints.iter())
+            .position(|(account, mint)| {
+                *account == ctx.accounts.vault_token_account.key() && *mint != Pubkey::default()
+            })
+            .ok_or(BugBountyError::UnknownRewardMint)?;
+        let mint = vault.extra_reward_mints[i];
+        require!(
+            !vault.tier_mints.contains(&mint) && vault.extra_committed[i] == 0,
+            BugBountyError::RewardMintInUse
+        );
+        
+        let amount = ctx.accounts.vault_token_account.amount;
+        if amount > 0 {
+            let seeds = vault.signer_seeds();
+            let signer_seeds: &[&[&[u8]]] = &[&seeds];
+            
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_token_account.to_account_info(),
+                    to: ctx.accounts.program_team_token_account.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+                signer_seeds,
+            );
+            
+            token::transfer(cpi_ctx, amount)?;
+        }
+        
+        vault.extra_reward_mints[i] = Pubkey::default();
+        vault.extra_token_accounts[i] = Pubkey::default();
+        vault.extra_funded[i] = 0;
+        vault.extra_paid_out[i] = 0;
+        vault.extra_committed[i] = 0;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_REMOVE_REWARD_MINT,
+            ctx.accounts.program_team.key(),
+            amount,
+        )?;
+        
+        msg!("🪙 Reward mint {} removed, {} tokens returned to program team", mint, amount);
+        Ok(())
+    }
+
+    /// Move the vault's primary reward mint to a new token (only program team)
+    /// Blocked while approved payouts are outstanding so they aren't silently redenominated;
+    /// the old token account must be drained (withdrawals, insurance release) first. Tokens the
+    /// vault never recorded are swept to program_team_token_account when one is passed.
+    pub fn change_reward_mint(
+        ctx: Context<ChangeRewardMint>,
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        let previous_mint = ctx.accounts.old_vault_token_account.mint;
+        let new_mint = ctx.accounts.new_mint.key();
+        
+        require!(vault.committed_amount == 0, BugBountyError::ApprovedPayoutsOutstanding);
+        require!(vault.recorded_balance()? == 0, BugBountyError::RewardMintNotDrained);
+        require!(
+            new_mint != previous_mint && !vault.extra_reward_mints.contains(&new_mint),
+            BugBountyError::RewardMintAlreadyAdded
+        );
+        let count = vault.previous_mint_count as usize;
+        require!(count < MAX_PREVIOUS_MINTS, BugBountyError::PreviousMintsFull);
+        
+        // The vault stays the old account's owner, so it must not be left holding anything
+        let stray = ctx.accounts.old_vault_token_account.amount;
+        if stray > 0 {
+            let sweep_to = ctx.accounts.program_team_token_account.as_ref()
+                .ok_or(BugBountyError::RewardMintNotDrained)?;
+            let seeds = vault.signer_seeds();
+            let signer_seeds: &[&[&[u8]]] = &[&seeds];
+            
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.old_vault_token_account.to_account_info(),
+                    to: sweep_to.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+                signer_seeds,
+            );
+            
+            token::transfer(cpi_ctx, stray)?;
+            msg!("🧹 Swept {} unrecorded tokens from the old vault token account", stray);
+        }
+        
+        vault.previous_mints[count] = previous_mint;
+        vault.previous_mint_count += 1;
+        vault.reward_token_mint = Some(new_mint);
+        vault.replace_vault_token_account(ctx.accounts.new_vault_token_account.key())?;
+        // A granularity in the old mint's base units means nothing once the decimals differ
+        if ctx.accounts.new_mint.decimals != vault.reward_decimals {
+            vault.reward_decimals = ctx.accounts.new_mint.decimals;
+            vault.reward_granularity = 0;
+        }
+        // Tiers pinned to the old primary mint follow it to the new one
+        for tier_mint in vault.tier_mints.iter_mut() {
+            if *tier_mint == previous_mint {
+                *tier_mint = Pubkey::default();
+            }
+        }
+        
+        let changed_at = current_time(vault)?;
+        emit!(RewardMintChanged {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            previous_mint,
+            new_mint,
+            new_vault_token_account: vault.vault_token_account,
+            changed_at,
+        });
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_CHANGE_REWARD_MINT,
+            ctx.accounts.program_team.key(),
+            vault.previous_mint_count as u64,
+        )?;
+        
+        msg!("🪙 Reward mint changed from {} to {}", previous_mint, new_mint);
+        Ok(())
+    }
+
+    /// Update reward tiers (only program team)
+    pub fn update_reward_tiers(
+        ctx: Context<PublishRewardTiers>,
+        critical_reward: u64,
+        high_reward: u64,
+        medium_reward: u64,
+        low_reward: u64,
+        tier_mints: [Pubkey; 4],
+    ) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        
+        require_permission(
+            ctx.accounts.program_team.key(),
+            vault.program_team,
+            vault.key(),
+            ctx.accounts.role.as_ref(),
+            PERMISSION_UPDATE_TIERS,
+            BugBountyError::UnauthorizedTeam,
+        )?;
+        let rewards = [critical_reward, high_reward, medium_reward, low_reward];
+        require_nonzero_rewards(rewards)?;
+        require_granular(vault.reward_granularity, rewards)?;
+        require!(
+            tier_mints.iter().all(|mint| vault.mint_index(mint).is_some()),
+            BugBountyError::UnknownRewardMint
+        );
+        
+        // Keep the outgoing tiers on record so reports judged under them stay attributable
+        let policy = &mut ctx.accounts.policy_version;
+        policy.vault = vault.key();
+        policy.version = vault.policy_version.checked_add(1).ok_or(BugBountyError::ArithmeticOverflow)?;
+        policy.rewards = rewards;
+        policy.previous_rewards = [vault.critical_reward, vault.high_reward, vault.medium_reward, vault.low_reward];
+        policy.tier_mints = tier_mints;
+        policy.updated_by = ctx.accounts.program_team.key();
+        policy.updated_at = current_time(vault)?;
+        policy.policy_bump = ctx.bumps.policy_version;
+        vault.policy_version = policy.version;
+        
+        vault.critical_reward = critical_reward;
+        vault.high_reward = high_reward;
+        vault.medium_reward = medium_reward;
+        vault.low_reward = low_reward;
+        vault.tier_mints = tier_mints;
+        
+        record_admin_action(
+            &ctx.accounts.audit_log,
+            vault,
+            AUDIT_UPDATE_REWARD_TIERS,
+            ctx.accounts.program_team.key(),
+            critical_reward,
+        )?;
+        
+        emit!(RewardTiersUpdated {
+            vault: vault.key(),
+            sequence: vault.next_event_sequence()?,
+            rewards,
+            reward_decimals: vault.reward_decimals,
+            whole_token_rewards: whole_token_amounts(rewards, vault.reward_decimals),
+            subscriber_count: vault.subscriber_count,
+            policy_version: vault.policy_version,
+        });
+        
+        msg!("⚙️ Reward tiers updated to policy version {}", vault.policy_version);
+        Ok(())
+    }
+
+
+    /// Delete vault and return remaining funds to program team
+    /// Only the program team can delete the vault
+    /// Vault must be inactive and have no pending reports
+    pub fn delete_vault(
+        ctx: Context<DeleteVault>,
+        force_delete: bool,
+    ) -> Result<()> {
+        let vault = &ctx.accounts.vault;
+        
+        // Vault must be inactive before deletion
+        require!(!vault.vault_active, BugBountyError::VaultMustBeInactive);
+        // Only the primary token account is swept here; extra mints go through remove_reward_mint
+        require!(
+            vault.extra_reward_mints.iter().all(|mint| *mint == Pubkey::default()),
+            BugBountyError::RewardMintInUse
+        );
+        
+        // Check if there are any pending reports that haven't been resolved
+        let pending_reports = vault.total_reports
+            .checked_sub(vault.approved_reports)
+            .ok_or(BugBountyError::ArithmeticUnderflow)?;
+        
+        // Allow deletion only if all reports have been processed
+        // Or the team accepts responsibility for unprocessed reports
+        require!(
+            pending_reports == 0 || force_delete,
+            BugBountyError::HasPendingReports
+        );
+        
+        // Transfer remaining tokens from vault token account to program team
+        let remaining_balance = vault.recorded_balance()?;
+        
+        if remaining_balance > 0 {
+            let seeds = vault.signer_seeds();
+            let signer_seeds: &[&[&[u8]]] = &[&seeds];
+            
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_token_account.to_account_info(),
+                    to: ctx.accounts.program_team_token_account.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                signer_seeds,
+            );
+            
+            token::transfer(cpi_ctx, remaining_balance)?;
+        }
+        
+        msg!("🗑️ Vault deleted. {} tokens returned to program team", remaining_balance);
+        
+        // The vault account will be closed automatically due to the `close` constraint
+        // in the DeleteVault accounts struct
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ACCOUNT CONTEXTS
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(critical_reward: u64, high_reward: u64, medium_reward: u64, low_reward: u64, initial_funding: u64, reward_token_mint: Option<Pubkey>)]
+pub struct CreateBountyVault<'info> {
+    #[account(mut)]
+    pub program_team: Signer<'info>,
+    
+    pub governance_authority: SystemAccount<'info>,
+    
+    #[account(
+        init,
+        payer = program_team,
+        space = 8 + std::mem::size_of::<BugBountyVault>() + MAX_PROJECT_NAME_LEN,
+        seeds = [VAULT_SEED, program_team.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// Must be controlled by the vault PDA, which signs every payout
+    #[account(
+        constraint = vault_token_account.owner == vault.key() @ BugBountyError::InvalidVaultTokenAccount,
+        constraint = reward_token_mint.is_none_or(|mint| vault_token_account.mint == mint)
+            @ BugBountyError::InvalidVaultTokenAccount,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    /// Mint of the vault token account; its decimals are recorded for display
+    #[account(address = vault_token_account.mint @ BugBountyError::InvalidVaultTokenAccount)]
+    pub reward_mint: Account<'info, Mint>,
+    
+    /// Latest registry page; the vault is listed there when supplied
+    #[account(mut)]
+    pub vault_registry: Option<AccountLoader<'info, VaultRegistryPage>>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(critical_reward: u64, high_reward: u64, medium_reward: u64, low_reward: u64, reward_token_mint: Option<Pubkey>)]
+pub struct BootstrapPlatformVault<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.config_bump,
+        has_one = admin @ BugBountyError::NotPlatformAdmin
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    pub governance_authority: SystemAccount<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + std::mem::size_of::<BugBountyVault>() + MAX_PROJECT_NAME_LEN,
+        seeds = [VAULT_SEED, PLATFORM_VAULT_SEED],
+        bump
+    )]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// Must be controlled by the vault PDA, which signs every payout
+    #[account(
+        constraint = vault_token_account.owner == vault.key() @ BugBountyError::InvalidVaultTokenAccount,
+        constraint = reward_token_mint.is_none_or(|mint| vault_token_account.mint == mint)
+            @ BugBountyError::InvalidVaultTokenAccount,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    /// Mint of the vault token account; its decimals are recorded for display
+    #[account(address = vault_token_account.mint @ BugBountyError::InvalidVaultTokenAccount)]
+    pub reward_mint: Account<'info, Mint>,
+    
+    /// Latest registry page; the vault is listed there when supplied
+    #[account(mut)]
+    pub vault_registry: Option<AccountLoader<'info, VaultRegistryPage>>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(reward_token_mint: Option<Pubkey>)]
+pub struct CreateVaultFromTemplate<'info> {
+    #[account(mut)]
+    pub program_team: Signer<'info>,
+    
+    pub governance_authority: SystemAccount<'info>,
+    
+    #[account(
+        init,
+        payer = program_team,
+        space = 8 + std::mem::size_of::<BugBountyVault>() + MAX_PROJECT_NAME_LEN,
+        seeds = [VAULT_SEED, program_team.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// Existing vault to copy settings from; Account checks it is owned by this program
+    pub template_vault: Account<'info, BugBountyVault>,
+    
+    /// Must be controlled by the vault PDA, which signs every payout
+    #[account(
+        constraint = vault_token_account.owner == vault.key() @ BugBountyError::InvalidVaultTokenAccount,
+        constraint = reward_token_mint.is_none_or(|mint| vault_token_account.mint == mint)
+            @ BugBountyError::InvalidVaultTokenAccount,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    /// Mint of the vault token account; its decimals are recorded for display
+    #[account(address = vault_token_account.mint @ BugBountyError::InvalidVaultTokenAccount)]
+    pub reward_mint: Account<'info, Mint>,
+    
+    /// Latest registry page; the vault is listed there when supplied
+    #[account(mut)]
+    pub vault_registry: Option<AccountLoader<'info, VaultRegistryPage>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(target_program: Pubkey)]
+pub struct VerifyVaultOwnership<'info> {
+    pub program_team: Signer<'info>,
+    
+    #[account(mut, has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// The target program's ProgramData, derived from its address under the upgradeable loader
+    #[account(
+        seeds = [target_program.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID
+    )]
+    pub program_data: Account<'info, ProgramData>,
+}
+
+#[derive(Accounts)]
+pub struct AssertActiveVault<'info> {
+    pub vault: Account<'info, BugBountyVault>,
+}
+
+#[derive(Accounts)]
+pub struct ChallengeVerification<'info> {
+    pub challenger: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// CHECK: ProgramData of the verified program; may be closed, so deserialized by hand
+    #[account(
+        seeds = [vault.verified_program.unwrap_or_default().as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID
+    )]
+    pub program_data: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateAndFundVault<'info> {
+    #[account(mut)]
+    pub program_team: Signer<'info>,
+    
+    pub governance_authority: SystemAccount<'info>,
+    
+    #[account(
+        init,
+        payer = program_team,
+        space = 8 + std::mem::size_of::<BugBountyVault>() + MAX_PROJECT_NAME_LEN,
+        seeds = [VAULT_SEED, program_team.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    pub reward_mint: Account<'info, Mint>,
+    
+    /// The vault PDA's associated token account, so only the vault can move its tokens
+    #[account(
+        init,
+        payer = program_team,
+        associated_token::mint = reward_mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = program_team,
+    )]
+    pub program_team_token_account: Account<'info, TokenAccount>,
+    
+    /// Latest registry page; the vault is listed there when supplied
+    #[account(mut)]
+    pub vault_registry: Option<AccountLoader<'info, VaultRegistryPage>>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+#[instruction(severity: SeverityTier, ipfs_hash: [u8; 32])]
+pub struct SubmitReport<'info> {
+    #[account(mut)]
+    pub researcher: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        init,
+        payer = researcher,
+        space = 8 + std::mem::size_of::<VulnerabilityReport>(),
+        seeds = [REPORT_SEED, vault.key().as_ref(), researcher.key().as_ref(), &vault.total_reports.to_le_bytes()],
+        bump
+    )]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    /// Required when the vault caps open reports per researcher
+    #[account(
+        mut,
+        seeds = [SUBMISSION_COUNTER_SEED, vault.key().as_ref(), researcher.key().as_ref()],
+        bump = submission_counter.counter_bump
+    )]
+    pub submission_counter: Option<Account<'info, SubmissionCounter>>,
+    
+    /// The researcher's report of the same finding in another vault, if any
+    #[account(
+        constraint = linked_report.researcher == researcher.key() @ BugBountyError::InvalidLinkedReport,
+        constraint = linked_report.vault != vault.key() @ BugBountyError::InvalidLinkedReport
+    )]
+    pub linked_report: Option<Account<'info, VulnerabilityReport>>,
+    
+    /// The researcher's auto-approval grant, if any
+    #[account(
+        seeds = [TRUSTED_RESEARCHER_SEED, vault.key().as_ref(), researcher.key().as_ref()],
+        bump = trusted_researcher.trusted_bump
+    )]
+    pub trusted_researcher: Option<Account<'info, TrustedResearcher>>,
+    
+    /// On-chain history, checked by the Critical reputation gate
+    #[account(
+        seeds = [RESEARCHER_PROFILE_SEED, researcher.key().as_ref()],
+        bump = researcher_profile.profile_bump
+    )]
+    pub researcher_profile: Option<Account<'info, ResearcherProfile>>,
+    
+    /// Off-chain history, accepted by the Critical reputation gate when the vault opts in
+    #[account(
+        seeds = [EXTERNAL_ATTESTATION_SEED, researcher.key().as_ref()],
+        bump = external_attestation.attestation_bump
+    )]
+    pub external_attestation: Option<Account<'info, ExternalAttestation>>,
+    
+    /// Compact copy for light clients, required exactly when the vault maintains summaries
+    #[account(
+        init,
+        payer = researcher,
+        space = REPORT_SUMMARY_SPACE,
+        seeds = [REPORT_SUMMARY_SEED, report.key().as_ref()],
+        bump
+    )]
+    pub report_summary: Option<Account<'info, ReportSummary>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitReportSubsidized<'info> {
+    /// Signs for the submission but pays nothing; the vault funds the new accounts
+    pub researcher: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// CHECK: created with vault-funded rent in the handler
+    #[account(
+        mut,
+        seeds = [REPORT_SEED, vault.key().as_ref(), researcher.key().as_ref(), &vault.total_reports.to_le_bytes()],
+        bump
+    )]
+    pub report: UncheckedAccount<'info>,
+    
+    /// Required when the vault caps open reports per researcher
+    #[account(
+        mut,
+        seeds = [SUBMISSION_COUNTER_SEED, vault.key().as_ref(), researcher.key().as_ref()],
+        bump = submission_counter.counter_bump
+    )]
+    pub submission_counter: Option<Account<'info, SubmissionCounter>>,
+    
+    /// The researcher's report of the same finding in another vault, if any
+    #[account(
+        constraint = linked_report.researcher == researcher.key() @ BugBountyError::InvalidLinkedReport,
+        constraint = linked_report.vault != vault.key() @ BugBountyError::InvalidLinkedReport
+    )]
+    pub linked_report: Option<Account<'info, VulnerabilityReport>>,
+    
+    /// The researcher's auto-approval grant, if any
+    #[account(
+        seeds = [TRUSTED_RESEARCHER_SEED, vault.key().as_ref(), researcher.key().as_ref()],
+        bump = trusted_researcher.trusted_bump
+    )]
+    pub trusted_researcher: Option<Account<'info, TrustedResearcher>>,
+    
+    /// On-chain history, checked by the Critical reputation gate
+    #[account(
+        seeds = [RESEARCHER_PROFILE_SEED, researcher.key().as_ref()],
+        bump = researcher_profile.profile_bump
+    )]
+    pub researcher_profile: Option<Account<'info, ResearcherProfile>>,
+    
+    /// Off-chain history, accepted by the Critical reputation gate when the vault opts in
+    #[account(
+        seeds = [EXTERNAL_ATTESTATION_SEED, researcher.key().as_ref()],
+        bump = external_attestation.attestation_bump
+    )]
+    pub external_attestation: Option<Account<'info, ExternalAttestation>>,
+    
+    /// CHECK: compact copy for light clients, created with vault-funded rent in the handler;
+    /// required exactly when the vault maintains summaries
+    #[account(mut, seeds = [REPORT_SUMMARY_SEED, report.key().as_ref()], bump)]
+    pub report_summary: Option<UncheckedAccount<'info>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(researcher: Pubkey)]
+pub struct SubmitReportSponsored<'info> {
+    /// Relayer paying rent and fees; gets nothing from the payout
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        init,
+        payer = sponsor,
+        space = 8 + std::mem::size_of::<VulnerabilityReport>(),
+        seeds = [REPORT_SEED, vault.key().as_ref(), researcher.as_ref(), &vault.total_reports.to_le_bytes()],
+        bump
+    )]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    /// Holds the researcher's sponsored submission nonce
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        space = 8 + std::mem::size_of::<SubmissionCounter>(),
+        seeds = [SUBMISSION_COUNTER_SEED, vault.key().as_ref(), researcher.as_ref()],
+        bump
+    )]
+    pub submission_counter: Account<'info, SubmissionCounter>,
+    
+    /// CHECK: address constrained to the instructions sysvar
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// On-chain history, checked by the Critical reputation gate
+    #[account(
+        seeds = [RESEARCHER_PROFILE_SEED, researcher.as_ref()],
+        bump = researcher_profile.profile_bump
+    )]
+    pub researcher_profile: Option<Account<'info, ResearcherProfile>>,
+    
+    /// Off-chain history, accepted by the Critical reputation gate when the vault opts in
+    #[account(
+        seeds = [EXTERNAL_ATTESTATION_SEED, researcher.as_ref()],
+        bump = external_attestation.attestation_bump
+    )]
+    pub external_attestation: Option<Account<'info, ExternalAttestation>>,
+    
+    /// Compact copy for light clients, required exactly when the vault maintains summaries
+    #[account(
+        init,
+        payer = sponsor,
+        space = REPORT_SUMMARY_SPACE,
+        seeds = [REPORT_SUMMARY_SEED, report.key().as_ref()],
+        bump
+    )]
+    pub report_summary: Option<Account<'info, ReportSummary>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateReport<'info> {
+    /// Funds the rent for growing the report to the current layout
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// CHECK: legacy layout can't be deserialized as VulnerabilityReport; validated in the handler
+    #[account(mut, owner = crate::ID)]
+    pub report: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateVault<'info> {
+    /// Funds the rent for growing the vault to the current layout
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: legacy layout can't be deserialized as BugBountyVault; validated in the handler
+    #[account(mut, owner = crate::ID)]
+    pub vault: UncheckedAccount<'info>,
+    
+    /// The vault's reward mint; omitted when it has none
+    pub reward_mint: Option<Account<'info, Mint>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveReport<'info> {
+    pub governance_authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key())]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    /// Role of a delegated signer, not needed when the legacy authority signs
+    #[account(mut)]
+    pub role: Option<Account<'info, Role>>,
+    
+    /// Updated alongside the report when passed
+    #[account(
+        mut,
+        seeds = [REPORT_SUMMARY_SEED, report.key().as_ref()],
+        bump = report_summary.summary_bump
+    )]
+    pub report_summary: Option<Account<'info, ReportSummary>>,
+}
+
+#[derive(Accounts)]
+pub struct RejectReport<'info> {
+    pub governance_authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key())]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(mut)]
+    pub submission_counter: Option<Account<'info, SubmissionCounter>>,
+    
+    /// Role of a delegated signer, not needed when the legacy authority signs
+    pub role: Option<Account<'info, Role>>,
+    
+    /// Updated alongside the report when passed
+    #[account(
+        mut,
+        seeds = [REPORT_SUMMARY_SEED, report.key().as_ref()],
+        bump = report_summary.summary_bump
+    )]
+    pub report_summary: Option<Account<'info, ReportSummary>>,
+}
+
+#[derive(Accounts)]
+pub struct RejectReportWithResponse<'info> {
+    #[account(mut)]
+    pub governance_authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key())]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(mut)]
+    pub submission_counter: Option<Account<'info, SubmissionCounter>>,
+    
+    /// Role of a delegated signer, not needed when the legacy authority signs
+    pub role: Option<Account<'info, Role>>,
+    
+    #[account(
+        init,
+        payer = governance_authority,
+        space = 8 + std::mem::size_of::<ResponsePacket>(),
+        seeds = [RESPONSE_PACKET_SEED, report.key().as_ref()],
+        bump
+    )]
+    pub response_packet: Account<'info, ResponsePacket>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RejectAsSpam<'info> {
+    pub governance_authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key())]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(mut)]
+    pub submission_counter: Option<Account<'info, SubmissionCounter>>,
+    
+    /// Role of a delegated signer, not needed when the legacy authority signs
+    pub role: Option<Account<'info, Role>>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawReport<'info> {
+    pub researcher: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        constraint = report.vault == vault.key() @ BugBountyError::ReportVaultMismatch,
+        constraint = report.researcher == researcher.key() @ BugBountyError::UnauthorizedResearcher
+    )]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(mut)]
+    pub submission_counter: Option<Account<'info, SubmissionCounter>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseReport<'info> {
+    pub researcher: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        has_one = vault @ BugBountyError::ReportVaultMismatch,
+        has_one = researcher @ BugBountyError::UnauthorizedResearcher,
+        close = rent_payer
+    )]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    /// CHECK: Refund destination, must be the account that paid the report's rent
+    #[account(mut, address = report.rent_refund_address() @ BugBountyError::RentPayerMismatch)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitSubmissionCounter<'info> {
+    #[account(mut)]
+    pub researcher: Signer<'info>,
+    
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        init,
+        payer = researcher,
+        space = 8 + std::mem::size_of::<SubmissionCounter>(),
+        seeds = [SUBMISSION_COUNTER_SEED, vault.key().as_ref(), researcher.key().as_ref()],
+        bump
+    )]
+    pub submission_counter: Account<'info, SubmissionCounter>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct EnsureResearcherAccounts<'info> {
+    #[account(mut)]
+    pub researcher: Signer<'info>,
+    
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = researcher,
+        space = 8 + std::mem::size_of::<SubmissionCounter>(),
+        seeds = [SUBMISSION_COUNTER_SEED, vault.key().as_ref(), researcher.key().as_ref()],
+        bump
+    )]
+    pub submission_counter: Account<'info, SubmissionCounter>,
+    
+    #[account(
+        init_if_needed,
+        payer = researcher,
+        space = 8 + std::mem::size_of::<ResearcherProfile>(),
+        seeds = [RESEARCHER_PROFILE_SEED, researcher.key().as_ref()],
+        bump
+    )]
+    pub researcher_profile: Account<'info, ResearcherProfile>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitAuditLog<'info> {
+    #[account(mut)]
+    pub program_team: Signer<'info>,
+    
+    #[account(has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        init,
+        payer = program_team,
+        space = 8 + std::mem::size_of::<AuditLog>(),
+        seeds = [AUDIT_LOG_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub audit_log: AccountLoader<'info, AuditLog>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct RegisterHandle<'info> {
+    #[account(mut)]
+    pub researcher: Signer<'info>,
+    
+    #[account(seeds = [PLATFORM_CONFIG_SEED], bump = platform_config.config_bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(mut, address = platform_config.treasury @ BugBountyError::TreasuryMismatch)]
+    pub treasury: SystemAccount<'info>,
+    
+    #[account(
+        init,
+        payer = researcher,
+        space = 8 + std::mem::size_of::<Handle>() + MAX_HANDLE_LEN,
+        seeds = [HANDLE_SEED, name.as_bytes()],
+        bump
+    )]
+    pub handle: Account<'info, Handle>,
+    
+    #[account(
+        init_if_needed,
+        payer = researcher,
+        space = 8 + std::mem::size_of::<ResearcherProfile>(),
+        seeds = [RESEARCHER_PROFILE_SEED, researcher.key().as_ref()],
+        bump
+    )]
+    pub researcher_profile: Account<'info, ResearcherProfile>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseHandle<'info> {
+    #[account(mut)]
+    pub researcher: Signer<'info>,
+    
+    #[account(
+        mut,
+        close = researcher,
+        has_one = researcher @ BugBountyError::UnauthorizedResearcher,
+        seeds = [HANDLE_SEED, handle.name.as_bytes()],
+        bump = handle.handle_bump
+    )]
+    pub handle: Account<'info, Handle>,
+    
+    #[account(
+        mut,
+        seeds = [RESEARCHER_PROFILE_SEED, researcher.key().as_ref()],
+        bump = researcher_profile.profile_bump
+    )]
+    pub researcher_profile: Account<'info, ResearcherProfile>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimHandle<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.config_bump,
+        has_one = admin @ BugBountyError::NotPlatformAdmin
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Current holder, refunded the handle's rent
+    #[account(mut)]
+    pub researcher: SystemAccount<'info>,
+    
+    #[account(
+        mut,
+        close = researcher,
+        has_one = researcher @ BugBountyError::UnauthorizedResearcher,
+        seeds = [HANDLE_SEED, handle.name.as_bytes()],
+        bump = handle.handle_bump
+    )]
+    pub handle: Account<'info, Handle>,
+    
+    #[account(
+        mut,
+        seeds = [RESEARCHER_PROFILE_SEED, researcher.key().as_ref()],
+        bump = researcher_profile.profile_bump
+    )]
+    pub researcher_profile: Account<'info, ResearcherProfile>,
+}
+
+#[derive(Accounts)]
+pub struct SubscribeVault<'info> {
+    #[account(mut)]
+    pub researcher: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        init,
+        payer = researcher,
+        space = 8 + std::mem::size_of::<Subscription>(),
+        seeds = [SUBSCRIPTION_SEED, vault.key().as_ref(), researcher.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnsubscribeVault<'info> {
+    #[account(mut)]
+    pub researcher: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        close = researcher,
+        has_one = vault,
+        has_one = researcher @ BugBountyError::UnauthorizedResearcher,
+        seeds = [SUBSCRIPTION_SEED, vault.key().as_ref(), researcher.key().as_ref()],
+        bump = subscription.subscription_bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+}
+
+#[derive(Accounts)]
+pub struct OpenRegistryPage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(mut, seeds = [PLATFORM_CONFIG_SEED], bump = platform_config.config_bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    /// Last page opened so far, required from the second page on
+    pub previous_page: Option<AccountLoader<'info, VaultRegistryPage>>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<VaultRegistryPage>(),
+        seeds = [VAULT_REGISTRY_SEED, &platform_config.registry_page_count.to_le_bytes()],
+        bump
+    )]
+    pub vault_registry: AccountLoader<'info, VaultRegistryPage>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageVaultRegistry<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.config_bump,
+        has_one = admin @ BugBountyError::NotPlatformAdmin
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut)]
+    pub vault_registry: AccountLoader<'info, VaultRegistryPage>,
+}
+
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    pub program_team: Signer<'info>,
+    
+    #[account(mut, has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// CHECK: Any wallet may be granted a role
+    pub member: UncheckedAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = program_team,
+        space = 8 + std::mem::size_of::<Role>(),
+        seeds = [ROLE_SEED, vault.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub role: Account<'info, Role>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(mut)]
+    pub program_team: Signer<'info>,
+    
+    #[account(mut, has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        seeds = [ROLE_SEED, vault.key().as_ref(), role.member.as_ref()],
+        bump = role.role_bump
+    )]
+    pub role: Account<'info, Role>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+pub struct SetDelegationCaps<'info> {
+    pub governance_authority: Signer<'info>,
+    
+    #[account(mut, has_one = governance_authority @ BugBountyError::NotGovernanceAuthority)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        seeds = [ROLE_SEED, vault.key().as_ref(), role.member.as_ref()],
+        bump = role.role_bump
+    )]
+    pub role: Account<'info, Role>,
+}
+
+#[derive(Accounts)]
+pub struct AcknowledgeReport<'info> {
+    pub governance_authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key())]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    /// Role of a delegated signer, not needed when the legacy authority signs
+    pub role: Option<Account<'info, Role>>,
+}
+
+#[derive(Accounts)]
+pub struct HoldReport<'info> {
+    pub governance_authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key())]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    /// Role of a delegated signer, not needed when the legacy authority signs
+    pub role: Option<Account<'info, Role>>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+pub struct ExpireApproval<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key())]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(mut)]
+    pub submission_counter: Option<Account<'info, SubmissionCounter>>,
+    
+    /// Role of the delegate who approved, refunded the expired payout against its caps;
+    /// required when the approval was charged to it
+    #[account(
+        mut,
+        seeds = [ROLE_SEED, vault.key().as_ref(), report.approver.unwrap_or_default().as_ref()],
+        bump = approver_role.role_bump
+    )]
+    pub approver_role: Option<Account<'info, Role>>,
+}
+
+#[derive(Accounts)]
+pub struct GovernanceRecovery<'info> {
+    pub program_team: Signer<'info>,
+    
+    #[account(mut, has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+pub struct CancelGovernanceRecovery<'info> {
+    pub governance_authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = governance_authority @ BugBountyError::NotGovernanceAuthority
+    )]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+pub struct CoApproveReport<'info> {
+    pub program_team: Signer<'info>,
+    
+    #[account(mut, has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key() @ BugBountyError::ReportVaultMismatch)]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+pub struct EscalateToPlatform<'info> {
+    pub researcher: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        constraint = report.vault == vault.key(),
+        constraint = report.researcher == researcher.key() @ BugBountyError::UnauthorizedResearcher
+    )]
+    pub report: Account<'info, VulnerabilityReport>,
+}
+
+#[derive(Accounts)]
+pub struct DisputeSeverity<'info> {
+    pub researcher: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        constraint = report.vault == vault.key() @ BugBountyError::ReportVaultMismatch,
+        constraint = report.researcher == researcher.key() @ BugBountyError::UnauthorizedResearcher
+    )]
+    pub report: Account<'info, VulnerabilityReport>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveSeverityDispute<'info> {
+    pub governance_authority: Signer<'info>,
+    
+    #[account(mut, has_one = governance_authority @ BugBountyError::NotGovernanceAuthority)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key() @ BugBountyError::ReportVaultMismatch)]
+    pub report: Account<'info, VulnerabilityReport>,
+}
+
+#[derive(Accounts)]
+#[instruction(researcher: Pubkey)]
+pub struct GrantTrustedResearcher<'info> {
+    #[account(mut)]
+    pub governance_authority: Signer<'info>,
+    
+    #[account(mut, has_one = governance_authority @ BugBountyError::NotGovernanceAuthority)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = governance_authority,
+        space = 8 + std::mem::size_of::<TrustedResearcher>(),
+        seeds = [TRUSTED_RESEARCHER_SEED, vault.key().as_ref(), researcher.as_ref()],
+        bump
+    )]
+    pub trusted_researcher: Account<'info, TrustedResearcher>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeTrustedResearcher<'info> {
+    #[account(mut)]
+    pub governance_authority: Signer<'info>,
+    
+    #[account(mut, has_one = governance_authority @ BugBountyError::NotGovernanceAuthority)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        close = governance_authority,
+        has_one = vault,
+        seeds = [TRUSTED_RESEARCHER_SEED, vault.key().as_ref(), trusted_researcher.researcher.as_ref()],
+        bump = trusted_researcher.trusted_bump
+    )]
+    pub trusted_researcher: Account<'info, TrustedResearcher>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeAutoApproval<'info> {
+    pub governance_authority: Signer<'info>,
+    
+    #[account(mut, has_one = governance_authority @ BugBountyError::NotGovernanceAuthority)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key() @ BugBountyError::ReportVaultMismatch)]
+    pub report: Account<'info, VulnerabilityReport>,
+}
+
+#[derive(Accounts)]
+pub struct Arbitrate<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.config_bump,
+        has_one = admin @ BugBountyError::NotPlatformAdmin
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key())]
+    pub report: Account<'info, VulnerabilityReport>,
+}
+
+#[derive(Accounts)]
+pub struct PostGovernanceBond<'info> {
+    #[account(mut)]
+    pub governance_authority: Signer<'info>,
+    
+    #[account(mut, has_one = governance_authority @ BugBountyError::NotGovernanceAuthority)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// Bonds are held in the primary reward mint, so slashes can refill the vault
+    #[account(
+        constraint = vault.reward_token_mint == Some(mint.key()) @ BugBountyError::WrongRewardMint
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(mut, token::mint = mint, token::authority = governance_authority)]
+    pub governance_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = governance_authority,
+        space = 8 + std::mem::size_of::<GovernanceBond>(),
+        seeds = [GOVERNANCE_BOND_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub governance_bond: Account<'info, GovernanceBond>,
+    
+    #[account(
+        init_if_needed,
+        payer = governance_authority,
+        seeds = [GOVERNANCE_BOND_ESCROW_SEED, vault.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = governance_bond,
+    )]
+    pub bond_escrow: Account<'info, TokenAccount>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RequestGovernanceBondWithdrawal<'info> {
+    pub governance_authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        has_one = vault,
+        has_one = governance_authority @ BugBountyError::NotGovernanceAuthority,
+        seeds = [GOVERNANCE_BOND_SEED, vault.key().as_ref()],
+        bump = governance_bond.bond_bump
+    )]
+    pub governance_bond: Account<'info, GovernanceBond>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawGovernanceBond<'info> {
+    #[account(mut)]
+    pub governance_authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        close = governance_authority,
+        has_one = vault,
+        has_one = governance_authority @ BugBountyError::NotGovernanceAuthority,
+        seeds = [GOVERNANCE_BOND_SEED, vault.key().as_ref()],
+        bump = governance_bond.bond_bump
+    )]
+    pub governance_bond: Account<'info, GovernanceBond>,
+    
+    #[account(mut, address = governance_bond.escrow)]
+    pub bond_escrow: Account<'info, TokenAccount>,
+    
+    #[account(mut, token::authority = governance_authority)]
+    pub governance_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SlashGovernanceBond<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.config_bump,
+        has_one = admin @ BugBountyError::NotPlatformAdmin
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key() @ BugBountyError::ReportVaultMismatch)]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(
+        has_one = vault,
+        seeds = [GOVERNANCE_BOND_SEED, vault.key().as_ref()],
+        bump = governance_bond.bond_bump
+    )]
+    pub governance_bond: Account<'info, GovernanceBond>,
+    
+    #[account(mut, address = governance_bond.escrow)]
+    pub bond_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        address = vault.vault_token_account @ BugBountyError::VaultTokenAccountMismatch
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct PublishReport<'info> {
+    pub payer: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key())]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    /// Co-signers required only to publish before the embargo ends
+    pub researcher: Option<Signer<'info>>,
+    pub governance_authority: Option<Signer<'info>>,
+    
+    #[account(mut)]
+    pub reputation_nft: Option<Account<'info, ReputationNFT>>,
+}
+
+#[derive(Accounts)]
+pub struct MarkRemediated<'info> {
+    pub program_team: Signer<'info>,
+    
+    #[account(mut, has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key() @ BugBountyError::ReportVaultMismatch)]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+pub struct AttachPoc<'info> {
+    pub researcher: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        has_one = vault @ BugBountyError::ReportVaultMismatch,
+        constraint = report.researcher == researcher.key() @ BugBountyError::UnauthorizedResearcher
+    )]
+    pub report: Account<'info, VulnerabilityReport>,
+}
+
+#[derive(Accounts)]
+pub struct AttachInvoice<'info> {
+    /// The report's researcher or the vault's program team
+    pub authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        has_one = vault @ BugBountyError::ReportVaultMismatch,
+        constraint = authority.key() == report.researcher || authority.key() == vault.program_team
+            @ BugBountyError::UnauthorizedResearcher
+    )]
+    pub report: Account<'info, VulnerabilityReport>,
+}
+
+#[derive(Accounts)]
+pub struct PreviewPayout<'info> {
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(constraint = report.vault == vault.key() @ BugBountyError::ReportVaultMismatch)]
+    pub report: Account<'info, VulnerabilityReport>,
+}
+
+#[derive(Accounts)]
+pub struct ReadReportPolicy<'info> {
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(constraint = report.vault == vault.key() @ BugBountyError::ReportVaultMismatch)]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(constraint = policy_version.vault == vault.key() @ BugBountyError::PolicyVersionMismatch)]
+    pub policy_version: Option<Account<'info, PolicyVersion>>,
+}
+
+#[derive(Accounts)]
+pub struct ReadVault<'info> {
+    pub vault: Account<'info, BugBountyVault>,
+}
+
+#[derive(Accounts)]
+#[instruction(researcher: Pubkey)]
+pub struct CanSubmit<'info> {
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// The researcher's counter, if they have one
+    #[account(
+        seeds = [SUBMISSION_COUNTER_SEED, vault.key().as_ref(), researcher.as_ref()],
+        bump = submission_counter.counter_bump
+    )]
+    pub submission_counter: Option<Account<'info, SubmissionCounter>>,
+}
+
+#[derive(Accounts)]
+pub struct DeclassifyReport<'info> {
+    pub governance_authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = governance_authority @ BugBountyError::NotGovernanceAuthority
+    )]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key())]
+    pub report: Account<'info, VulnerabilityReport>,
+}
+
+#[derive(Accounts)]
+pub struct ExecutePayout<'info> {
+    /// The report's researcher, or a member registered with firm_member by its payout recipient.
+    /// Not written or charged, so a PDA researcher can sign through CPI without holding lamports
+    pub researcher: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key())]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    /// CHECK: Vault token account
+    #[account(mut)]
+    pub vault_token_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Researcher token account
+    #[account(mut)]
+    pub researcher_token_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Signs token transfers out of the vault; checked against the stored authority
+    #[account(address = vault.vault_authority @ BugBountyError::VaultAuthorityMismatch)]
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    /// Donation leg destination, required only when the report has a donation split
+    #[account(mut)]
+    pub donation_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Claim escrow receiving the researcher leg instead of researcher_token_account
+    #[account(mut)]
+    pub claim_escrow: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub submission_counter: Option<Account<'info, SubmissionCounter>>,
+    
+    /// Credited with the payout towards milestone badges when supplied
+    #[account(
+        mut,
+        seeds = [RESEARCHER_PROFILE_SEED, report.researcher.as_ref()],
+        bump = researcher_profile.profile_bump
+    )]
+    pub researcher_profile: Option<Account<'info, ResearcherProfile>>,
+    
+    /// Required when a firm member rather than the researcher signs
+    #[account(
+        seeds = [FIRM_MEMBER_SEED, firm_member.recipient.as_ref(), researcher.key().as_ref()],
+        bump = firm_member.firm_member_bump
+    )]
+    pub firm_member: Option<Account<'info, FirmMember>>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    /// Updated alongside the report when passed
+    #[account(
+        mut,
+        seeds = [REPORT_SUMMARY_SEED, report.key().as_ref()],
+        bump = report_summary.summary_bump
+    )]
+    pub report_summary: Option<Account<'info, ReportSummary>>,
+}
+
+#[derive(Accounts)]
+#[instruction(milestone_id: u8)]
+pub struct ClaimMilestone<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        seeds = [RESEARCHER_PROFILE_SEED, researcher_profile.researcher.as_ref()],
+        bump = researcher_profile.profile_bump
+    )]
+    pub researcher_profile: Account<'info, ResearcherProfile>,
+    
+    /// Seeded by the milestone id, so each milestone can be claimed only once
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<MilestoneBadge>(),
+        seeds = [MILESTONE_SEED, researcher_profile.researcher.as_ref(), &[milestone_id]],
+        bump
+    )]
+    pub milestone_badge: Account<'info, MilestoneBadge>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenClaimEscrow<'info> {
+    #[account(mut)]
+    pub researcher: Signer<'info>,
+    
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        constraint = report.vault == vault.key(),
+        constraint = report.researcher == researcher.key() @ BugBountyError::UnauthorizedResearcher
+    )]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    /// The report's payout mint, or the vault's primary mint when the report has none
+    #[account(
+        constraint = if report.payout_mint == Pubkey::default() {
+            vault.reward_token_mint.is_none_or(|m| m == mint.key())
+        } else {
+            report.payout_mint == mint.key()
+        } @ BugBountyError::WrongRewardMint
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    /// CHECK: PDA signing for the report escrows, holds no data
+    #[account(seeds = [ESCROW_AUTHORITY_SEED, report.key().as_ref()], bump)]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = researcher,
+        seeds = [CLAIM_ESCROW_SEED, report.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = escrow_authority,
+    )]
+    pub claim_escrow: Account<'info, TokenAccount>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimEscrow<'info> {
+    pub researcher: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        has_one = vault @ BugBountyError::ReportVaultMismatch,
+        constraint = report.researcher == researcher.key() @ BugBountyError::UnauthorizedResearcher
+    )]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(
+        mut,
+        constraint = Some(claim_escrow.key()) == report.claim_escrow @ BugBountyError::InvalidClaimEscrow
+    )]
+    pub claim_escrow: Account<'info, TokenAccount>,
+    
+    /// CHECK: PDA signing for the report escrows, holds no data
+    #[account(seeds = [ESCROW_AUTHORITY_SEED, report.key().as_ref()], bump)]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = destination_token_account.owner == researcher.key() @ BugBountyError::UnauthorizedResearcher
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimAs<'info> {
+    pub researcher: Signer<'info>,
+    
+    #[account(seeds = [PLATFORM_CONFIG_SEED], bump = platform_config.config_bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        has_one = vault @ BugBountyError::ReportVaultMismatch,
+        constraint = report.researcher == researcher.key() @ BugBountyError::UnauthorizedResearcher
+    )]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(
+        mut,
+        constraint = Some(claim_escrow.key()) == report.claim_escrow @ BugBountyError::InvalidClaimEscrow
+    )]
+    pub claim_escrow: Account<'info, TokenAccount>,
+    
+    /// CHECK: PDA signing for the report escrows, holds no data
+    #[account(seeds = [ESCROW_AUTHORITY_SEED, report.key().as_ref()], bump)]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = destination_token_account.owner == researcher.key() @ BugBountyError::UnauthorizedResearcher,
+        constraint = destination_token_account.key() != claim_escrow.key() @ BugBountyError::InvalidClaimEscrow
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Checked against the platform swap whitelist in the handler
+    #[account(executable)]
+    pub swap_program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseClaimEscrow<'info> {
+    /// Paid for the escrow when opening it, so receives its rent back
+    #[account(mut)]
+    pub researcher: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        has_one = vault @ BugBountyError::ReportVaultMismatch,
+        constraint = report.researcher == researcher.key() @ BugBountyError::UnauthorizedResearcher
+    )]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(
+        mut,
+        constraint = Some(claim_escrow.key()) == report.claim_escrow @ BugBountyError::InvalidClaimEscrow
+    )]
+    pub claim_escrow: Account<'info, TokenAccount>,
+    
+    /// CHECK: PDA signing for the report escrows, holds no data
+    #[account(seeds = [ESCROW_AUTHORITY_SEED, report.key().as_ref()], bump)]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(boost_id: u64)]
+pub struct CreateBountyBoost<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        init,
+        payer = sponsor,
+        space = 8 + std::mem::size_of::<BountyBoost>(),
+        seeds = [BOUNTY_BOOST_SEED, vault.key().as_ref(), sponsor.key().as_ref(), &boost_id.to_le_bytes()],
+        bump
+    )]
+    pub boost: Account<'info, BountyBoost>,
+    
+    /// Any mint; researchers receive the boost in their associated token account for it
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = sponsor,
+        seeds = [BOOST_ESCROW_SEED, boost.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = boost,
+    )]
+    pub boost_escrow: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub sponsor_token_account: Account<'info, TokenAccount>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RefundBountyBoost<'info> {
+    /// Paid for the boost and its escrow, so receives both rents back
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        close = sponsor,
+        has_one = vault @ BugBountyError::InvalidBoostAccounts,
+        has_one = sponsor @ BugBountyError::InvalidBoostAccounts,
+    )]
+    pub boost: Account<'info, BountyBoost>,
+    
+    #[account(mut, address = boost.escrow @ BugBountyError::InvalidBoostAccounts)]
+    pub boost_escrow: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub sponsor_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct StakeForPriority<'info> {
+    #[account(mut)]
+    pub researcher: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        constraint = report.vault == vault.key(),
+        constraint = report.researcher == researcher.key() @ BugBountyError::UnauthorizedResearcher
+    )]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(
+        constraint = vault.reward_token_mint.is_none_or(|m| m == mint.key()) @ BugBountyError::WrongRewardMint
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(mut, token::mint = mint, token::authority = researcher)]
+    pub researcher_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: PDA signing for the report escrows, holds no data
+    #[account(seeds = [ESCROW_AUTHORITY_SEED, report.key().as_ref()], bump)]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = researcher,
+        seeds = [STAKE_ESCROW_SEED, report.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = escrow_authority,
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SettlePriorityStake<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, constraint = report.vault == vault.key())]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    /// CHECK: Receives the stake escrow rent, must be the report researcher
+    #[account(mut, constraint = researcher.key() == report.researcher @ BugBountyError::UnauthorizedResearcher)]
+    pub researcher: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = Some(stake_escrow.key()) == report.stake_escrow @ BugBountyError::InvalidStakeEscrow
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    
+    /// CHECK: PDA signing for the report escrows, holds no data
+    #[account(seeds = [ESCROW_AUTHORITY_SEED, report.key().as_ref()], bump)]
+    pub escrow_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = researcher_token_account.owner == report.researcher @ BugBountyError::UnauthorizedResearcher
+    )]
+    pub researcher_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.key() == vault.vault_token_account @ BugBountyError::VaultTokenAccountMismatch
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct InitializePlatformConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + std::mem::size_of::<PlatformConfig>(),
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::BugBountyPlatform>,
+    
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ BugBountyError::NotPlatformAdmin
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatformConfig<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.config_bump,
+        has_one = admin @ BugBountyError::NotPlatformAdmin
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(researcher: Pubkey)]
+pub struct AttestExternalReputation<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.config_bump,
+        has_one = admin @ BugBountyError::NotPlatformAdmin
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + std::mem::size_of::<ExternalAttestation>(),
+        seeds = [EXTERNAL_ATTESTATION_SEED, researcher.as_ref()],
+        bump
+    )]
+    pub external_attestation: Account<'info, ExternalAttestation>,
+    
+    /// Gets a display copy of the source and score when supplied
+    #[account(
+        mut,
+        seeds = [RESEARCHER_PROFILE_SEED, researcher.as_ref()],
+        bump = researcher_profile.profile_bump
+    )]
+    pub researcher_profile: Option<Account<'info, ResearcherProfile>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeExternalAttestation<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.config_bump,
+        has_one = admin @ BugBountyError::NotPlatformAdmin
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        mut,
+        close = admin,
+        seeds = [EXTERNAL_ATTESTATION_SEED, external_attestation.researcher.as_ref()],
+        bump = external_attestation.attestation_bump
+    )]
+    pub external_attestation: Account<'info, ExternalAttestation>,
+    
+    /// Has its display copy cleared when supplied
+    #[account(
+        mut,
+        seeds = [RESEARCHER_PROFILE_SEED, external_attestation.researcher.as_ref()],
+        bump = researcher_profile.profile_bump
+    )]
+    pub researcher_profile: Option<Account<'info, ResearcherProfile>>,
+}
+
+#[derive(Accounts)]
+pub struct SetDonation<'info> {
+    pub researcher: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = report.researcher == researcher.key() @ BugBountyError::UnauthorizedResearcher
+    )]
+    pub report: Account<'info, VulnerabilityReport>,
+}
+
+#[derive(Accounts)]
+pub struct SetPayoutRecipient<'info> {
+    pub researcher: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = report.researcher == researcher.key() @ BugBountyError::UnauthorizedResearcher
+    )]
+    pub report: Account<'info, VulnerabilityReport>,
+}
+
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct RegisterFirmMember<'info> {
+    /// Payout recipient; a multisig vault signs through its program
+    pub recipient: Signer<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<FirmMember>(),
+        seeds = [FIRM_MEMBER_SEED, recipient.key().as_ref(), member.as_ref()],
+        bump
+    )]
+    pub firm_member: Account<'info, FirmMember>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveFirmMember<'info> {
+    pub recipient: Signer<'info>,
+    
+    /// CHECK: Refund destination, must be the account that paid the firm member's rent
+    #[account(mut, address = firm_member.rent_payer @ BugBountyError::RentPayerMismatch)]
+    pub rent_payer: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = recipient,
+        seeds = [FIRM_MEMBER_SEED, recipient.key().as_ref(), firm_member.member.as_ref()],
+        bump = firm_member.firm_member_bump
+    )]
+    pub firm_member: Account<'info, FirmMember>,
+}
+
+#[derive(Accounts)]
+pub struct MintReputationNFT<'info> {
+    /// Program team when the vault sets `team_mints_reputation`, otherwise the researcher
+    #[account(mut)]
+    pub minter: Signer<'info>,
+    
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(constraint = report.vault == vault.key() @ BugBountyError::ReportVaultMismatch)]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(
+        init,
+        payer = minter,
+        space = 8 + std::mem::size_of::<ReputationNFT>() + 256,
+        seeds = [REPUTATION_SEED, report.researcher.as_ref(), report.key().as_ref()],
+        bump
+    )]
+    pub reputation_nft: Account<'info, ReputationNFT>,
+    
+    // Compressed badge accounts, required when the vault sets `compressed_reputation`
+    #[account(seeds = [PLATFORM_CONFIG_SEED], bump = platform_config.config_bump)]
+    pub platform_config: Option<Account<'info, PlatformConfig>>,
+    
+    /// CHECK: Badge recipient, checked against the report's researcher
+    pub leaf_owner: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Bubblegum tree config PDA, derived from merkle_tree in the handler
+    #[account(mut)]
+    pub tree_config: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Platform merkle tree, checked against PlatformConfig
+    #[account(mut)]
+    pub merkle_tree: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Bubblegum program
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    pub bubblegum_program: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: SPL noop program used as the compression log wrapper
+    #[account(address = SPL_NOOP_PROGRAM_ID)]
+    pub log_wrapper: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: SPL account compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: Option<UncheckedAccount<'info>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeReputation<'info> {
+    pub governance_authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = governance_authority @ BugBountyError::NotGovernanceAuthority
+    )]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(constraint = report.vault == vault.key() @ BugBountyError::ReportVaultMismatch)]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(
+        mut,
+        seeds = [REPUTATION_SEED, report.researcher.as_ref(), report.key().as_ref()],
+        bump
+    )]
+    pub reputation_nft: Account<'info, ReputationNFT>,
+}
+
+#[derive(Accounts)]
+pub struct RestoreReputation<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.config_bump,
+        has_one = admin @ BugBountyError::NotPlatformAdmin
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, has_one = vault @ BugBountyError::ReputationVaultMismatch)]
+    pub reputation_nft: Account<'info, ReputationNFT>,
+}
+
+#[derive(Accounts)]
+pub struct FundVault<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// CHECK: Funder token account
+    #[account(mut)]
+    pub funder_token_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Vault token account
+    #[account(mut)]
+    pub vault_token_account: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct FundRentSubsidy<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SweepLamports<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// Destination when the vault pays in wSOL
+    #[account(
+        mut,
+        address = vault.vault_token_account @ BugBountyError::VaultTokenAccountMismatch
+    )]
+    pub vault_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Destination otherwise
+    #[account(mut, address = vault.program_team @ BugBountyError::UnauthorizedTeam)]
+    pub program_team: Option<SystemAccount<'info>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SyncVaultBalance<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        constraint = vault_token_account.key() == vault.vault_token_account @ BugBountyError::VaultTokenAccountMismatch
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+}
+
+#[derive(Accounts)]
+#[instruction(period_id: u32)]
+pub struct SnapshotVault<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        constraint = vault_token_account.key() == vault.vault_token_account @ BugBountyError::VaultTokenAccountMismatch
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<VaultSnapshot>(),
+        seeds = [SNAPSHOT_SEED, vault.key().as_ref(), &period_id.to_le_bytes()],
+        bump
+    )]
+    pub snapshot: Account<'info, VaultSnapshot>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseSnapshot<'info> {
+    #[account(mut)]
+    pub program_team: Signer<'info>,
+    
+    #[account(has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        close = program_team,
+        seeds = [SNAPSHOT_SEED, vault.key().as_ref(), &snapshot.period_id.to_le_bytes()],
+        bump = snapshot.snapshot_bump
+    )]
+    pub snapshot: Account<'info, VaultSnapshot>,
+}
+
+#[derive(Accounts)]
+pub struct AddHallOfFameEntry<'info> {
+    #[account(mut)]
+    pub program_team: Signer<'info>,
+    
+    #[account(mut, has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(constraint = report.vault == vault.key() @ BugBountyError::ReportVaultMismatch)]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(
+        init_if_needed,
+        payer = program_team,
+        space = 8 + std::mem::size_of::<HallOfFame>(),
+        seeds = [HALL_OF_FAME_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub hall_of_fame: Account<'info, HallOfFame>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PostAcknowledgment<'info> {
+    #[account(mut)]
+    pub program_team: Signer<'info>,
+    
+    #[account(mut, has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(constraint = report.vault == vault.key() @ BugBountyError::ReportVaultMismatch)]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(
+        init,
+        payer = program_team,
+        space = 8 + std::mem::size_of::<Acknowledgment>(),
+        seeds = [ACKNOWLEDGMENT_SEED, report.key().as_ref()],
+        bump
+    )]
+    pub acknowledgment: Account<'info, Acknowledgment>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct HideAcknowledgment<'info> {
+    pub researcher: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        has_one = vault,
+        has_one = researcher @ BugBountyError::UnauthorizedResearcher,
+        seeds = [ACKNOWLEDGMENT_SEED, acknowledgment.report.as_ref()],
+        bump = acknowledgment.acknowledgment_bump
+    )]
+    pub acknowledgment: Account<'info, Acknowledgment>,
+}
+
+#[derive(Accounts)]
+pub struct SyncReportSummary<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(has_one = vault @ BugBountyError::ReportVaultMismatch)]
+    pub report: Account<'info, VulnerabilityReport>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = REPORT_SUMMARY_SPACE,
+        seeds = [REPORT_SUMMARY_SEED, report.key().as_ref()],
+        bump
+    )]
+    pub report_summary: Account<'info, ReportSummary>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(feature = "test-clock")]
+#[derive(Accounts)]
+pub struct SetTestClock<'info> {
+    #[account(address = TEST_CLOCK_AUTHORITY @ BugBountyError::NotTestClockAuthority)]
+    pub test_authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+}
+
+#[derive(Accounts)]
+pub struct ReportGovernanceCompromise<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + std::mem::size_of::<CompromiseAlert>(),
+        seeds = [COMPROMISE_ALERT_SEED, vault.key().as_ref()],
+        bump
+    )]
+    pub compromise_alert: Account<'info, CompromiseAlert>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveCompromiseAlert<'info> {
+    #[account(mut)]
+    pub program_team: Signer<'info>,
+    
+    #[account(mut, has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        has_one = vault,
+        seeds = [COMPROMISE_ALERT_SEED, vault.key().as_ref()],
+        bump = compromise_alert.alert_bump,
+        close = reporter
+    )]
+    pub compromise_alert: Account<'info, CompromiseAlert>,
+    
+    /// CHECK: Refund destination, must be the alert's reporter
+    #[account(mut, address = compromise_alert.reporter @ BugBountyError::AlertReporterMismatch)]
+    pub reporter: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveHallOfFameEntry<'info> {
+    pub program_team: Signer<'info>,
+    
+    #[account(mut, has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        seeds = [HALL_OF_FAME_SEED, vault.key().as_ref()],
+        bump = hall_of_fame.hall_bump
+    )]
+    pub hall_of_fame: Account<'info, HallOfFame>,
+}
+
+#[derive(Accounts)]
+pub struct ToggleVaultStatus<'info> {
+    pub program_team: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// Role of a delegated signer, not needed when the legacy authority signs
+    pub role: Option<Account<'info, Role>>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+pub struct BeginWindDown<'info> {
+    pub program_team: Signer<'info>,
+    
+    pub governance_authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = program_team @ BugBountyError::UnauthorizedTeam,
+        has_one = governance_authority @ BugBountyError::NotGovernanceAuthority
+    )]
+    pub vault: Account<'info, BugBountyVault>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeWindDown<'info> {
+    pub program_team: Signer<'info>,
+    
+    #[account(mut, has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut, address = vault.vault_token_account @ BugBountyError::VaultTokenAccountMismatch)]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = program_team_token_account.owner == program_team.key() @ BugBountyError::TokenAccountOwnerMismatch,
+        constraint = program_team_token_account.mint == vault_token_account.mint @ BugBountyError::WrongRewardMint
+    )]
+    pub program_team_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRewardTiers<'info> {
+    pub program_team: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// Role of a delegated signer, not needed when the legacy authority signs
+    pub role: Option<Account<'info, Role>>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+pub struct PublishRewardTiers<'info> {
+    pub program_team: Signer<'info>,
+    
+    /// Pays the policy version's rent; may be the program team itself
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<PolicyVersion>(),
+        seeds = [POLICY_VERSION_SEED, vault.key().as_ref(), &(vault.policy_version + 1).to_le_bytes()],
+        bump
+    )]
+    pub policy_version: Account<'info, PolicyVersion>,
+    
+    /// Role of a delegated signer, not needed when the legacy authority signs
+    pub role: Option<Account<'info, Role>>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Settings that relax vault safeguards, reserved for the program team itself rather than delegated roles
+#[derive(Accounts)]
+pub struct AddRewardMint<'info> {
+    pub program_team: Signer<'info>,
+    
+    #[account(mut, has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        constraint = vault_token_account.mint == mint.key() @ BugBountyError::WrongRewardMint,
+        constraint = vault_token_account.owner == vault.key() @ BugBountyError::TokenAccountOwnerMismatch
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+pub struct ChangeRewardMint<'info> {
+    pub program_team: Signer<'info>,
+    
+    #[account(mut, has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        constraint = old_vault_token_account.key() == vault.vault_token_account @ BugBountyError::VaultTokenAccountMismatch
+    )]
+    pub old_vault_token_account: Account<'info, TokenAccount>,
+    
+    pub new_mint: Account<'info, Mint>,
+    
+    #[account(
+        constraint = new_vault_token_account.mint == new_mint.key() @ BugBountyError::WrongRewardMint,
+        constraint = new_vault_token_account.owner == vault.key() @ BugBountyError::TokenAccountOwnerMismatch
+    )]
+    pub new_vault_token_account: Account<'info, TokenAccount>,
+    
+    /// Receives unrecorded tokens left in the old account; required only when there are some
+    #[account(
+        mut,
+        constraint = program_team_token_account.owner == program_team.key() @ BugBountyError::TokenAccountOwnerMismatch
+    )]
+    pub program_team_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveRewardMint<'info> {
+    pub program_team: Signer<'info>,
+    
+    #[account(mut, has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut)]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = program_team_token_account.owner == program_team.key() @ BugBountyError::TokenAccountOwnerMismatch
+    )]
+    pub program_team_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTeamSetting<'info> {
+    pub program_team: Signer<'info>,
+    
+    #[account(mut, has_one = program_team @ BugBountyError::UnauthorizedTeam)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+pub struct ManageWithdrawal<'info> {
+    pub program_team: Signer<'info>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// Role of a delegated signer, not needed when the legacy authority signs
+    pub role: Option<Account<'info, Role>>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteWithdrawal<'info> {
+    pub program_team: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.pda_seed()],
+        bump = vault.vault_bump
+    )]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.key() == vault.vault_token_account @ BugBountyError::VaultTokenAccountMismatch
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    /// Withdrawals always land with the team, even when a treasurer executes them
+    #[account(
+        mut,
+        constraint = program_team_token_account.owner == vault.program_team @ BugBountyError::UnauthorizedTeam
+    )]
+    pub program_team_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    /// Role of a delegated signer, not needed when the legacy authority signs
+    pub role: Option<Account<'info, Role>>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseInsurance<'info> {
+    pub program_team: Signer<'info>,
+    
+    pub governance_authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = program_team @ BugBountyError::UnauthorizedTeam,
+        has_one = governance_authority @ BugBountyError::NotGovernanceAuthority
+    )]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateGovernanceAuthority<'info> {
+    pub governance_authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = governance_authority @ BugBountyError::NotGovernanceAuthority
+    )]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    #[account(mut)]
+    pub audit_log: Option<AccountLoader<'info, AuditLog>>,
+}
+
+#[derive(Accounts)]
+#[instruction(force_delete: bool)]
+pub struct DeleteVault<'info> {
+    #[account(mut)]
+    pub program_team: Signer<'info>,
+    
+    #[account(
+        mut,
+        close = program_team,
+        has_one = program_team @ BugBountyError::UnauthorizedTeam,
+        seeds = [VAULT_SEED, vault.pda_seed()],
+        bump = vault.vault_bump
+    )]
+    pub vault: Account<'info, BugBountyVault>,
+    
+    /// CHECK: Vault token account - tokens will be transferred out
+    #[account(mut)]
+    pub vault_token_account: UncheckedAccount<'info>,
+    
+    /// CHECK: Program team's token account to receive remaining funds
+    #[account(mut)]
+    pub program_team_token_account: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// ERROR HANDLING
+// ============================================================================
+
+#[error_code]
+pub enum BugBountyError {
+    #[msg("Vault is inactive")]
+    VaultInactive,
+    
+    #[msg("Not authorized as governance authority")]
+    NotGovernanceAuthority,
+    
+    #[msg("Invalid report status for this operation")]
+    InvalidReportStatus,
+    
+    #[msg("Report must be approved before payout")]
+    ReportNotApproved,
+    
+    #[msg("Report must be paid before NFT minting")]
+    ReportNotPaid,
+    
+    #[msg("Unauthorized researcher")]
+    UnauthorizedResearcher,
+    
+    #[msg("Only program team can perform this action")]
+    UnauthorizedTeam,
+    
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+    
+    #[msg("Arithmetic underflow")]
+    ArithmeticUnderflow,
+    
+    #[msg("Approvals are paused while the vault is inactive")]
+    ApprovalsPaused,
+    
+    #[msg("Claim window must not be negative")]
+    InvalidClaimWindow,
+    
+    #[msg("Approvals never expire in this vault")]
+    ClaimWindowDisabled,
+    
+    #[msg("Claim window has not elapsed yet")]
+    ClaimWindowOpen,
+    
+    #[msg("Vault has not opted into platform arbitration")]
+    ArbitrationNotEnabled,
+    
+    #[msg("Report has already been escalated")]
+    AlreadyEscalated,
+    
+    #[msg("Snapshot is still inside its retention window")]
+    SnapshotRetentionActive,
+    
+    #[msg("Project name must be between 1 and 64 bytes")]
+    InvalidProjectName,
+    
+    #[msg("Vault has no project name set for reputation NFTs")]
+    ProjectNameNotSet,
+    
+    #[msg("Reason template index is out of range or unset")]
+    InvalidReasonTemplate,
+    
+    #[msg("Reason text is too long")]
+    ReasonTooLong,
+    
+    #[msg("Account is not a legacy-layout report of this vault")]
+    NotLegacyReport,
+    
+    #[msg("Rejections must carry a reason hash or a reason template")]
+    RejectionReasonRequired,
+    
+    #[msg("Only withdrawn, rejected or expired non-spam reports can be closed")]
+    ReportNotClosable,
+    
+    #[msg("Settle the report's priority stake before closing it")]
+    PriorityStakeUnsettled,
+    
+    #[msg("Rent refund must go to the account that paid it")]
+    RentPayerMismatch,
+    
+    #[msg("Signer is not allowed to mint this reputation NFT")]
+    UnauthorizedMinter,
+    
+    #[msg("Report does not belong to this vault")]
+    ReportVaultMismatch,
+    
+    #[msg("Reputation NFT is already revoked")]
+    ReputationAlreadyRevoked,
+    
+    #[msg("Reputation NFT is not revoked")]
+    ReputationNotRevoked,
+    
+    #[msg("Compressed badges need the platform config, tree and Bubblegum accounts")]
+    MissingCompressionAccounts,
+    
+    #[msg("Platform has no reputation tree configured")]
+    ReputationTreeNotSet,
+    
+    #[msg("Merkle tree or tree config is not the platform reputation tree")]
+    ReputationTreeMismatch,
+    
+    #[msg("Hall of fame is full")]
+    HallOfFameFull,
+    
+    #[msg("Report is already in the hall of fame")]
+    AlreadyInHallOfFame,
+    
+    #[msg("Report is not in the hall of fame")]
+    NotInHallOfFame,
+    
+    #[msg("Approver cannot approve their own report")]
+    SelfApprovalForbidden,
+    
+    #[msg("Vault registry page is full; open the next page")]
+    RegistryPageFull,
+    
+    #[msg("Vault registry page still has room")]
+    RegistryPageNotFull,
+    
+    #[msg("The last vault registry page must be supplied")]
+    PreviousRegistryPageRequired,
+    
+    #[msg("Vault is not in this registry page")]
+    VaultNotInRegistry,
+    
+    #[msg("Handles are 1-32 characters of a-z, 0-9 and underscore")]
+    InvalidHandle,
+    
+    #[msg("Researcher already holds a handle")]
+    HandleAlreadySet,
+    
+    #[msg("Treasury does not match the platform config")]
+    TreasuryMismatch,
+    
+    #[msg("Payout at or above the dual approval threshold needs program team co-approval")]
+    CoApprovalRequired,
+    
+    #[msg("Payout is below the dual approval threshold")]
+    CoApprovalNotRequired,
+    
+    #[msg("Report is already co-approved")]
+    AlreadyCoApproved,
+    
+    #[msg("Governance recovery delay must be 0 (disabled) or at least one day")]
+    InvalidRecoveryDelay,
+    
+    #[msg("Decay window must end after it starts and both multipliers must be non-zero")]
+    InvalidDecayConfig,
+    
+    #[msg("An invoice must be attached before this report can be paid")]
+    InvoiceRequired,
+    
+    #[msg("Linked report must belong to the same researcher in a different vault")]
+    InvalidLinkedReport,
+    
+    #[msg("Researcher must wait out the cooldown after a rejection before submitting again")]
+    SubmissionCooldownActive,
+    
+    #[msg("Rejection cooldown cannot be negative")]
+    InvalidRejectionCooldown,
+    
+    #[msg("Proof-of-concept transaction signature cannot be empty")]
+    InvalidPocReference,
+    
+    #[msg("Target program's upgrade authority is not the vault's program team")]
+    ProgramAuthorityMismatch,
+    
+    #[msg("Vault has no program verification to challenge")]
+    VaultNotProgramVerified,
+    
+    #[msg("Program team still holds the upgrade authority")]
+    VerificationStillValid,
+    
+    #[msg("Reward tiers must be whole multiples of the vault's granularity")]
+    GranularityViolation,
+    
+    #[msg("Governance recovery is disabled for this vault")]
+    GovernanceRecoveryDisabled,
+    
+    #[msg("A governance recovery is already pending")]
+    GovernanceRecoveryPending,
+    
+    #[msg("No governance recovery is pending")]
+    NoGovernanceRecoveryPending,
+    
+    #[msg("Governance recovery timelock has not elapsed")]
+    GovernanceRecoveryTimelockActive,
+    
+    #[msg("Funding would push the vault balance over its cap")]
+    FundingCapExceeded,
+    
+    #[msg("Funding cap cannot be below the committed amount")]
+    FundingCapBelowCommitted,
+    
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+    
+    #[msg("Vault must be inactive before deletion")]
+    VaultMustBeInactive,
+    
+    #[msg("Cannot delete vault with pending reports. Use force_delete to override")]
+    HasPendingReports,
+    
+    #[msg("Report is on hold pending review")]
+    ReportOnHold,
+    
+    #[msg("Report is not on hold")]
+    ReportNotOnHold,
+    
+    #[msg("Report has already been acknowledged")]
+    AlreadyAcknowledged,
+    
+    #[msg("Token account does not match the vault token account")]
+    VaultTokenAccountMismatch,
+    
+    #[msg("Vault token account must be owned by the vault PDA and hold the reward mint")]
+    InvalidVaultTokenAccount,
+    
+    #[msg("Vault authority does not match the authority stored on the vault")]
+    VaultAuthorityMismatch,
+    
+    #[msg("Donation basis points must not exceed 10,000")]
+    InvalidDonationBps,
+    
+    #[msg("Donation token account is required for this payout")]
+    DonationAccountRequired,
+    
+    #[msg("Donation token account does not belong to the donation recipient")]
+    DonationRecipientMismatch,
+    
+    #[msg("Token account mint does not match the vault reward mint")]
+    WrongRewardMint,
+    
+    #[msg("Claim escrow does not match the report")]
+    InvalidClaimEscrow,
+    
+    #[msg("Claim escrow is empty")]
+    EscrowEmpty,
+    
+    #[msg("Escrow still holds tokens and cannot be closed")]
+    EscrowNotEmpty,
+    
+    #[msg("Swap program is not whitelisted by the platform")]
+    SwapProgramNotWhitelisted,
+    
+    #[msg("Swap output is below the requested minimum")]
+    SlippageExceeded,
+    
+    #[msg("Too many swap programs for the platform whitelist")]
+    TooManySwapPrograms,
+    
+    #[msg("Only the platform admin can perform this action")]
+    NotPlatformAdmin,
+    
+    #[msg("Priority staking is disabled for this vault")]
+    PriorityStakeDisabled,
+    
+    #[msg("Report already has a priority stake")]
+    AlreadyStaked,
+    
+    #[msg("Priority stake can only be settled once the report is triaged")]
+    StakeNotResolvable,
+    
+    #[msg("Stake escrow does not match the report")]
+    InvalidStakeEscrow,
+    
+    #[msg("Researcher has too many open reports in this vault")]
+    TooManyOpenReports,
+    
+    #[msg("Submission counter account is required")]
+    SubmissionCounterRequired,
+    
+    #[msg("Submission counter does not match the report")]
+    SubmissionCounterMismatch,
+    
+    #[msg("Missing or invalid researcher signature for sponsored submission")]
+    InvalidSponsorSignature,
+    
+    #[msg("Governance authority must differ from the program team")]
+    AuthoritiesMustDiffer,
+    
+    #[msg("Vault does not have enough uncommitted funds")]
+    InsufficientVaultFunds,
+    
+    #[msg("A withdrawal is already pending")]
+    WithdrawalAlreadyPending,
+    
+    #[msg("No withdrawal is pending")]
+    NoPendingWithdrawal,
+    
+    #[msg("Withdrawal timelock has not elapsed")]
+    WithdrawalTimelockActive,
+    
+    #[msg("Withdrawal delay must not be negative")]
+    InvalidWithdrawalDelay,
+    
+    #[msg("Insurance basis points must not exceed 10,000")]
+    InvalidInsuranceBps,
+    
+    #[msg("Insurance reserve is smaller than the requested release")]
+    InsufficientInsuranceReserve,
+    
+    #[msg("Vault is not accepting submissions")]
+    SubmissionsClosed,
+    
+    #[msg("Spam window must not be negative")]
+    InvalidSpamWindow,
+    
+    #[msg("Report is not confidential")]
+    ReportNotConfidential,
+    
+    #[msg("Disclosure embargo is still active")]
+    EmbargoActive,
+    
+    #[msg("Report has already been disclosed")]
+    ReportAlreadyDisclosed,
+    
+    #[msg("Report is already marked as remediated")]
+    ReportAlreadyRemediated,
+    
+    #[msg("Embargo must not be negative")]
+    InvalidEmbargo,
+    
+    #[msg("Reputation NFT does not belong to this report")]
+    ReputationReportMismatch,
+    
+    #[msg("Reputation NFT was not issued by this vault")]
+    ReputationVaultMismatch,
+    
+    #[msg("Audit log does not belong to this vault")]
+    AuditLogMismatch,
+    
+    #[msg("Role does not belong to this signer and vault")]
+    RoleMismatch,
+    
+    #[msg("Role lacks the permission for this action")]
+    MissingPermission,
+    
+    #[msg("Bounty boost needs a nonzero severity mask of known tiers")]
+    InvalidBoostConfig,
+    
+    #[msg("Boost accounts must be distinct (boost, escrow, destination) triples for this vault")]
+    InvalidBoostAccounts,
+    
+    #[msg("Bounty boost does not cover this report's severity or has expired")]
+    BoostNotApplicable,
+    
+    #[msg("Bounty boost has not expired yet")]
+    BoostNotExpired,
+    
+    #[msg("Triage queue is full; try again once pending reports are triaged")]
+    TriageQueueFull,
+    
+    #[msg("Report's severity has already been disputed")]
+    SeverityAlreadyDisputed,
+    
+    #[msg("A severity dispute needs a different tier and an evidence hash")]
+    InvalidSeverityDispute,
+    
+    #[msg("Payout is frozen while the report's severity is disputed")]
+    SeverityDisputeOpen,
+    
+    #[msg("Vault's rent subsidy can't cover this report; submit with submit_report instead")]
+    RentSubsidyExhausted,
+    
+    #[msg("This feature is not enabled for the vault")]
+    FeatureDisabled,
+    
+    #[msg("Feature flags must be a nonzero mask of known FEATURE_* bits")]
+    InvalidFeatureFlags,
+    
+    #[msg("Feature can't be toggled while reports depend on it")]
+    FeatureInUse,
+    
+    #[msg("Policy version account doesn't hold the tiers this report was judged under")]
+    PolicyVersionMismatch,
+    
+    #[msg("Auto-approved reports can't be paid out until the revocation window has passed")]
+    AutoApprovalTimelockActive,
+    
+    #[msg("Report was not auto-approved")]
+    NotAutoApproved,
+    
+    #[msg("Byte is not a discriminant of this enum")]
+    UnknownDiscriminant,
+    
+    #[msg("Payout destination is the vault's own token account or the claim escrow")]
+    SelfTransferDestination,
+    
+    #[msg("Payouts are halted by the circuit breaker until the program team resets it")]
+    CircuitBreakerTripped,
+    
+    #[msg("A vault accounting invariant does not hold")]
+    AccountingInvariantViolated,
+    
+    #[msg("Researcher reputation is below the vault's minimum for Critical reports")]
+    InsufficientReputation,
+    
+    #[msg("External attestation must expire in the future")]
+    AttestationExpired,
+    
+    #[msg("Governance bond is in its cooldown or the vault has open arbitrations")]
+    GovernanceBondLocked,
+    
+    #[msg("Report was not approved by the bonded governance authority")]
+    NotBondedApproval,
+    
+    #[msg("Vault is not verified for the target program")]
+    VaultNotVerifiedForProgram,
+    
+    #[msg("Submissions of this severity tier are paused")]
+    SeverityTierPaused,
+    
+    #[msg("Payout exceeds the share of the vault balance a single payout may take")]
+    PayoutFractionExceeded,
+    
+    #[msg("Payout fraction must be between 1 and 10,000 basis points")]
+    InvalidPayoutFractionBps,
+    
+    #[msg("A governance compromise alert is open; only payouts up to the Low reward, in Low's mint, are allowed")]
+    CompromiseAlertOpen,
+    
+    #[msg("Account is not the compromise alert's reporter")]
+    AlertReporterMismatch,
+    
+    #[msg("Evidence hash must be nonzero")]
+    EvidenceRequired,
+    
+    #[msg("This vault maintains report summaries; pass one with the submission")]
+    ReportSummaryRequired,
+    
+    #[msg("This vault does not maintain report summaries")]
+    ReportSummariesDisabled,
+    
+    #[msg("Only the test clock authority can set a vault's clock")]
+    NotTestClockAuthority,
+    
+    #[msg("Final severity tier is paid in another mint than the disputed report")]
+    DisputeTierMintMismatch,
+    
+    #[msg("The governance bond was already slashed over this report")]
+    BondAlreadySlashed,
+    
+    #[msg("Account is not a first-deployment vault")]
+    NotLegacyVault,
+    
+    #[msg("The approving delegate's role must be passed to refund its spend cap")]
+    ApproverRoleRequired,
+    
+    #[msg("Timestamp is in the past, too far in the future, or overflows")]
+    InvalidTimestamp,
+    
+    #[msg("Vault is winding down and no longer accepts submissions")]
+    VaultWindingDown,
+    
+    #[msg("Vault is not in the wind-down phase this operation requires")]
+    InvalidWindDownPhase,
+    
+    #[msg("Wind-down triage deadline has passed; no further approvals")]
+    TriageDeadlinePassed,
+    
+    #[msg("Wind-down triage window is still open")]
+    TriageWindowOpen,
+    
+    #[msg("Reports are still pending, approved or in arbitration")]
+    UnresolvedReportsRemain,
+    
+    #[msg("No milestone is defined with this id")]
+    UnknownMilestone,
+    
+    #[msg("Researcher profile has not reached this milestone")]
+    MilestoneNotReached,
+    
+    #[msg("Approval would exceed the delegate's spend cap")]
+    DelegationCapExceeded,
+    
+    #[msg("Unknown or empty permission bits")]
+    InvalidPermissions,
+    
+    #[msg("Account is not an SPL token account")]
+    InvalidTokenAccount,
+    
+    #[msg("Token account is not owned by the expected wallet")]
+    TokenAccountOwnerMismatch,
+    
+    #[msg("Mint is not one of the vault's reward mints")]
+    UnknownRewardMint,
+    
+    #[msg("Mint is already a reward mint of this vault")]
+    RewardMintAlreadyAdded,
+    
+    #[msg("Vault already has the maximum number of extra reward mints")]
+    RewardMintSlotsFull,
+    
+    #[msg("Reward mint still pays a tier or holds reserved payouts")]
+    RewardMintInUse,
+    
+    #[msg("Approved reports are still awaiting payout in the current reward mint")]
+    ApprovedPayoutsOutstanding,
+    
+    #[msg("Withdraw the vault's balance in the current reward mint first")]
+    RewardMintNotDrained,
+    
+    #[msg("Vault has changed its reward mint too many times")]
+    PreviousMintsFull,
+    
+    #[msg("Vault token balance is too low for this payout")]
+    InsufficientVaultBalance,
+    
+    #[msg("Payout destination must be the researcher's associated token account")]
+    DestinationNotAssociatedTokenAccount,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use solana_sysvar::program_stubs;
+
+    /// Vault with every field zeroed, as a freshly allocated account would be
+    fn zeroed_vault() -> BugBountyVault {
+        AnchorDeserialize::deserialize(&mut &vec![0u8; 8192][..]).unwrap()
+    }
+
+    fn counter_with(open_reports: u16, last_rejected_at: i64) -> SubmissionCounter {
+        SubmissionCounter {
+            vault: Pubkey::default(),
+            researcher: Pubkey::default(),
+            open_reports,
+            counter_bump: 0,
+            nonce: 0,
+            last_rejected_at,
+        }
+    }
+
+    fn zeroed_report() -> VulnerabilityReport {
+        AnchorDeserialize::deserialize(&mut &vec![0u8; 8192][..]).unwrap()
+    }
+
+    /// xorshift64 draws below a bound, so every run of a randomized test replays the same sequence
+    fn xorshift64(mut seed: u64) -> impl FnMut(u64) -> u64 {
+        move |bound| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed % bound
+        }
+    }
+
+    fn spam_guarded_vault(threshold: u32, window: i64) -> BugBountyVault {
+        let mut vault = zeroed_vault();
+        vault.spam_threshold = threshold;
+        vault.spam_window_seconds = window;
+        vault
+    }
+
+    #[test]
+    fn spam_window_trips_after_threshold_is_exceeded() {
+        let mut vault = spam_guarded_vault(2, 100);
+        assert!(!vault.record_spam_rejection(1_000).unwrap());
+        assert!(!vault.record_spam_rejection(1_050).unwrap());
+        assert!(vault.record_spam_rejection(1_099).unwrap());
+        assert_eq!(vault.recent_spam_count, 3);
+        assert_eq!(vault.spam_window_start, 1_000);
+    }
+
+    #[test]
+    fn spam_window_resets_at_the_boundary() {
+        let mut vault = spam_guarded_vault(2, 100);
+        vault.record_spam_rejection(1_000).unwrap();
+        vault.record_spam_rejection(1_099).unwrap();
+        // Exactly window_seconds after the start belongs to a new window
+        assert!(!vault.record_spam_rejection(1_100).unwrap());
+        assert_eq!(vault.recent_spam_count, 1);
+        assert_eq!(vault.spam_window_start, 1_100);
+    }
+
+    #[test]
+    fn zero_threshold_never_trips() {
+        let mut vault = spam_guarded_vault(0, 100);
+        for t in 0..10 {
+            assert!(!vault.record_spam_rejection(t).unwrap());
+        }
+    }
+
+    #[test]
+    fn audit_log_wraps_and_keeps_counting() {
+        let mut log: AuditLog = bytemuck::Zeroable::zeroed();
+        let actor = Pubkey::new_unique();
+        for i in 0..(AUDIT_LOG_CAPACITY as u64 + 3) {
+            log.append(AUDIT_UPDATE_REWARD_TIERS, actor, i, i as i64).unwrap();
+        }
+        assert_eq!(log.head, AUDIT_LOG_CAPACITY as u64 + 3);
+        // The three newest entries overwrote the oldest slots
+        assert_eq!(log.entries[0].data, AUDIT_LOG_CAPACITY as u64);
+        assert_eq!(log.entries[2].data, AUDIT_LOG_CAPACITY as u64 + 2);
+        assert_eq!(log.entries[3].data, 3);
+        assert_eq!(log.entries[0].action, AUDIT_UPDATE_REWARD_TIERS);
+    }
+
+    #[test]
+    fn spam_counter_overflow_is_an_error() {
+        let mut vault = spam_guarded_vault(0, i64::MAX);
+        vault.recent_spam_count = u32::MAX;
+        vault.spam_window_start = 0;
+        assert_eq!(
+            vault.record_spam_rejection(1).unwrap_err(),
+            error!(BugBountyError::ArithmeticOverflow)
+        );
+    }
+
+    #[test]
+    fn audit_log_head_overflow_is_an_error() {
+        let mut log: AuditLog = bytemuck::Zeroable::zeroed();
+        log.head = u64::MAX;
+        assert_eq!(
+            log.append(AUDIT_UPDATE_REWARD_TIERS, Pubkey::default(), 0, 0).unwrap_err(),
+            error!(BugBountyError::ArithmeticOverflow)
+        );
+    }
+
+    #[test]
+    fn overdrawn_balances_underflow() {
+        let mut vault = zeroed_vault();
+        vault.total_funded = u64::MAX - 1;
+        vault.total_paid_out = u64::MAX;
+        assert_eq!(vault.recorded_balance().unwrap_err(), error!(BugBountyError::ArithmeticUnderflow));
+
+        vault.total_paid_out = 0;
+        vault.committed_amount = u64::MAX;
+        assert_eq!(vault.available_balance().unwrap_err(), error!(BugBountyError::ArithmeticUnderflow));
+    }
+
+    #[test]
+    fn pda_helpers_match_account_seeds() {
+        let team = Pubkey::new_unique();
+        let researcher = Pubkey::new_unique();
+        let (vault, vault_bump) = find_vault_address(&team);
+        assert_eq!(
+            Pubkey::create_program_address(&[b"vault", team.as_ref(), &[vault_bump]], &ID).unwrap(),
+            vault
+        );
+
+        let (report, report_bump) = find_report_address(&vault, &researcher, 7);
+        assert_eq!(
+            Pubkey::create_program_address(
+                &[b"report", vault.as_ref(), researcher.as_ref(), &7u64.to_le_bytes(), &[report_bump]],
+                &ID
+            )
+            .unwrap(),
+            report
+        );
+
+        let (reputation, reputation_bump) = find_reputation_address(&researcher, &report);
+        assert_eq!(
+            Pubkey::create_program_address(
+                &[b"reputation", researcher.as_ref(), report.as_ref(), &[reputation_bump]],
+                &ID
+            )
+            .unwrap(),
+            reputation
+        );
+    }
+
+    #[test]
+    fn vault_signer_seeds_derive_the_vault_authority() {
+        let mut vault = zeroed_vault();
+        vault.program_team = Pubkey::new_unique();
+        let (address, bump) = find_vault_address(&vault.program_team);
+        vault.vault_bump = bump;
+        vault.vault_authority = address;
+
+        assert_eq!(
+            Pubkey::create_program_address(&vault.signer_seeds(), &ID).unwrap(),
+            vault.vault_authority
+        );
+
+        // The platform vault signs with the reserved seed, whoever its program team is
+        let (platform_address, platform_bump) = find_platform_vault_address();
+        vault.platform_vault = true;
+        vault.vault_bump = platform_bump;
+        assert_eq!(
+            Pubkey::create_program_address(&vault.signer_seeds(), &ID).unwrap(),
+            platform_address
+        );
+    }
+
+    #[test]
+    fn seed_constants_are_stable() {
+        assert_eq!(VAULT_SEED, b"vault");
+        assert_eq!(REPORT_SEED, b"report");
+        assert_eq!(REPUTATION_SEED, b"reputation");
+        assert_eq!(PLATFORM_CONFIG_SEED, b"platform_config");
+        assert_eq!(SUBMISSION_COUNTER_SEED, b"submission_counter");
+        assert_eq!(CLAIM_ESCROW_SEED, b"claim_escrow");
+        assert_eq!(STAKE_ESCROW_SEED, b"stake_escrow");
+        assert_eq!(ESCROW_AUTHORITY_SEED, b"escrow_authority");
+        assert_eq!(AUDIT_LOG_SEED, b"audit_log");
+        assert_eq!(ROLE_SEED, b"role");
+        assert_eq!(SNAPSHOT_SEED, b"snapshot");
+        assert_eq!(HALL_OF_FAME_SEED, b"hall_of_fame");
+        assert_eq!(VAULT_REGISTRY_SEED, b"vault_registry");
+        assert_eq!(HANDLE_SEED, b"handle");
+        assert_eq!(RESEARCHER_PROFILE_SEED, b"researcher_profile");
+        assert_eq!(MILESTONE_SEED, b"milestone");
+        assert_eq!(BOUNTY_BOOST_SEED, b"bounty_boost");
+        assert_eq!(BOOST_ESCROW_SEED, b"boost_escrow");
+        assert_eq!(SUBSCRIPTION_SEED, b"subscription");
+        assert_eq!(POLICY_VERSION_SEED, b"policy_version");
+        assert_eq!(TRUSTED_RESEARCHER_SEED, b"trusted_researcher");
+        assert_eq!(FIRM_MEMBER_SEED, b"firm_member");
+        assert_eq!(PLATFORM_VAULT_SEED, b"platform");
+        assert_eq!(EXTERNAL_ATTESTATION_SEED, b"external_attestation");
+        assert_eq!(GOVERNANCE_BOND_SEED, b"governance_bond");
+        assert_eq!(GOVERNANCE_BOND_ESCROW_SEED, b"governance_bond_escrow");
+        assert_eq!(ACKNOWLEDGMENT_SEED, b"acknowledgment");
+        assert_eq!(COMPROMISE_ALERT_SEED, b"compromise_alert");
+        assert_eq!(REPORT_SUMMARY_SEED, b"report_summary");
+    }
+
+    #[test]
+    fn compressed_badge_metadata_fits_bubblegum_limits() {
+        let vault = Pubkey::new_unique();
+        let report = Pubkey::new_unique();
+        let metadata = compressed_badge_metadata(&"é".repeat(20), vault, report, SeverityTier::High);
+
+        assert_eq!(metadata.name, "é".repeat(16));
+        assert!(metadata.uri.len() <= 200);
+        assert!(metadata.uri.contains(&report.to_string()));
+        assert!(metadata.uri.ends_with("severity=High"));
+
+        // Leading bytes are the length-prefixed name, as Bubblegum expects
+        let bytes = metadata.try_to_vec().unwrap();
+        assert_eq!(&bytes[..4], &32u32.to_le_bytes());
+    }
+
+    #[test]
+    fn hall_of_fame_dedupes_and_keeps_order_on_removal() {
+        let mut hall = HallOfFame {
+            vault: Pubkey::new_unique(),
+            hall_bump: 255,
+            entry_count: 0,
+            entries: [HallOfFameEntry::default(); HALL_OF_FAME_CAPACITY],
+        };
+        let entries: Vec<HallOfFameEntry> = (0..3)
+            .map(|i| HallOfFameEntry {
+                researcher: Pubkey::new_unique(),
+                report: Pubkey::new_unique(),
+                note_hash: [i; 32],
+            })
+            .collect();
+        for entry in &entries {
+            hall.add(*entry).unwrap();
+        }
+
+        assert!(hall.add(entries[1]).is_err());
+
+        assert_eq!(hall.remove(&entries[0].report).unwrap(), entries[0]);
+        assert_eq!(hall.active_entries(), &entries[1..]);
+        assert!(hall.remove(&entries[0].report).is_err());
+
+        for _ in hall.entry_count as usize..HALL_OF_FAME_CAPACITY {
+            hall.add(HallOfFameEntry { report: Pubkey::new_unique(), ..Default::default() }).unwrap();
+        }
+        assert!(hall.add(entries[0]).is_err());
+    }
+
+    #[test]
+    fn registry_page_fills_in_order_and_finds_entries() {
+        let mut page: VaultRegistryPage = bytemuck::Zeroable::zeroed();
+        let vaults: Vec<Pubkey> = (0..REGISTRY_PAGE_CAPACITY).map(|_| Pubkey::new_unique()).collect();
+        for vault in &vaults {
+            page.append(*vault).unwrap();
+        }
+
+        assert!(page.is_full());
+        assert!(page.append(Pubkey::new_unique()).is_err());
+
+        let entry = page.entry_mut(&vaults[7]).unwrap();
+        assert_eq!(entry.listed, 1);
+        entry.verified = 1;
+        assert_eq!(page.entries[7].verified, 1);
+        assert!(page.entry_mut(&Pubkey::new_unique()).is_err());
+    }
+
+    #[test]
+    fn handles_allow_only_lowercase_digits_and_underscore() {
+        assert!(validate_handle("white_hat_42").is_ok());
+        assert!(validate_handle(&"a".repeat(MAX_HANDLE_LEN)).is_ok());
+
+        for invalid in ["", "WhiteHat", "white-hat", "white hat", "hаcker", &"a".repeat(MAX_HANDLE_LEN + 1)] {
+            assert!(validate_handle(invalid).is_err(), "{invalid:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn ed25519_instruction_must_carry_its_own_signature_data() {
+        let signer = Pubkey::new_unique();
+        let message = sponsored_report_message(&Pubkey::new_unique(), &[7; 32], SeverityTier::High, false, 3);
+
+        // Layout produced by Ed25519Program.createInstructionWithPublicKey: header, offsets, key, signature, message
+        let build = |instruction_index: u16| {
+            let mut data = vec![1, 0];
+            for field in [48, instruction_index, 16, instruction_index, 112, message.len() as u16, instruction_index] {
+                data.extend_from_slice(&field.to_le_bytes());
+            }
+            data.extend_from_slice(signer.as_ref());
+            data.extend_from_slice(&[0; 64]);
+            data.extend_from_slice(&message);
+            data
+        };
+
+        let data = build(u16::MAX);
+        assert_eq!(parse_ed25519_instruction(&data), Some((signer, message.as_slice())));
+        assert_eq!(parse_ed25519_instruction(&build(0)), None);
+        assert_eq!(parse_ed25519_instruction(&data[..100]), None);
+    }
+
+    /// Pre-version-2 report data (no discriminator or account padding) with a payout of 500
+    fn legacy_report_data(head: &ReportLayoutHead, reason: Option<&str>) -> Vec<u8> {
+        let mut data = head.try_to_vec().unwrap();
+        reason.map(str::to_string).serialize(&mut data).unwrap();
+
+        // reason_template .. remediated_at, all unset apart from the payout amount
+        data.push(0);
+        data.extend_from_slice(&500u64.to_le_bytes());
+        data.extend_from_slice(&[0; 1 + 32 + 1 + 8 + 2 + 1 + 1 + 8 + 1 + 8 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 32 + 1]);
+        data
+    }
+
+    #[test]
+    fn legacy_report_reason_is_replaced_by_its_hash() {
+        let researcher = Pubkey::new_unique();
+        let legacy = |reason: Option<&str>, padding: usize| {
+            let head = ReportLayoutHead {
+                vault: Pubkey::new_unique(),
+                researcher,
+                severity: SeverityTier::High,
+                status: ReportStatus::Approved,
+                report_ipfs_hash: [9; 32],
+                report_bump: 254,
+                submitted_at: 1_700_000_000,
+                approved_at: Some(1_700_000_100),
+                paid_at: None,
+                approver: Some(Pubkey::new_unique()),
+            };
+            let mut data = legacy_report_data(&head, reason);
+            data.extend(std::iter::repeat_n(0, padding));
+            data
+        };
+
+        let report = migrate_legacy_report(&legacy(Some("Valid critical vulnerability"), 64)).unwrap();
+        assert_eq!(report.researcher, researcher);
+        assert_eq!(report.approved_at, Some(1_700_000_100));
+        assert_eq!(report.reason_hash, hash(b"Valid critical vulnerability").to_bytes());
+        assert_eq!(report.reason_template, None);
+        assert_eq!(report.payout_amount, 500);
+        assert_eq!(report.version, REPORT_VERSION);
+
+        let report = migrate_legacy_report(&legacy(None, 0)).unwrap();
+        assert_eq!(report.reason_hash, [0; 32]);
+        assert_eq!(report.payout_amount, 500);
+        assert_eq!(report.version, REPORT_VERSION);
+        assert_eq!(report.rent_refund_address(), researcher);
+    }
+
+    /// Unix time served by the stub Clock sysvar
+    const STUB_NOW: i64 = 1_700_000_000;
+
+    /// Serves the default Rent sysvar and a Clock at STUB_NOW, so instructions that read them
+    /// can run through crate::entry
+    struct DefaultSysvarStubs;
+
+    impl program_stubs::SyscallStubs for DefaultSysvarStubs {
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { *(var_addr as *mut Rent) = Rent::default() };
+            0
+        }
+
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { *(var_addr as *mut Clock) = Clock { unix_timestamp: STUB_NOW, ..Clock::default() } };
+            0
+        }
+    }
+
+    /// Account key preceded by the original data length, where the runtime keeps it for realloc
+    #[repr(C)]
+    struct SerializedKey {
+        original_data_len: u32,
+        key: Pubkey,
+    }
+
+    #[test]
+    fn migrate_report_grows_a_baseline_sized_account() {
+        program_stubs::set_syscall_stubs(Box::new(DefaultSysvarStubs));
+
+        let vault_key = Pubkey::new_unique();
+        let researcher = Pubkey::new_unique();
+        let (report_address, report_bump) = find_report_address(&vault_key, &researcher, 7);
+        let head = ReportLayoutHead {
+            vault: vault_key,
+            researcher,
+            severity: SeverityTier::Critical,
+            status: ReportStatus::Paid,
+            report_ipfs_hash: [9; 32],
+            report_bump,
+            submitted_at: 1_700_000_000,
+            approved_at: Some(1_700_000_100),
+            paid_at: Some(1_700_000_200),
+            approver: Some(Pubkey::new_unique()),
+        };
+
+        // Baseline reports were allocated 8 + size_of::<VulnerabilityReport>() + 256 = 472 bytes
+        let baseline_len = 472;
+        let space = 8 + std::mem::size_of::<VulnerabilityReport>();
+        let mut report_buffer = vec![0u8; 8 + space + MAX_PERMITTED_DATA_INCREASE];
+        let report_data = &mut report_buffer[8..8 + baseline_len];
+        report_data[..8].copy_from_slice(VulnerabilityReport::DISCRIMINATOR);
+        let legacy = legacy_report_data(&head, Some("Drained the vault"));
+        report_data[8..8 + legacy.len()].copy_from_slice(&legacy);
+        let report_key = SerializedKey { original_data_len: baseline_len as u32, key: report_address };
+        // Already rent-exempt at the new size: CPI into the system program can't run off-chain
+        let mut report_lamports = Rent::default().minimum_balance(space);
+
+        let mut vault_data = vec![0u8; 8 + std::mem::size_of::<BugBountyVault>() + MAX_PROJECT_NAME_LEN];
+        zeroed_vault().try_serialize(&mut &mut vault_data[..]).unwrap();
+        let mut vault_lamports = 1;
+
+        let payer = Pubkey::new_unique();
+        let mut payer_lamports = 1_000_000_000;
+        let mut system_lamports = 1;
+        let system_program_id = system_program::ID;
+        let accounts = [
+            AccountInfo::new(&payer, true, true, &mut payer_lamports, &mut [], &system_program_id, false, 0),
+            AccountInfo::new(&vault_key, false, false, &mut vault_lamports, &mut vault_data, &crate::ID, false, 0),
+            AccountInfo::new(&report_key.key, false, true, &mut report_lamports, report_data, &crate::ID, false, 0),
+            AccountInfo::new(&system_program_id, false, false, &mut system_lamports, &mut [], &system_program_id, true, 0),
+        ];
+
+        let mut data = hash(b"global:migrate_report").to_bytes()[..8].to_vec();
+        data.extend_from_slice(&7u64.to_le_bytes());
+        crate::entry(&crate::ID, &accounts, &data).unwrap();
+
+        let report_info = &accounts[2];
+        assert_eq!(report_info.data_len(), space);
+        assert_eq!(accounts[0].lamports(), 1_000_000_000);
+
+        let report = VulnerabilityReport::try_deserialize(&mut &report_info.data.borrow()[..]).unwrap();
+        assert_eq!(report.researcher, researcher);
+        assert_eq!(report.status, ReportStatus::Paid);
+        assert_eq!(report.paid_at, Some(1_700_000_200));
+        assert_eq!(report.reason_hash, hash(b"Drained the vault").to_bytes());
+        assert_eq!(report.payout_amount, 500);
+        assert_eq!(report.version, REPORT_VERSION);
+    }
+
+    #[test]
+    fn migrate_vault_grows_a_first_deployment_vault() {
+        program_stubs::set_syscall_stubs(Box::new(DefaultSysvarStubs));
+
+        // First-deployment vaults were allocated 8 + size_of::<BugBountyVault>() = 216 bytes
+        assert_eq!(VAULT_V1_ACCOUNT_LEN, 216);
+        let reward_mint = Pubkey::new_unique();
+        let legacy = VaultLayoutV1 {
+            program_team: Pubkey::new_unique(),
+            governance_authority: Pubkey::new_unique(),
+            vault_bump: 253,
+            vault_token_account: Pubkey::new_unique(),
+            critical_reward: 1_000,
+            high_reward: 500,
+            medium_reward: 250,
+            low_reward: 100,
+            total_funded: 5_000,
+            total_paid_out: 1_000,
+            total_reports: 4,
+            approved_reports: 2,
+            reward_token_mint: Some(reward_mint),
+            vault_active: true,
+            created_at: 1_700_000_000,
+        };
+
+        let space = 8 + std::mem::size_of::<BugBountyVault>() + MAX_PROJECT_NAME_LEN;
+        let mut vault_buffer = vec![0u8; 8 + space + MAX_PERMITTED_DATA_INCREASE];
+        let vault_data = &mut vault_buffer[8..8 + VAULT_V1_ACCOUNT_LEN];
+        vault_data[..8].copy_from_slice(BugBountyVault::DISCRIMINATOR);
+        legacy.serialize(&mut &mut vault_data[8..]).unwrap();
+        let vault_key = SerializedKey { original_data_len: VAULT_V1_ACCOUNT_LEN as u32, key: Pubkey::new_unique() };
+        // Already rent-exempt at the new size: CPI into the system program can't run off-chain
+        let mut vault_lamports = Rent::default().minimum_balance(space);
+
+        let mut mint_data = vec![0u8; spl_token::state::Mint::LEN];
+        spl_token::state::Mint { decimals: 6, is_initialized: true, ..Default::default() }.pack_into_slice(&mut mint_data);
+        let mut mint_lamports = 1;
+
+        let payer = Pubkey::new_unique();
+        let mut payer_lamports = 1_000_000_000;
+        let mut system_lamports = 1;
+        let system_program_id = system_program::ID;
+        let token_program_id = token::ID;
+        let accounts = [
+            AccountInfo::new(&payer, true, true, &mut payer_lamports, &mut [], &system_program_id, false, 0),
+            AccountInfo::new(&vault_key.key, false, true, &mut vault_lamports, vault_data, &crate::ID, false, 0),
+            AccountInfo::new(&reward_mint, false, false, &mut mint_lamports, &mut mint_data, &token_program_id, false, 0),
+            AccountInfo::new(&system_program_id, false, false, &mut system_lamports, &mut [], &system_program_id, true, 0),
+        ];
+
+        let data = hash(b"global:migrate_vault").to_bytes()[..8].to_vec();
+        crate::entry(&crate::ID, &accounts, &data).unwrap();
+
+        let vault_info = &accounts[1];
+        assert_eq!(vault_info.data_len(), space);
+        assert_eq!(accounts[0].lamports(), 1_000_000_000);
+
+        let vault = BugBountyVault::try_deserialize(&mut &vault_info.data.borrow()[..]).unwrap();
+        assert_eq!(vault.vault_bump, 253);
+        assert_eq!(vault.low_reward, 100);
+        assert_eq!(vault.approved_reports, 2);
+        assert_eq!(vault.rejected_reports, 0);
+        assert_eq!(vault.reward_token_mint, Some(reward_mint));
+        assert_eq!(vault.created_at, 1_700_000_000);
+        assert_eq!(vault.reward_decimals, 6);
+        assert_eq!(vault.vault_authority, vault_key.key);
+        assert!(vault.submissions_open);
+        assert_eq!(vault.claim_window_seconds, DEFAULT_CLAIM_WINDOW_SECONDS);
+
+        // Once migrated, the vault is refused
+        assert_eq!(
+            crate::entry(&crate::ID, &accounts, &data).unwrap_err(),
+            ProgramError::Custom(ERROR_CODE_OFFSET + BugBountyError::NotLegacyVault as u32)
+        );
+    }
+
+    #[test]
+    fn expire_approval_refunds_the_charged_delegate_role() {
+        program_stubs::set_syscall_stubs(Box::new(DefaultSysvarStubs));
+
+        let vault_key = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let (role_key, role_bump) = find_role_address(&vault_key, &delegate);
+
+        let mut vault = zeroed_vault();
+        vault.claim_window_seconds = 60;
+        vault.committed_amount = 250;
+        let mut vault_data = vec![0u8; 8 + std::mem::size_of::<BugBountyVault>() + MAX_PROJECT_NAME_LEN];
+        vault.try_serialize(&mut &mut vault_data[..]).unwrap();
+
+        let mut report = zeroed_report();
+        report.vault = vault_key;
+        report.status = ReportStatus::Approved;
+        report.approved_at = Some(STUB_NOW - 120);
+        report.approver = Some(delegate);
+        report.payout_amount = 250;
+        report.approver_role_charged = true;
+        let mut report_data = vec![0u8; 8 + std::mem::size_of::<VulnerabilityReport>()];
+        report.try_serialize(&mut &mut report_data[..]).unwrap();
+
+        let role = Role {
+            vault: vault_key,
+            member: delegate,
+            permissions: PERMISSION_APPROVE,
+            role_bump,
+            max_total_payout: 1_000,
+            max_single_payout: 500,
+            spent: 750,
+        };
+        let mut role_data = vec![0u8; 8 + std::mem::size_of::<Role>()];
+        role.try_serialize(&mut &mut role_data[..]).unwrap();
+
+        let report_key = Pubkey::new_unique();
+        let (mut vault_lamports, mut report_lamports, mut role_lamports, mut program_lamports) = (1, 1, 1, 1);
+        let program_owner = Pubkey::new_unique();
+        let accounts = [
+            AccountInfo::new(&vault_key, false, true, &mut vault_lamports, &mut vault_data, &crate::ID, false, 0),
+            AccountInfo::new(&report_key, false, true, &mut report_lamports, &mut report_data, &crate::ID, false, 0),
+            AccountInfo::new(&crate::ID, false, false, &mut program_lamports, &mut [], &program_owner, true, 0),
+            AccountInfo::new(&role_key, false, true, &mut role_lamports, &mut role_data, &crate::ID, false, 0),
+        ];
+        let data = hash(b"global:expire_approval").to_bytes()[..8].to_vec();
+
+        // Leaving out the charged role would strand its spend
+        let without_role = [accounts[0].clone(), accounts[1].clone(), accounts[2].clone(), accounts[2].clone()];
+        assert_eq!(
+            crate::entry(&crate::ID, &without_role, &data).unwrap_err(),
+            ProgramError::Custom(ERROR_CODE_OFFSET + BugBountyError::ApproverRoleRequired as u32)
+        );
+
+        crate::entry(&crate::ID, &accounts, &data).unwrap();
+        let role = Role::try_deserialize(&mut &accounts[3].data.borrow()[..]).unwrap();
+        assert_eq!(role.spent, 500);
+        let report = VulnerabilityReport::try_deserialize(&mut &accounts[1].data.borrow()[..]).unwrap();
+        assert_eq!(report.status, ReportStatus::Expired);
+        assert!(!report.approver_role_charged);
+        let vault = BugBountyVault::try_deserialize(&mut &accounts[0].data.borrow()[..]).unwrap();
+        assert_eq!(vault.committed_amount, 0);
+    }
+
+    #[test]
+    fn payout_breakdown_splits_donation_and_flags_co_approval() {
+        let mut vault = zeroed_vault();
+        vault.dual_approval_threshold = 1_000;
+        let mut report = zeroed_report();
+        report.status = ReportStatus::Approved;
+        report.payout_amount = 1_000;
+        report.donation_bps = 2_500;
+
+        let breakdown = payout_breakdown(&vault, &report).unwrap();
+        assert_eq!(breakdown.researcher_amount, 750);
+        assert_eq!(breakdown.donation_amount, 250);
+        assert!(breakdown.co_approval_required);
+        assert!(!breakdown.payable);
+
+        report.team_co_approved = true;
+        assert!(payout_breakdown(&vault, &report).unwrap().payable);
+    }
+
+    #[test]
+    fn payout_legs_conserve_value_under_either_dust_policy() {
+        let mut next = xorshift64(0x2545_F491_4F6C_DD1D);
+
+        let mut vault = zeroed_vault();
+        let mut report = zeroed_report();
+        for i in 0..2_000 {
+            vault.dust_policy = if i % 2 == 0 { DustPolicy::Researcher } else { DustPolicy::Vault };
+            report.payout_amount = match next(3) {
+                0 => next(1_000),
+                1 => next(u64::MAX),
+                _ => u64::MAX - next(10),
+            };
+            report.donation_bps = next(BPS_DENOMINATOR + 1) as u16;
+
+            let breakdown = payout_breakdown(&vault, &report).unwrap();
+            let legs = breakdown.researcher_amount as u128
+                + breakdown.donation_amount as u128
+                + breakdown.retained_dust_amount as u128;
+            assert_eq!(legs, report.payout_amount as u128);
+            // Two floored legs lose less than a unit each
+            assert!(breakdown.retained_dust_amount <= 1);
+            assert_eq!(breakdown.donation_amount, bps_of(report.payout_amount, report.donation_bps).unwrap());
+            if vault.dust_policy == DustPolicy::Researcher {
+                assert_eq!(breakdown.retained_dust_amount, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn dust_policy_assigns_the_rounding_remainder() {
+        let mut vault = zeroed_vault();
+        let mut report = zeroed_report();
+        report.payout_amount = 101;
+        report.donation_bps = 5_000;
+
+        let breakdown = payout_breakdown(&vault, &report).unwrap();
+        assert_eq!((breakdown.researcher_amount, breakdown.donation_amount, breakdown.retained_dust_amount), (51, 50, 0));
+
+        vault.dust_policy = DustPolicy::Vault;
+        let breakdown = payout_breakdown(&vault, &report).unwrap();
+        assert_eq!((breakdown.researcher_amount, breakdown.donation_amount, breakdown.retained_dust_amount), (50, 50, 1));
+    }
+
+    #[test]
+    fn submission_checks_enforce_the_open_report_cap() {
+        let mut vault = zeroed_vault();
+        vault.vault_active = true;
+        vault.submissions_open = true;
+        assert!(check_can_submit(&vault, None, 0).is_ok());
+
+        vault.max_open_reports_per_researcher = 2;
+        assert_eq!(
+            check_can_submit(&vault, None, 0).unwrap_err(),
+            BugBountyError::SubmissionCounterRequired.into()
+        );
+        assert!(check_can_submit(&vault, Some(&counter_with(1, 0)), 0).is_ok());
+        assert_eq!(
+            check_can_submit(&vault, Some(&counter_with(2, 0)), 0).unwrap_err(),
+            BugBountyError::TooManyOpenReports.into()
+        );
+
+        vault.submissions_open = false;
+        assert_eq!(
+            check_can_submit(&vault, Some(&counter_with(0, 0)), 0).unwrap_err(),
+            BugBountyError::SubmissionsClosed.into()
+        );
+    }
+
+    #[test]
+    fn rejection_cooldown_ends_on_the_boundary_second() {
+        let mut vault = zeroed_vault();
+        vault.vault_active = true;
+        vault.submissions_open = true;
+        let rejected = counter_with(0, 1_000);
+
+        // Disabled cooldowns ignore past rejections
+        assert!(check_can_submit(&vault, Some(&rejected), 1_000).is_ok());
+
+        vault.cooldown_after_rejection_seconds = 60;
+        assert_eq!(
+            check_can_submit(&vault, None, 5_000).unwrap_err(),
+            BugBountyError::SubmissionCounterRequired.into()
+        );
+        assert_eq!(
+            check_can_submit(&vault, Some(&rejected), 1_059).unwrap_err(),
+            BugBountyError::SubmissionCooldownActive.into()
+        );
+        assert!(check_can_submit(&vault, Some(&rejected), 1_060).is_ok());
+        // Researchers who were never rejected aren't held back
+        assert!(check_can_submit(&vault, Some(&counter_with(0, 0)), 10).is_ok());
+    }
+
+    #[test]
+    fn extra_reward_mints_keep_separate_accounting() {
+        let usdc = Pubkey::new_unique();
+        let mut vault = zeroed_vault();
+        vault.total_funded = 100;
+        vault.extra_reward_mints[1] = usdc;
+        vault.extra_funded[1] = 1_000;
+        vault.tier_mints[0] = usdc;
+
+        assert_eq!(vault.mint_index(&Pubkey::default()), Some(0));
+        assert_eq!(vault.mint_index(&usdc), Some(2));
+        assert_eq!(vault.mint_index(&Pubkey::new_unique()), None);
+        assert_eq!(vault.tier_mint_for(SeverityTier::Critical), usdc);
+        assert_eq!(vault.tier_mint_for(SeverityTier::Low), Pubkey::default());
+
+        vault.commit_payout(2, 800).unwrap();
+        assert_eq!(
+            vault.commit_payout(2, 201).unwrap_err(),
+            BugBountyError::InsufficientVaultFunds.into()
+        );
+        assert_eq!(vault.mint_available_balance(0).unwrap(), 100);
+
+        vault.settle_payout(2, 800).unwrap();
+        assert_eq!(vault.extra_paid_out[1], 800);
+        assert_eq!(vault.total_paid_out, 0);
+        assert_eq!(vault.mint_available_balance(2).unwrap(), 200);
+        assert_eq!(
+            vault.mint_available_balance(1).unwrap_err(),
+            BugBountyError::UnknownRewardMint.into()
+        );
+    }
+
+    #[test]
+    fn decay_multiplier_interpolates_within_the_window_and_rounds_down() {
+        let decay = DecayConfig {
+            start_multiplier_bps: 20_000,
+            end_multiplier_bps: 10_000,
+            decay_start: 1_000,
+            decay_end: 1_003,
+        };
+
+        // Outside the window the reward is unscaled
+        assert_eq!(decay.multiplier_bps_at(999), 10_000);
+        assert_eq!(decay.multiplier_bps_at(1_004), 10_000);
+        // The endpoints are inclusive
+        assert_eq!(decay.multiplier_bps_at(1_000), 20_000);
+        assert_eq!(decay.multiplier_bps_at(1_003), 10_000);
+        // 16_666.67 and 13_333.33 both round down
+        assert_eq!(decay.multiplier_bps_at(1_001), 16_666);
+        assert_eq!(decay.multiplier_bps_at(1_002), 13_333);
+
+        let mut vault = zeroed_vault();
+        vault.high_reward = 999;
+        let mut report = zeroed_report();
+        report.severity = SeverityTier::High;
+        assert_eq!(scaled_reward(&vault, &report).unwrap(), 999);
+        report.reward_multiplier_bps = 15_000;
+        // 1498.5 rounds down
+        assert_eq!(scaled_reward(&vault, &report).unwrap(), 1_498);
+    }
+
+    #[test]
+    fn reward_tiers_respect_granularity_and_scale_to_whole_tokens() {
+        let rewards = [5_000_000_000, 1_500_000, 1_000_000, 999_999];
+
+        assert!(require_granular(0, rewards).is_ok());
+        assert!(require_granular(1, rewards).is_ok());
+        assert_eq!(
+            require_granular(1_000_000, rewards).unwrap_err(),
+            BugBountyError::GranularityViolation.into()
+        );
+        assert!(require_granular(500_000, [5_000_000_000, 1_500_000, 1_000_000, 500_000]).is_ok());
+
+        // 6 decimals: partial tokens round down
+        assert_eq!(whole_token_amounts(rewards, 6), [5_000, 1, 1, 0]);
+        assert_eq!(whole_token_amounts(rewards, 0), rewards);
+        // Decimals beyond u64 range can never reach a whole token
+        assert_eq!(whole_token_amounts(rewards, 30), [0; 4]);
+    }
+
+    #[test]
+    fn delegation_caps_bound_single_and_total_approvals() {
+        let mut role = Role {
+            vault: Pubkey::default(),
+            member: Pubkey::default(),
+            permissions: PERMISSION_APPROVE,
+            role_bump: 0,
+            max_total_payout: 0,
+            max_single_payout: 0,
+            spent: 0,
+        };
+
+        // Uncapped by default
+        role.charge_payout(u64::MAX / 2).unwrap();
+        role.refund_payout(u64::MAX);
+        assert_eq!(role.spent, 0);
+
+        role.max_single_payout = 500;
+        role.max_total_payout = 1_000;
+        role.charge_payout(500).unwrap();
+        assert_eq!(
+            role.charge_payout(501).unwrap_err(),
+            BugBountyError::DelegationCapExceeded.into()
+        );
+        role.charge_payout(400).unwrap();
+        // 900 spent, so 101 more would cross the total
+        assert_eq!(
+            role.charge_payout(101).unwrap_err(),
+            BugBountyError::DelegationCapExceeded.into()
+        );
+        assert_eq!(role.spent, 900);
+
+        // An expired approval frees its share of the cap
+        role.refund_payout(400);
+        role.charge_payout(500).unwrap();
+        assert_eq!(role.spent, 1_000);
+    }
+
+    #[test]
+    fn profile_payouts_advance_milestones() {
+        let mut profile = ResearcherProfile {
+            researcher: Pubkey::default(),
+            profile_bump: 0,
+            handle: None,
+            paid_reports: 0,
+            paid_critical_reports: 0,
+            total_earned: 0,
+            external_source: 0,
+            external_score: 0,
+        };
+        let reached = |profile: &ResearcherProfile| {
+            MILESTONES.iter().map(|m| profile.progress(m.metric) >= m.threshold).collect::<Vec<_>>()
+        };
+        assert_eq!(reached(&profile), [false; 6]);
+
+        // Informational payouts are zero and don't count
+        profile.record_payout(SeverityTier::Informational, 0, 0).unwrap();
+        assert_eq!(profile.paid_reports, 0);
+
+        // 99,999 USDC (6 decimals), then 0.1 USDC, stay short of 100k whole tokens earned
+        let usdc = |amount: u64| (amount, whole_tokens(amount, 6));
+        let (amount, earned) = usdc(99_999_000_000);
+        profile.record_payout(SeverityTier::Critical, amount, earned).unwrap();
+        assert_eq!(reached(&profile), [true, false, false, true, false, false]);
+        let (amount, earned) = usdc(100_000);
+        profile.record_payout(SeverityTier::Low, amount, earned).unwrap();
+        assert_eq!(profile.total_earned, 99_999);
+        assert_eq!(reached(&profile), [true, false, false, true, false, false]);
+
+        let (amount, earned) = usdc(1_000_000);
+        profile.record_payout(SeverityTier::Low, amount, earned).unwrap();
+        assert_eq!(profile.paid_critical_reports, 1);
+        assert_eq!(reached(&profile), [true, false, false, true, false, true]);
+    }
+
+    #[test]
+    fn wind_down_closes_submissions_and_tracks_unresolved_reports() {
+        let mut vault = zeroed_vault();
+        vault.vault_active = true;
+        vault.submissions_open = true;
+        assert!(check_can_submit(&vault, None, 0).is_ok());
+
+        vault.wind_down_phase = WindDownPhase::WindingDown;
+        assert_eq!(
+            check_can_submit(&vault, None, 0).unwrap_err(),
+            BugBountyError::VaultWindingDown.into()
+        );
+
+        vault.report_unresolved().unwrap();
+        vault.report_unresolved().unwrap();
+        vault.report_resolved();
+        assert_eq!(vault.unresolved_reports, 1);
+        // Reports opened before the count existed resolve without underflowing
+        vault.report_resolved();
+        vault.report_resolved();
+        assert_eq!(vault.unresolved_reports, 0);
+    }
+
+    #[test]
+    fn tier_bits_follow_severity_order() {
+        let mut vault = zeroed_vault();
+        assert_eq!(tier_bit(SeverityTier::Critical), 0b00001);
+        assert_eq!(tier_bit(SeverityTier::Informational), 0b10000);
+
+        vault.paused_tiers = tier_bit(SeverityTier::Medium);
+        assert!(vault.tier_paused(SeverityTier::Medium));
+        assert!(!vault.tier_paused(SeverityTier::High));
+        assert!(!vault.tier_paused(SeverityTier::Low));
+        assert_eq!(tier_bit(SeverityTier::Informational) << 1, ALL_SEVERITY_TIERS + 1);
+    }
+
+    #[test]
+    fn timestamp_guards_reject_past_far_future_and_overflow() {
+        let now = 1_700_000_000;
+        assert!(validate_timestamp(now, now).is_ok());
+        assert!(validate_timestamp(now + MAX_TIMESTAMP_HORIZON_SECONDS, now).is_ok());
+        assert!(validate_timestamp(now - 1, now).is_err());
+        assert!(validate_timestamp(now + MAX_TIMESTAMP_HORIZON_SECONDS + 1, now).is_err());
+        assert!(validate_timestamp(i64::MAX, i64::MAX - 1).is_err());
+        
+        assert!(validate_duration(0).is_ok());
+        assert!(validate_duration(MAX_TIMESTAMP_HORIZON_SECONDS).is_ok());
+        assert!(validate_duration(-1).is_err());
+        assert!(validate_duration(MAX_TIMESTAMP_HORIZON_SECONDS + 1).is_err());
+    }
+
+    #[test]
+    fn first_response_counts_once_and_skips_held_time() {
+        let mut vault = zeroed_vault();
+        let mut report = zeroed_report();
+        report.status = ReportStatus::Pending;
+        report.submitted_at = 1_000;
+        report.total_held_seconds = 300;
+
+        assert_eq!(record_first_response(&mut vault, &report, 2_000).unwrap(), 700);
+        assert_eq!((vault.first_responses, vault.total_first_response_seconds), (1, 700));
+
+        // A decision after the acknowledgment is not a second first response
+        report.acknowledged_at = Some(2_000);
+        record_first_response(&mut vault, &report, 5_000).unwrap();
+        assert_eq!((vault.first_responses, vault.total_first_response_seconds), (1, 700));
+
+        // Re-approving an Expired report does not count either
+        report.acknowledged_at = None;
+        report.status = ReportStatus::Expired;
+        record_first_response(&mut vault, &report, 5_000).unwrap();
+        assert_eq!(vault.first_responses, 1);
+    }
+
+    #[test]
+    fn pending_count_matches_reports_after_random_transitions() {
+        let mut draw = xorshift64(0x9E37_79B9_7F4A_7C15);
+        let mut next = |bound: usize| draw(bound as u64) as usize;
+
+        for _ in 0..64 {
+            let mut vault = zeroed_vault();
+            let mut reports: Vec<VulnerabilityReport> = Vec::new();
+            for _ in 0..200 {
+                if reports.is_empty() || next(4) == 0 {
+                    reports.push(zeroed_report());
+                    vault.report_opened().unwrap();
+                } else {
+                    let index = next(reports.len());
+                    let report = &mut reports[index];
+                    let targets: &[ReportStatus] = match report.status {
+                        ReportStatus::Pending => &[ReportStatus::Approved, ReportStatus::Rejected, ReportStatus::Withdrawn],
+                        ReportStatus::Approved if report.disputed_severity.is_none() => {
+                            &[ReportStatus::Paid, ReportStatus::Expired, ReportStatus::SeverityDisputed]
+                        }
+                        ReportStatus::Approved => &[ReportStatus::Paid, ReportStatus::Expired],
+                        ReportStatus::SeverityDisputed => &[ReportStatus::Approved],
+                        ReportStatus::Expired => &[ReportStatus::Approved],
+                        ReportStatus::Rejected if !report.escalated => &[ReportStatus::InArbitration],
+                        ReportStatus::InArbitration => &[ReportStatus::Approved, ReportStatus::Rejected],
+                        _ => &[],
+                    };
+                    if targets.is_empty() {
+                        continue;
+                    }
+                    let target = targets[next(targets.len())];
+                    report.escalated |= target == ReportStatus::InArbitration;
+                    if target == ReportStatus::SeverityDisputed {
+                        report.disputed_severity = Some(SeverityTier::Critical);
+                    }
+                    vault.transition_report(report, target, Pubkey::default()).unwrap();
+                }
+
+                let count = |unresolved: bool| reports.iter()
+                    .filter(|r| if unresolved { r.status.is_unresolved() } else { r.status == ReportStatus::Pending })
+                    .count() as u64;
+                assert_eq!(vault.pending_reports, count(false));
+                assert_eq!(vault.unresolved_reports, count(true));
+                assert_eq!(
+                    vault.disputed_reports,
+                    reports.iter().filter(|r| r.status == ReportStatus::SeverityDisputed).count() as u64
+                );
+                assert_eq!(
+                    vault.open_arbitrations,
+                    reports.iter().filter(|r| r.status == ReportStatus::InArbitration).count() as u64
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn triage_queue_cap_blocks_submissions_when_full() {
+        let mut vault = zeroed_vault();
+        vault.vault_active = true;
+        vault.submissions_open = true;
+        vault.max_pending_reports = 2;
+        vault.report_opened().unwrap();
+        assert!(check_can_submit(&vault, None, 0).is_ok());
+
+        vault.report_opened().unwrap();
+        assert_eq!(
+            check_can_submit(&vault, None, 0).unwrap_err(),
+            BugBountyError::TriageQueueFull.into()
+        );
+
+        let mut report = zeroed_report();
+        vault.transition_report(&mut report, ReportStatus::Rejected, Pubkey::default()).unwrap();
+        assert!(check_can_submit(&vault, None, 0).is_ok());
+    }
+
+    #[test]
+    fn circuit_breaker_counts_a_rolling_hour_of_payouts() {
+        let mut vault = zeroed_vault();
+        vault.max_payouts_per_hour = 3;
+        vault.max_hourly_outflow = 1_000;
+        let start = 1_700_000_000;
+
+        vault.record_hourly_payout(300, start).unwrap();
+        vault.record_hourly_payout(300, start + 900).unwrap();
+        assert_eq!(vault.hourly_payouts_with(300, start + 1_800).unwrap(), (3, 900));
+        assert!(!vault.breaks_circuit(3, 900));
+        // A fourth payout, or 1,100 out within the hour, breaks a limit
+        assert!(vault.breaks_circuit(4, 900));
+        assert_eq!(vault.hourly_payouts_with(500, start + 1_800).unwrap(), (3, 1_100));
+        assert!(vault.breaks_circuit(3, 1_100));
+
+        // An hour on, the first payout's bucket has left the window and its slot is reused
+        let later = start + CIRCUIT_BREAKER_WINDOW_SECONDS;
+        assert_eq!(vault.hourly_payouts_with(0, later).unwrap(), (2, 300));
+        vault.record_hourly_payout(100, later).unwrap();
+        assert_eq!(vault.hourly_payouts_with(0, later).unwrap(), (3, 400));
+    }
+
+    #[test]
+    fn circuit_breaker_limits_of_zero_never_trip() {
+        let vault = zeroed_vault();
+        assert!(!vault.breaks_circuit(u32::MAX, u64::MAX));
+    }
+
+    fn dispatch_set_test_clock() -> std::result::Result<(), ProgramError> {
+        let mut data = hash(b"global:set_test_clock").to_bytes()[..8].to_vec();
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        crate::entry(&crate::ID, &[], &data)
+    }
+
+    #[cfg(not(feature = "test-clock"))]
+    #[test]
+    fn set_test_clock_is_compiled_out() {
+        assert_eq!(
+            dispatch_set_test_clock().unwrap_err(),
+            ProgramError::Custom(ErrorCode::InstructionFallbackNotFound as u32)
+        );
+    }
+
+    #[cfg(feature = "test-clock")]
+    #[test]
+    fn set_test_clock_pins_current_time() {
+        // Dispatched, then refused for want of accounts
+        assert_eq!(
+            dispatch_set_test_clock().unwrap_err(),
+            ProgramError::Custom(ErrorCode::AccountNotEnoughKeys as u32)
+        );
+
+        let mut vault = zeroed_vault();
+        vault.clock_override = 1_700_000_000;
+        assert_eq!(current_time(&vault).unwrap(), 1_700_000_000);
+    }
+
+    #[test]
+    fn payout_fraction_cap_is_inclusive_at_the_boundary() {
+        let mut vault = zeroed_vault();
+        vault.max_payout_fraction_bps = 2_500;
+        assert!(!vault.exceeds_payout_fraction(250, 1_000));
+        assert!(vault.exceeds_payout_fraction(251, 1_000));
+        // Rounding never lets a payout past the cap: 25% of 1,001 is 250.25
+        assert!(!vault.exceeds_payout_fraction(250, 1_001));
+        assert!(vault.exceeds_payout_fraction(251, 1_001));
+        assert!(!vault.exceeds_payout_fraction(u64::MAX / 4, u64::MAX));
+
+        // 10,000 and the pre-upgrade 0 both leave payouts uncapped
+        for uncapped in [0, BPS_DENOMINATOR as u16] {
+            vault.max_payout_fraction_bps = uncapped;
+            assert!(!vault.exceeds_payout_fraction(1_000, 1_000));
+        }
+    }
+
+    #[test]
+    fn compromise_alert_limits_payouts_to_the_low_reward_in_lows_mint() {
+        let mut vault = zeroed_vault();
+        vault.low_reward = 100;
+        let primary = Pubkey::default();
+        assert!(!vault.held_by_compromise_alert(&primary, 1_000));
+
+        vault.compromise_alert_open = true;
+        assert!(!vault.held_by_compromise_alert(&primary, 100));
+        assert!(vault.held_by_compromise_alert(&primary, 101));
+
+        // Once Low pays in another mint, 100 primary tokens may be worth far more than a Low reward
+        let low_mint = Pubkey::new_unique();
+        vault.tier_mints[3] = low_mint;
+        assert!(vault.held_by_compromise_alert(&primary, 100));
+        assert!(!vault.held_by_compromise_alert(&low_mint, 100));
+        assert!(vault.held_by_compromise_alert(&low_mint, 101));
+    }
+
+    #[test]
+    fn firm_members_crank_payouts_only_for_their_recipient() {
+        let firm = Pubkey::new_unique();
+        let member = Pubkey::new_unique();
+        let mut report = zeroed_report();
+        report.researcher = Pubkey::new_unique();
+        report.payout_recipient = Some(firm);
+        let registration = FirmMember {
+            recipient: firm,
+            member,
+            registered_at: 0,
+            rent_payer: firm,
+            firm_member_bump: 0,
+        };
+
+        assert!(check_payout_signer(report.researcher, &report, None).is_ok());
+        assert!(check_payout_signer(member, &report, Some(&registration)).is_ok());
+        assert_eq!(
+            check_payout_signer(member, &report, None).unwrap_err(),
+            BugBountyError::UnauthorizedResearcher.into()
+        );
+
+        // A member of some other recipient can't crank this report
+        report.payout_recipient = Some(Pubkey::new_unique());
+        assert_eq!(
+            check_payout_signer(member, &report, Some(&registration)).unwrap_err(),
+            BugBountyError::UnauthorizedResearcher.into()
+        );
+        assert_eq!(report.payout_owner(), report.payout_recipient.unwrap());
+    }
+
+    #[test]
+    fn approval_price_follows_the_vault_pricing_policy_both_ways() {
+        // (policy, price after a raise from 250 to 400, price after a cut from 250 to 100)
+        let cases = [
+            (PricingPolicy::AtSubmission, 250, 250),
+            (PricingPolicy::AtApproval, 400, 100),
+            (PricingPolicy::HigherOfBoth, 400, 250),
+            (PricingPolicy::LowerOfBoth, 250, 100),
+        ];
+        for (policy, after_raise, after_cut) in cases {
+            let mut vault = zeroed_vault();
+            vault.pricing_policy = policy;
+            let mut report = zeroed_report();
+            report.severity = SeverityTier::Medium;
+            report.payout_amount = 250;
+
+            vault.medium_reward = 400;
+            assert_eq!(approval_price(&vault, &report).unwrap(), (after_raise, policy));
+            vault.medium_reward = 100;
+            assert_eq!(approval_price(&vault, &report).unwrap(), (after_cut, policy));
+        }
+    }
+
+    #[test]
+    fn approval_price_keeps_the_submitted_amount_across_a_tier_mint_change() {
+        let mut vault = zeroed_vault();
+        vault.pricing_policy = PricingPolicy::AtApproval;
+        vault.medium_reward = 400;
+        let mut report = zeroed_report();
+        report.severity = SeverityTier::Medium;
+        report.payout_amount = 250;
+        report.payout_mint = Pubkey::new_unique();
+
+        assert_eq!(approval_price(&vault, &report).unwrap(), (250, PricingPolicy::AtSubmission));
+    }
+
+    #[test]
+    fn time_on_hold_does_not_run_down_the_claim_window() {
+        let mut vault = zeroed_vault();
+        vault.claim_window_seconds = 1_000;
+        let mut report = zeroed_report();
+        report.status = ReportStatus::Approved;
+        report.approved_at = Some(10_000);
+
+        // Held 100 seconds before the window closes and released well after it
+        report.on_hold = true;
+        report.held_at = Some(10_900);
+        assert_eq!(release_hold(&mut report, 12_000).unwrap(), 1_100);
+        assert!(!report.on_hold);
+        assert_eq!(report.total_held_seconds, 1_100);
+
+        // The 100 unheld seconds left in the window still remain, so it can't expire at release
+        assert_eq!(claim_expires_at(&vault, &report).unwrap(), 12_100);
+    }
+
+    #[test]
+    fn releasing_a_pending_report_leaves_it_unapproved() {
+        let mut report = zeroed_report();
+        report.on_hold = true;
+        report.held_at = Some(10_000);
+
+        assert_eq!(release_hold(&mut report, 10_500).unwrap(), 500);
+        assert_eq!(report.approved_at, None);
+    }
+
+    #[test]
+    fn upholding_the_approved_tier_keeps_the_approved_payout() {
+        let mut vault = zeroed_vault();
+        vault.pricing_policy = PricingPolicy::AtApproval;
+        vault.medium_reward = 400;
+        let mut report = zeroed_report();
+        report.severity = SeverityTier::Critical;
+        report.approved_severity = Some(SeverityTier::Medium);
+        report.payout_amount = 250;
+        report.priced_by = Some(PricingPolicy::AtSubmission);
+
+        reprice_disputed_report(&vault, &mut report, SeverityTier::Medium).unwrap();
+        assert_eq!(report.severity, SeverityTier::Medium);
+        assert_eq!(report.payout_amount, 250);
+        assert_eq!(report.priced_by, Some(PricingPolicy::AtSubmission));
+    }
+
+    #[test]
+    fn dispute_moves_the_report_to_the_new_tier_reward() {
+        let mut vault = zeroed_vault();
+        vault.pricing_policy = PricingPolicy::LowerOfBoth;
+        vault.critical_reward = 1000;
+        let mut report = zeroed_report();
+        report.severity = SeverityTier::Medium;
+        report.approved_severity = Some(SeverityTier::Medium);
+        report.payout_amount = 250;
+
+        reprice_disputed_report(&vault, &mut report, SeverityTier::Critical).unwrap();
+        assert_eq!(report.severity, SeverityTier::Critical);
+        assert_eq!(report.payout_amount, 1000);
+        assert_eq!(report.priced_by, Some(PricingPolicy::LowerOfBoth));
+    }
+
+    #[test]
+    fn dispute_cannot_move_the_report_onto_another_mint() {
+        let mut vault = zeroed_vault();
+        vault.critical_reward = 1000;
+        vault.tier_mints[0] = Pubkey::new_unique();
+        let mut report = zeroed_report();
+        report.severity = SeverityTier::Medium;
+        report.approved_severity = Some(SeverityTier::Medium);
+        report.payout_amount = 250;
+
+        assert_eq!(
+            reprice_disputed_report(&vault, &mut report, SeverityTier::Critical).unwrap_err(),
+            BugBountyError::DisputeTierMintMismatch.into()
+        );
+        assert_eq!(report.severity, SeverityTier::Medium);
+        assert_eq!(report.payout_amount, 250);
+    }
+
+    #[test]
+    fn payout_destination_must_leave_the_vault() {
+        let vault_token_account = Pubkey::new_unique();
+        let claim_escrow = Pubkey::new_unique();
+        let researcher_account = Pubkey::new_unique();
+
+        assert!(check_payout_destination(researcher_account, vault_token_account, Some(claim_escrow)).is_ok());
+        assert_eq!(
+            check_payout_destination(vault_token_account, vault_token_account, None).unwrap_err(),
+            BugBountyError::SelfTransferDestination.into()
+        );
+        assert_eq!(
+            check_payout_destination(claim_escrow, vault_token_account, Some(claim_escrow)).unwrap_err(),
+            BugBountyError::SelfTransferDestination.into()
+        );
+    }
+
+    #[test]
+    fn vault_invariants_hold_after_random_money_movements() {
+        let mut next = xorshift64(0x9E37_79B9_7F4A_7C15);
+
+        for _ in 0..64 {
+            let mut vault = zeroed_vault();
+            vault.insurance_bps = next(2_000) as u16;
+            // Token balance of the primary vault token account, and the payouts committed against it
+            let mut balance = 0u64;
+            let mut commitments: Vec<u64> = Vec::new();
+            for _ in 0..200 {
+                match next(7) {
+                    0 => {
+                        let amount = 1 + next(10_000);
+                        vault.total_funded += amount;
+                        vault.insurance_reserve += bps_of(amount, vault.insurance_bps).unwrap();
+                        balance += amount;
+                    }
+                    1 => {
+                        let amount = 1 + next(5_000);
+                        if vault.commit_payout(0, amount).is_ok() {
+                            commitments.push(amount);
+                        }
+                    }
+                    2 if !commitments.is_empty() => {
+                        let amount = commitments.swap_remove(next(commitments.len() as u64) as usize);
+                        vault.settle_payout(0, amount).unwrap();
+                        balance -= amount;
+                    }
+                    3 if !commitments.is_empty() => {
+                        let amount = commitments.swap_remove(next(commitments.len() as u64) as usize);
+                        vault.release_payout(0, amount).unwrap();
+                    }
+                    4 => {
+                        let amount = next(vault.available_balance().unwrap() + 1);
+                        vault.total_withdrawn += amount;
+                        balance -= amount;
+                    }
+                    5 => {
+                        vault.insurance_reserve -= next(vault.insurance_reserve + 1);
+                    }
+                    6 => {
+                        // An untracked donation, sometimes picked up by a sync
+                        balance += next(1_000);
+                        if next(2) == 0 {
+                            vault.total_funded += balance - vault.recorded_balance().unwrap();
+                        }
+                    }
+                    _ => {}
+                }
+                assert!(assert_vault_invariants(&vault, Some(balance)).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn broken_vault_invariants_are_rejected() {
+        let violated = |vault: &BugBountyVault, balance: Option<u64>| {
+            assert_eq!(
+                assert_vault_invariants(vault, balance).unwrap_err(),
+                BugBountyError::AccountingInvariantViolated.into()
+            );
+        };
+
+        let mut vault = zeroed_vault();
+        vault.total_funded = 1_000;
+        vault.committed_amount = 600;
+        vault.insurance_reserve = 100;
+        assert!(assert_vault_invariants(&vault, Some(1_000)).is_ok());
+        assert!(assert_vault_invariants(&vault, None).is_ok());
+
+        // Paid out more than was ever funded
+        let mut overpaid = vault.clone();
+        overpaid.total_paid_out = 700;
+        overpaid.total_withdrawn = 400;
+        violated(&overpaid, None);
+
+        // Commitments the recorded balance can't cover
+        let mut overcommitted = vault.clone();
+        overcommitted.total_withdrawn = 500;
+        violated(&overcommitted, None);
+
+        // Books that claim more than the token account holds
+        violated(&vault, Some(599));
+        let mut reserve_heavy = vault.clone();
+        reserve_heavy.committed_amount = 0;
+        reserve_heavy.insurance_reserve = 900;
+        violated(&reserve_heavy, Some(800));
+
+        // An extra mint paying out more than it was funded with
+        let mut extra = vault.clone();
+        extra.extra_funded[1] = 100;
+        extra.extra_paid_out[1] = 60;
+        extra.extra_committed[1] = 50;
+        violated(&extra, Some(1_000));
+    }
+
+    #[test]
+    fn external_attestations_count_only_when_accepted_and_unexpired() {
+        let mut vault = zeroed_vault();
+        let mut profile: ResearcherProfile = AnchorDeserialize::deserialize(&mut &[0u8; 128][..]).unwrap();
+        profile.paid_reports = 3;
+        let attestation = ExternalAttestation {
+            researcher: profile.researcher,
+            source: 1,
+            score: 10,
+            expires_at: 1_000,
+            issued_by: Pubkey::default(),
+            issued_at: 0,
+            attestation_bump: 0,
+        };
+
+        assert_eq!(researcher_reputation(&vault, None, None, 0), 0);
+        assert_eq!(researcher_reputation(&vault, Some(&profile), Some(&attestation), 0), 3);
+
+        vault.accept_external_attestations = true;
+        assert_eq!(researcher_reputation(&vault, Some(&profile), Some(&attestation), 0), 10);
+        assert_eq!(researcher_reputation(&vault, None, Some(&attestation), 999), 10);
+        assert_eq!(researcher_reputation(&vault, Some(&profile), Some(&attestation), 1_000), 3);
+
+        // On-chain history wins when it is the stronger record
+        profile.paid_reports = 20;
+        assert_eq!(researcher_reputation(&vault, Some(&profile), Some(&attestation), 0), 20);
+    }
+
+    #[test]
+    fn active_vault_check_needs_live_verified_and_funded() {
+        let target = Pubkey::new_unique();
+        let mut vault = zeroed_vault();
+        vault.vault_active = true;
+        vault.verified_program = Some(target);
+        vault.total_funded = 1_000;
+        vault.committed_amount = 300;
+        assert!(check_active_vault(&vault, target, 700).is_ok());
+
+        let fails_with = |vault: &BugBountyVault, target: Pubkey, min_balance: u64, error: BugBountyError| {
+            assert_eq!(check_active_vault(vault, target, min_balance).unwrap_err(), error.into());
+        };
+        fails_with(&vault, target, 701, BugBountyError::InsufficientVaultFunds);
+        fails_with(&vault, Pubkey::new_unique(), 0, BugBountyError::VaultNotVerifiedForProgram);
+
+        let mut winding_down = vault.clone();
+        winding_down.wind_down_phase = WindDownPhase::WindingDown;
+        fails_with(&winding_down, target, 0, BugBountyError::VaultInactive);
+
+        vault.vault_active = false;
+        fails_with(&vault, target, 0, BugBountyError::VaultInactive);
+    }
+
+    #[test]
+    fn only_stray_lamports_are_sweepable() {
+        let mut vault = zeroed_vault();
+        vault.rent_subsidy_budget = 2_000;
+
+        // A 0.5 SOL transfer on top of rent and the subsidy budget
+        assert_eq!(vault.sweepable_lamports(5_000 + 2_000 + 500_000_000, 5_000), 500_000_000);
+        assert_eq!(vault.sweepable_lamports(5_000 + 2_000, 5_000), 0);
+        assert_eq!(vault.sweepable_lamports(6_000, 5_000), 0);
+
+        assert!(!vault.pays_in_wrapped_sol());
+        vault.reward_token_mint = Some(token::spl_token::native_mint::ID);
+        assert!(vault.pays_in_wrapped_sol());
+    }
+
+    #[test]
+    fn report_state_machine_allows_only_the_lifecycle_edges() {
+        use ReportStatus::*;
+        let allowed = [
+            (Pending, Approved),
+            (Pending, Rejected),
+            (Pending, Withdrawn),
+            (Approved, Paid),
+            (Approved, Expired),
+            (Approved, SeverityDisputed),
+            (Approved, Pending),
+            (SeverityDisputed, Approved),
+            (Expired, Approved),
+            (Rejected, InArbitration),
+            (InArbitration, Approved),
+            (InArbitration, Rejected),
+        ];
+        for from in ReportStatus::ALL {
+            for to in ReportStatus::ALL {
+                assert_eq!(
+                    from.can_transition_to(to),
+                    allowed.contains(&(from, to)),
+                    "{} -> {}",
+                    from,
+                    to
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn forbidden_transitions_leave_the_report_and_counters_alone() {
+        let mut vault = zeroed_vault();
+        let mut report = zeroed_report();
+        vault.report_opened().unwrap();
+        vault.transition_report(&mut report, ReportStatus::Withdrawn, Pubkey::default()).unwrap();
+
+        assert_eq!(
+            vault.transition_report(&mut report, ReportStatus::Paid, Pubkey::default()).unwrap_err(),
+            BugBountyError::InvalidReportStatus.into()
+        );
+        assert_eq!(report.status, ReportStatus::Withdrawn);
+        assert_eq!(vault.pending_reports, 0);
+        assert_eq!(vault.unresolved_reports, 0);
+    }
+
+    #[test]
+    fn enum_discriminants_round_trip_and_match_borsh() {
+        for severity in SeverityTier::ALL {
+            let byte = severity as u8;
+            assert_eq!(SeverityTier::try_from(byte).unwrap(), severity);
+            assert_eq!(severity.try_to_vec().unwrap(), vec![byte]);
+        }
+        for status in ReportStatus::ALL {
+            let byte = status as u8;
+            assert_eq!(ReportStatus::try_from(byte).unwrap(), status);
+            assert_eq!(status.try_to_vec().unwrap(), vec![byte]);
+        }
+
+        assert_eq!(
+            SeverityTier::try_from(SeverityTier::ALL.len() as u8).unwrap_err(),
+            BugBountyError::UnknownDiscriminant.into()
+        );
+        assert_eq!(
+            ReportStatus::try_from(ReportStatus::ALL.len() as u8).unwrap_err(),
+            BugBountyError::UnknownDiscriminant.into()
+        );
+        assert_eq!(SeverityTier::Informational.to_string(), "Informational");
+        assert_eq!(ReportStatus::InArbitration.to_string(), "In arbitration");
+    }
+
+    #[test]
+    fn severity_reward_mapping_reads_the_vault_tiers() {
+        let mut vault = zeroed_vault();
+        vault.critical_reward = 1000;
+        vault.high_reward = 500;
+        vault.medium_reward = 250;
+        vault.low_reward = 100;
+
+        let rewards: Vec<u64> = SeverityTier::ALL.iter().map(|severity| severity.reward_for(&vault)).collect();
+        assert_eq!(rewards, vec![1000, 500, 250, 100, 0]);
+
+        let mut report = zeroed_report();
+        report.severity = SeverityTier::High;
+        assert_eq!(scaled_reward(&vault, &report).unwrap(), 500);
+    }
+
+    #[test]
+    fn auto_approval_needs_both_limits() {
+        let trusted = TrustedResearcher {
+            vault: Pubkey::new_unique(),
+            researcher: Pubkey::new_unique(),
+            auto_approve_max_severity: SeverityTier::Medium,
+            auto_approve_max_amount: 250,
+            granted_by: Pubkey::new_unique(),
+            granted_at: 0,
+            trusted_bump: 255,
+        };
+        let report_of = |severity, payout_amount| {
+            let mut report = zeroed_report();
+            report.severity = severity;
+            report.payout_amount = payout_amount;
+            report
+        };
+
+        assert!(fits_auto_approval(&trusted, &report_of(SeverityTier::Medium, 250)));
+        assert!(fits_auto_approval(&trusted, &report_of(SeverityTier::Low, 100)));
+        assert!(fits_auto_approval(&trusted, &report_of(SeverityTier::Informational, 0)));
+        assert!(!fits_auto_approval(&trusted, &report_of(SeverityTier::High, 100)));
+        assert!(!fits_auto_approval(&trusted, &report_of(SeverityTier::Medium, 251)));
+    }
+
+    #[test]
+    fn approval_records_the_policy_version_in_force() {
+        let mut vault = zeroed_vault();
+        let mut report = zeroed_report();
+        report.submitted_policy_version = vault.policy_version;
+
+        // Tiers change while the report waits in triage
+        vault.policy_version = 1;
+        vault.transition_report(&mut report, ReportStatus::Approved, Pubkey::default()).unwrap();
+        assert_eq!(report.submitted_policy_version, 0);
+        assert_eq!(report.approved_policy_version, Some(1));
+
+        // Other transitions leave the approval's version alone
+        vault.policy_version = 2;
+        vault.transition_report(&mut report, ReportStatus::SeverityDisputed, Pubkey::default()).unwrap();
+        assert_eq!(report.approved_policy_version, Some(1));
+        vault.transition_report(&mut report, ReportStatus::Approved, Pubkey::default()).unwrap();
+        assert_eq!(report.approved_policy_version, Some(2));
+    }
+
+    #[test]
+    fn response_packet_decision_code_has_a_fixed_offset() {
+        let packet = ResponsePacket {
+            report: Pubkey::new_unique(),
+            vault: Pubkey::new_unique(),
+            decision_code: DecisionCode::Duplicate,
+            details_ipfs_hash: [1; 32],
+            decided_by: Pubkey::new_unique(),
+            decided_at: 1_700_000_000,
+            packet_bump: 255,
+        };
+        let mut data = Vec::new();
+        packet.try_serialize(&mut data).unwrap();
+
+        // Byte 72 counts the discriminator, as getProgramAccounts memcmp offsets do
+        assert_eq!(data[72], DecisionCode::Duplicate as u8);
+    }
+
+    #[test]
+    fn replacing_the_vault_token_account_bumps_its_generation() {
+        let mut vault = zeroed_vault();
+        let first = Pubkey::new_unique();
+        vault.replace_vault_token_account(first).unwrap();
+        assert_eq!((vault.vault_token_account, vault.token_account_generation), (first, 1));
+
+        vault.token_account_generation = u16::MAX;
+        assert!(vault.replace_vault_token_account(Pubkey::new_unique()).is_err());
+    }
+
+    #[test]
+    fn money_moving_features_lock_while_reports_depend_on_them() {
+        let mut vault = zeroed_vault();
+        assert_eq!(require_feature(&vault, FEATURE_BOUNTY_BOOSTS).unwrap_err(), BugBountyError::FeatureDisabled.into());
+        vault.feature_flags = FEATURE_BOUNTY_BOOSTS | FEATURE_PRIORITY_STAKE | FEATURE_SEVERITY_DISPUTES;
+        assert!(require_feature(&vault, FEATURE_BOUNTY_BOOSTS).is_ok());
+
+        vault.open_priority_stakes = 1;
+        vault.disputed_reports = 1;
+        for feature in [FEATURE_PRIORITY_STAKE, FEATURE_SEVERITY_DISPUTES] {
+            assert_eq!(check_feature_toggle(&vault, feature).unwrap_err(), BugBountyError::FeatureInUse.into());
+        }
+        assert!(check_feature_toggle(&vault, FEATURE_BOUNTY_BOOSTS | FEATURE_RENT_SUBSIDY).is_ok());
+
+        vault.open_priority_stakes = 0;
+        vault.disputed_reports = 0;
+        assert!(check_feature_toggle(&vault, ALL_FEATURES).is_ok());
+    }
+}
+
+/// Golden Borsh layouts of every account type.
+///
+/// Each account is serialized fully populated, with a distinct value per field, and compared
+/// byte for byte against its fixture in `fixtures/layouts`. A mismatch means a field was added,
+/// removed, retyped or reordered, so accounts already on chain would no longer deserialize.
+/// When the change is intended, bump the layout version and migrate, then regenerate with
+/// `UPDATE_LAYOUT_FIXTURES=1 cargo test layout`.
+#[cfg(test)]
+mod layout_tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+
+    const UPDATE_FIXTURES_ENV: &str = "UPDATE_LAYOUT_FIXTURES";
+    const HEX_BYTES_PER_LINE: usize = 32;
+
+    fn key(n: u8) -> Pubkey {
+        Pubkey::new_from_array([n; 32])
+    }
+
+    // Every field holds a distinct value, so reordered fields of the same type still show up
+    fn populated_bug_bounty_vault() -> BugBountyVault {
+        BugBountyVault {
+            program_team: key(1),
+            governance_authority: key(2),
+            vault_bump: 3,
+            vault_token_account: key(4),
+            critical_reward: 1_000_005,
+            high_reward: 1_000_006,
+            medium_reward: 1_000_007,
+            low_reward: 1_000_008,
+            total_funded: 1_000_009,
+            total_paid_out: 1_000_010,
+            total_reports: 1_000_011,
+            approved_reports: 1_000_012,
+            rejected_reports: 1_000_013,
+            withdrawn_reports: 1_000_014,
+            reward_token_mint: Some(key(16)),
+            vault_active: true,
+            created_at: 1_700_000_018,
+            priority_stake: 1_000_019,
+            max_open_reports_per_researcher: 20,
+            allow_same_authority: true,
+            committed_amount: 1_000_022,
+            withdrawal_delay_seconds: 1_700_000_023,
+            withdrawal_pending: false,
+            pending_withdrawal_amount: 1_000_025,
+            withdrawal_executable_at: 1_700_000_026,
+            total_withdrawn: 1_000_027,
+            insurance_bps: 1028,
+            insurance_reserve: 1_000_029,
+            submissions_open: false,
+            spam_threshold: 100_031,
+            spam_window_seconds: 1_700_000_032,
+            spam_window_start: 1_700_000_033,
+            recent_spam_count: 100_034,
+            embargo_seconds: 1_700_000_035,
+            claim_window_seconds: 1_700_000_036,
+            arbitration_enabled: true,
+            team_mints_reputation: false,
+            project_name: "layout-39".to_string(),
+            reason_templates: [[40; REASON_TEMPLATE_LEN]; MAX_REASON_TEMPLATES],
+            compressed_reputation: true,
+            reputation_on_approval: false,
+            allow_self_approval: true,
+            dual_approval_threshold: 1_000_044,
+            governance_recovery_delay_seconds: 1_700_000_045,
+            pending_governance_authority: Some(key(47)),
+            governance_recovery_executable_at: 1_700_000_048,
+            max_vault_balance: 1_000_049,
+            event_sequence: 1_000_050,
+            extra_reward_mints: [key(51); MAX_EXTRA_REWARD_MINTS],
+            extra_token_accounts: [key(52); MAX_EXTRA_REWARD_MINTS],
+            extra_funded: [1_000_053; MAX_EXTRA_REWARD_MINTS],
+            extra_paid_out: [1_000_054; MAX_EXTRA_REWARD_MINTS],
+            extra_committed: [1_000_055; MAX_EXTRA_REWARD_MINTS],
+            tier_mints: [key(56); 4],
+            previous_mints: [key(57); MAX_PREVIOUS_MINTS],
+            previous_mint_count: 58,
+            vault_authority: key(59),
+            decay_config: Some(DecayConfig { start_multiplier_bps: 1061, end_multiplier_bps: 2061, decay_start: 1_700_000_061, decay_end: 1_800_000_061 }),
+            invoice_required: false,
+            cooldown_after_rejection_seconds: 1_700_000_063,
+            verified_program: Some(key(65)),
+            verified_at: 1_700_000_066,
+            reward_decimals: 67,
+            reward_granularity: 1_000_068,
+            unresolved_reports: 1_000_069,
+            wind_down_phase: WindDownPhase::WindingDown,
+            triage_deadline: 1_700_000_071,
+            paused_tiers: 72,
+            first_responses: 1_000_073,
+            total_first_response_seconds: 1_000_074,
+            pending_reports: 1_000_075,
+            max_pending_reports: 1076,
+            rent_subsidy_budget: 1_000_077,
+            rent_subsidy_outstanding: 1_000_078,
+            token_account_generation: 1079,
+            feature_flags: 1_000_080,
+            open_priority_stakes: 1_000_081,
+            disputed_reports: 1_000_082,
+            subscriber_count: 100_083,
+            policy_version: 100_084,
+            pricing_policy: PricingPolicy::LowerOfBoth,
+            max_payouts_per_hour: 100_086,
+            max_hourly_outflow: 1_000_087,
+            payout_buckets: std::array::from_fn(|i| PayoutBucket {
+                start: 1_700_000_088 + i as i64,
+                payouts: 100_094 + i as u32,
+                outflow: 1_000_100 + i as u64,
+            }),
+            circuit_breaker_tripped: true,
+            platform_vault: true,
+            min_critical_reputation: 100_101,
+            accept_external_attestations: true,
+            governance_bond_amount: 1_000_103,
+            open_arbitrations: 1_000_104,
+            max_payout_fraction_bps: 105,
+            compromise_alert_open: true,
+            dust_policy: DustPolicy::Vault,
+            maintain_summaries: true,
+            clock_override: 1_700_000_109,
+        }
+    }
+
+    fn populated_report_summary() -> ReportSummary {
+        ReportSummary {
+            vault: key(1),
+            researcher: key(2),
+            severity: SeverityTier::High,
+            status: ReportStatus::Approved,
+            payout_amount: 1_000_005,
+            updated_at: 1_700_000_006,
+            report: key(7),
+            summary_bump: 8,
+        }
+    }
+
+    fn populated_vulnerability_report() -> VulnerabilityReport {
+        VulnerabilityReport {
+            vault: key(1),
+            researcher: key(2),
+            severity: SeverityTier::High,
+            status: ReportStatus::SeverityDisputed,
+            report_ipfs_hash: [5; 32],
+            report_bump: 6,
+            submitted_at: 1_700_000_007,
+            approved_at: Some(1_700_000_009),
+            paid_at: Some(1_700_000_011),
+            approver: Some(key(13)),
+            reason_hash: [14; 32],
+            reason_template: Some(16),
+            payout_amount: 1_000_017,
+            on_hold: false,
+            hold_reason_hash: [19; 32],
+            held_at: Some(1_700_000_021),
+            total_held_seconds: 1_700_000_022,
+            donation_bps: 1023,
+            donation_recipient: Some(key(25)),
+            claim_escrow: Some(key(27)),
+            claim_escrow_amount: 1_000_028,
+            priority: true,
+            priority_stake_amount: 1_000_030,
+            stake_escrow: Some(key(32)),
+            is_spam: true,
+            counted_open: false,
+            confidential: true,
+            embargo_until: Some(1_700_000_037),
+            public_ipfs_hash: Some([39; 32]),
+            disclosed_at: Some(1_700_000_041),
+            escalated: false,
+            team_co_approved: true,
+            sponsor: Some(key(45)),
+            remediation_ref: [46; 32],
+            remediated_at: Some(1_700_000_048),
+            version: 49,
+            rejected_at: Some(1_700_000_051),
+            rent_payer: key(52),
+            payout_mint: key(53),
+            mint_generation: 54,
+            reward_multiplier_bps: 1055,
+            invoice_hash: [56; 32],
+            linked_report: Some(key(58)),
+            poc_tx_signature: [59; 64],
+            poc_cluster: Some(PocCluster::Testnet),
+            acknowledged_at: Some(1_700_000_063),
+            disputed_severity: Some(SeverityTier::High),
+            dispute_evidence_hash: [66; 32],
+            approved_severity: Some(SeverityTier::High),
+            dispute_resolved_at: Some(1_700_000_070),
+            decision_code: Some(DecisionCode::Duplicate),
+            submitted_policy_version: 100_072,
+            approved_policy_version: Some(100_073),
+            auto_approved: true,
+            priced_by: Some(PricingPolicy::HigherOfBoth),
+            payout_recipient: Some(key(76)),
+            bond_slashed: true,
+            approver_role_charged: true,
+        }
+    }
+
+    fn populated_reputation_nft() -> ReputationNFT {
+        ReputationNFT {
+            researcher: key(1),
+            vault: key(2),
+            report: key(3),
+            severity: SeverityTier::High,
+            project_name: "layout-5".to_string(),
+            minted_at: 1_700_000_006,
+            disclosed_report_hash: Some([8; 32]),
+            revoked: true,
+            revoked_reason_hash: [10; 32],
+            leaf_index: Some(1_000_012),
+            minted_at_approval: true,
+            rent_payer: key(14),
+        }
+    }
+
+    fn populated_vault_snapshot() -> VaultSnapshot {
+        VaultSnapshot {
+            vault: key(1),
+            period_id: 100_002,
+            taken_at: 1_700_000_003,
+            snapshot_bump: 4,
+            total_funded: 1_000_005,
+            total_paid_out: 1_000_006,
+            total_withdrawn: 1_000_007,
+            committed_amount: 1_000_008,
+            insurance_reserve: 1_000_009,
+            total_reports: 1_000_010,
+            approved_reports: 1_000_011,
+            rejected_reports: 1_000_012,
+            withdrawn_reports: 1_000_013,
+            token_balance: 1_000_014,
+        }
+    }
+
+    fn populated_hall_of_fame() -> HallOfFame {
+        HallOfFame {
+            vault: key(1),
+            hall_bump: 2,
+            entry_count: 3,
+            entries: [HallOfFameEntry { researcher: key(4), report: key(104), note_hash: [4; 32] }; HALL_OF_FAME_CAPACITY],
+        }
+    }
+
+    fn populated_submission_counter() -> SubmissionCounter {
+        SubmissionCounter {
+            vault: key(1),
+            researcher: key(2),
+            open_reports: 1003,
+            counter_bump: 4,
+            nonce: 1_000_005,
+            last_rejected_at: 1_700_000_006,
+        }
+    }
+
+    fn populated_platform_config() -> PlatformConfig {
+        PlatformConfig {
+            admin: key(1),
+            config_bump: 2,
+            swap_programs: [key(3); MAX_SWAP_PROGRAMS],
+            swap_program_count: 4,
+            reputation_tree: key(5),
+            registry_page_count: 100_006,
+            treasury: key(7),
+            handle_fee_lamports: 1_000_008,
+        }
+    }
+
+    fn populated_researcher_profile() -> ResearcherProfile {
+        ResearcherProfile {
+            researcher: key(1),
+            profile_bump: 2,
+            handle: Some(key(4)),
+            paid_reports: 100_005,
+            paid_critical_reports: 100_006,
+            total_earned: 1_000_007,
+            external_source: 8,
+            external_score: 100_009,
+        }
+    }
+
+    fn populated_bounty_boost() -> BountyBoost {
+        BountyBoost {
+            vault: key(1),
+            sponsor: key(2),
+            boost_id: 1_000_003,
+            mint: key(4),
+            escrow: key(5),
+            severity_mask: 6,
+            max_per_report: 1_000_007,
+            expires_at: 1_700_000_008,
+            total_deposited: 1_000_009,
+            total_paid: 1_000_010,
+            boost_bump: 11,
+        }
+    }
+
+    fn populated_response_packet() -> ResponsePacket {
+        ResponsePacket {
+            report: key(1),
+            vault: key(2),
+            decision_code: DecisionCode::Duplicate,
+            details_ipfs_hash: [4; 32],
+            decided_by: key(5),
+            decided_at: 1_700_000_006,
+            packet_bump: 7,
+        }
+    }
+
+    fn populated_milestone_badge() -> MilestoneBadge {
+        MilestoneBadge {
+            researcher: key(1),
+            milestone_id: 2,
+            badge_bump: 3,
+            claimed_at: 1_700_000_004,
+            rent_payer: key(5),
+        }
+    }
+
+    fn populated_handle() -> Handle {
+        Handle {
+            researcher: key(1),
+            handle_bump: 2,
+            registered_at: 1_700_000_003,
+            name: "layout-4".to_string(),
+        }
+    }
+
+    fn populated_policy_version() -> PolicyVersion {
+        PolicyVersion {
+            vault: key(1),
+            version: 100_002,
+            rewards: [1_000_003, 1_000_004, 1_000_005, 1_000_006],
+            previous_rewards: [1_000_007, 1_000_008, 1_000_009, 1_000_010],
+            tier_mints: [key(11), key(12), key(13), key(14)],
+            updated_by: key(15),
+            updated_at: 1_700_000_016,
+            policy_bump: 17,
+        }
+    }
+
+    fn populated_trusted_researcher() -> TrustedResearcher {
+        TrustedResearcher {
+            vault: key(1),
+            researcher: key(2),
+            auto_approve_max_severity: SeverityTier::Medium,
+            auto_approve_max_amount: 1_000_004,
+            granted_by: key(5),
+            granted_at: 1_700_000_006,
+            trusted_bump: 7,
+        }
+    }
+
+    fn populated_firm_member() -> FirmMember {
+        FirmMember {
+            recipient: key(1),
+            member: key(2),
+            registered_at: 1_700_000_003,
+            rent_payer: key(4),
+            firm_member_bump: 5,
+        }
+    }
+
+    fn populated_compromise_alert() -> CompromiseAlert {
+        CompromiseAlert {
+            vault: key(1),
+            reporter: key(2),
+            evidence_hash: [3; 32],
+            deposit_lamports: 1_000_004,
+            raised_at: 1_700_000_005,
+            alert_bump: 6,
+        }
+    }
+
+    fn populated_acknowledgment() -> Acknowledgment {
+        Acknowledgment {
+            vault: key(1),
+            report: key(2),
+            researcher: key(3),
+            message_hash: [4; 32],
+            display_name_override: Some([5; 32]),
+            posted_at: 1_700_000_006,
+            hidden: true,
+            acknowledgment_bump: 8,
+        }
+    }
+
+    fn populated_governance_bond() -> GovernanceBond {
+        GovernanceBond {
+            vault: key(1),
+            governance_authority: key(2),
+            escrow: key(3),
+            posted_at: 1_700_000_004,
+            withdrawable_at: 1_700_000_005,
+            bond_bump: 6,
+        }
+    }
+
+    fn populated_external_attestation() -> ExternalAttestation {
+        ExternalAttestation {
+            researcher: key(1),
+            source: 2,
+            score: 100_003,
+            expires_at: 1_700_000_004,
+            issued_by: key(5),
+            issued_at: 1_700_000_006,
+            attestation_bump: 7,
+        }
+    }
+
+    fn populated_subscription() -> Subscription {
+        Subscription {
+            vault: key(1),
+            researcher: key(2),
+            subscribed_at: 1_700_000_003,
+            subscription_bump: 4,
+        }
+    }
+
+    fn populated_role() -> Role {
+        Role {
+            vault: key(1),
+            member: key(2),
+            permissions: 1003,
+            role_bump: 4,
+            max_total_payout: 1_000_005,
+            max_single_payout: 1_000_006,
+            spent: 1_000_007,
+        }
+    }
+
+    fn fixture_path(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("fixtures/layouts")
+            .join(format!("{}.hex", name))
+    }
+
+    /// Account bytes as stored on chain, discriminator included
+    fn account_bytes<T: AccountSerialize>(account: &T) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    /// Lowercase hex, one line per 32 bytes so fixture diffs point at the moved field
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes
+            .chunks(HEX_BYTES_PER_LINE)
+            .map(|line| line.iter().map(|b| format!("{:02x}", b)).collect::<String>() + "\n")
+            .collect()
+    }
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        let digits: Vec<u8> = hex.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+        digits
+            .chunks(2)
+            .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).unwrap(), 16).unwrap())
+            .collect()
+    }
+
+    /// Compare against the golden fixture, or rewrite it when UPDATE_LAYOUT_FIXTURES is set
+    fn check_layout(name: &str, actual: &[u8]) {
+        let path = fixture_path(name);
+        if std::env::var_os(UPDATE_FIXTURES_ENV).is_some() {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, to_hex(actual)).unwrap();
+            return;
+        }
+        
+        let expected = match fs::read_to_string(&path) {
+            Ok(hex) => from_hex(&hex),
+            Err(_) => panic!(
+                "No layout fixture for {} at {}; generate it with {}=1 cargo test layout",
+                name,
+                path.display(),
+                UPDATE_FIXTURES_ENV
+            ),
+        };
+        if expected != actual {
+            let first_difference = expected
+                .iter()
+                .zip(actual)
+                .position(|(a, b)| a != b)
+                .unwrap_or(expected.len().min(actual.len()));
+            panic!(
+                "{} layout changed: first difference at byte {}, {} bytes expected, {} serialized. \
+                 Accounts already on chain would deserialize wrongly. Append new fields rather than \
+                 reordering; if the change is intended, bump the layout version, migrate old \
+                 accounts, and regenerate with {}=1 cargo test layout",
+                name,
+                first_difference,
+                expected.len(),
+                actual.len(),
+                UPDATE_FIXTURES_ENV
+            );
+        }
+    }
+
+    #[test]
+    fn hex_fixtures_round_trip() {
+        let bytes: Vec<u8> = (0..=u8::MAX).collect();
+        assert_eq!(from_hex(&to_hex(&bytes)), bytes);
+    }
+
+    #[test]
+    fn bug_bounty_vault_layout_matches_fixture() {
+        check_layout("bug_bounty_vault", &account_bytes(&populated_bug_bounty_vault()));
+    }
+
+    #[test]
+    fn vulnerability_report_layout_matches_fixture() {
+        // Keyed by REPORT_VERSION: a bumped layout starts a new fixture next to the old one
+        check_layout(&format!("vulnerability_report_v{}", REPORT_VERSION), &account_bytes(&populated_vulnerability_report()));
+    }
+
+    #[test]
+    fn report_filter_offsets_match_serialization() {
+        let report = populated_vulnerability_report();
+        let data = account_bytes(&report);
+
+        assert_eq!(&data[..8], VulnerabilityReport::DISCRIMINATOR);
+        assert_eq!(&data[REPORT_VAULT_OFFSET..REPORT_VAULT_OFFSET + 32], report.vault.as_ref());
+        assert_eq!(&data[REPORT_RESEARCHER_OFFSET..REPORT_RESEARCHER_OFFSET + 32], report.researcher.as_ref());
+        assert_eq!(data[REPORT_SEVERITY_OFFSET], report.severity as u8);
+        assert_eq!(data[REPORT_STATUS_OFFSET], report.status as u8);
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn report_filters_select_only_matching_reports() {
+        use crate::client::*;
+
+        let report = populated_vulnerability_report();
+        let data = account_bytes(&report);
+        let matches = |filters: Vec<MemcmpFilter>| filters.iter().all(|filter| filter.matches(&data));
+
+        assert!(matches(report_filters_for_vault(&report.vault)));
+        assert!(matches(report_filters_for_researcher(&report.researcher)));
+        assert!(matches(report_filters_for_vault_status(&report.vault, report.status)));
+        assert!(matches(report_filters_for_vault_severity(&report.vault, report.severity)));
+
+        assert!(!matches(report_filters_for_vault(&report.researcher)));
+        assert!(!matches(report_filters_for_researcher(&report.vault)));
+        assert!(!matches(report_filters_for_vault_status(&report.vault, ReportStatus::Paid)));
+        assert!(!matches(report_filters_for_vault_severity(&report.vault, SeverityTier::Critical)));
+        assert!(!report_discriminator_filter().matches(&account_bytes(&populated_bug_bounty_vault())));
+
+        let summary = account_bytes(&populated_report_summary());
+        assert!(report_summary_filters_for_vault(&key(1)).iter().all(|filter| filter.matches(&summary)));
+        assert!(!report_summary_filters_for_vault(&report.vault).iter().all(|filter| filter.matches(&data)));
+    }
+
+    #[test]
+    fn report_summary_layout_matches_fixture() {
+        check_layout("report_summary", &account_bytes(&populated_report_summary()));
+    }
+
+    #[test]
+    fn report_summary_fits_its_space_at_report_offsets() {
+        let summary = populated_report_summary();
+        let data = account_bytes(&summary);
+
+        assert!(data.len() <= REPORT_SUMMARY_SPACE);
+        assert_eq!(&data[REPORT_VAULT_OFFSET..REPORT_VAULT_OFFSET + 32], summary.vault.as_ref());
+        assert_eq!(&data[REPORT_RESEARCHER_OFFSET..REPORT_RESEARCHER_OFFSET + 32], summary.researcher.as_ref());
+        assert_eq!(data[REPORT_SEVERITY_OFFSET], summary.severity as u8);
+        assert_eq!(data[REPORT_STATUS_OFFSET], summary.status as u8);
+    }
+
+    #[test]
+    fn reputation_nft_layout_matches_fixture() {
+        check_layout("reputation_nft", &account_bytes(&populated_reputation_nft()));
+    }
+
+    #[test]
+    fn vault_snapshot_layout_matches_fixture() {
+        check_layout("vault_snapshot", &account_bytes(&populated_vault_snapshot()));
+    }
+
+    #[test]
+    fn hall_of_fame_layout_matches_fixture() {
+        check_layout("hall_of_fame", &account_bytes(&populated_hall_of_fame()));
+    }
+
+    #[test]
+    fn submission_counter_layout_matches_fixture() {
+        check_layout("submission_counter", &account_bytes(&populated_submission_counter()));
+    }
+
+    #[test]
+    fn platform_config_layout_matches_fixture() {
+        check_layout("platform_config", &account_bytes(&populated_platform_config()));
+    }
+
+    #[test]
+    fn researcher_profile_layout_matches_fixture() {
+        check_layout("researcher_profile", &account_bytes(&populated_researcher_profile()));
+    }
+
+    #[test]
+    fn bounty_boost_layout_matches_fixture() {
+        check_layout("bounty_boost", &account_bytes(&populated_bounty_boost()));
+    }
+
+    #[test]
+    fn response_packet_layout_matches_fixture() {
+        check_layout("response_packet", &account_bytes(&populated_response_packet()));
+    }
+
+    #[test]
+    fn milestone_badge_layout_matches_fixture() {
+        check_layout("milestone_badge", &account_bytes(&populated_milestone_badge()));
+    }
+
+    #[test]
+    fn handle_layout_matches_fixture() {
+        check_layout("handle", &account_bytes(&populated_handle()));
+    }
+
+    #[test]
+    fn policy_version_layout_matches_fixture() {
+        check_layout("policy_version", &account_bytes(&populated_policy_version()));
+    }
+
+    #[test]
+    fn trusted_researcher_layout_matches_fixture() {
+        check_layout("trusted_researcher", &account_bytes(&populated_trusted_researcher()));
+    }
+
+    #[test]
+    fn firm_member_layout_matches_fixture() {
+        check_layout("firm_member", &account_bytes(&populated_firm_member()));
+    }
+
+    #[test]
+    fn compromise_alert_layout_matches_fixture() {
+        check_layout("compromise_alert", &account_bytes(&populated_compromise_alert()));
+    }
+
+    #[test]
+    fn acknowledgment_layout_matches_fixture() {
+        check_layout("acknowledgment", &account_bytes(&populated_acknowledgment()));
+    }
+
+    #[test]
+    fn governance_bond_layout_matches_fixture() {
+        check_layout("governance_bond", &account_bytes(&populated_governance_bond()));
+    }
+
+    #[test]
+    fn external_attestation_layout_matches_fixture() {
+        check_layout("external_attestation", &account_bytes(&populated_external_attestation()));
+    }
+
+    #[test]
+    fn subscription_layout_matches_fixture() {
+        check_layout("subscription", &account_bytes(&populated_subscription()));
+    }
+
+    #[test]
+    fn role_layout_matches_fixture() {
+        check_layout("role", &account_bytes(&populated_role()));
+    }
 }
@@ -0,0 +1,201 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { BugBountyPlatform } from "../target/types/bug_bounty_platform";
+import { BountyCpiTester } from "../target/types/bounty_cpi_tester";
+import {
+  TOKEN_PROGRAM_ID,
+  createAccount,
+  createAssociatedTokenAccount,
+  createMint,
+  getAccount,
+  mintTo,
+} from "@solana/spl-token";
+import { expect } from "chai";
+
+describe("bounty-cpi-tester", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const bounty = anchor.workspace.bugBountyPlatform as Program<BugBountyPlatform>;
+  const tester = anchor.workspace.bountyCpiTester as Program<BountyCpiTester>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const connection = provider.connection;
+
+  let team: anchor.web3.Keypair;
+  let governanceAuthority: anchor.web3.Keypair;
+  let vault: anchor.web3.PublicKey;
+  let mint: anchor.web3.PublicKey;
+  let vaultTokenAccount: anchor.web3.PublicKey;
+  let agentTokenAccount: anchor.web3.PublicKey;
+  let report: anchor.web3.PublicKey;
+
+  // Lamport-only PDA of the tester program acting as researcher and funder
+  const [agent] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("agent")],
+    tester.programId
+  );
+
+  before(async () => {
+    team = anchor.web3.Keypair.generate();
+    governanceAuthority = anchor.web3.Keypair.generate();
+    const sig = await connection.requestAirdrop(team.publicKey, 10 * anchor.web3.LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(sig);
+    const agentSig = await connection.requestAirdrop(agent, anchor.web3.LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(agentSig);
+
+    [vault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), team.publicKey.toBuffer()],
+      bounty.programId
+    );
+
+    mint = await createMint(connection, team, team.publicKey, null, 6);
+    vaultTokenAccount = await createAccount(connection, team, mint, vault, anchor.web3.Keypair.generate());
+    agentTokenAccount = await createAccount(connection, team, mint, agent, anchor.web3.Keypair.generate());
+    await mintTo(connection, team, mint, agentTokenAccount, team, 5000);
+
+    await bounty.methods
+      .createBountyVault(
+        new anchor.BN(1000),
+        new anchor.BN(500),
+        new anchor.BN(250),
+        new anchor.BN(100),
+        new anchor.BN(0),
+        mint,
+        false,
+        false,
+        new anchor.BN(0),
+        { atSubmission: {} }
+      )
+      .accounts({
+        programTeam: team.publicKey,
+        governanceAuthority: governanceAuthority.publicKey,
+        vault,
+        vaultTokenAccount,
+        rewardMint: mint,
+        vaultRegistry: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([team])
+      .rpc();
+  });
+
+  it("Should submit a report with a PDA researcher", async () => {
+    const vaultAccount = await bounty.account.bugBountyVault.fetch(vault);
+    [report] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("report"),
+        vault.toBuffer(),
+        agent.toBuffer(),
+        vaultAccount.totalReports.toBuffer("le", 8),
+      ],
+      bounty.programId
+    );
+
+    await tester.methods
+      .submitViaCpi({ high: {} }, Array.from(Buffer.alloc(32, "cpi")))
+      .accounts({
+        agent,
+        vault,
+        report,
+        bountyProgram: bounty.programId,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const reportAccount = await bounty.account.vulnerabilityReport.fetch(report);
+    expect(reportAccount.researcher.toString()).to.equal(agent.toString());
+    expect(reportAccount.status).to.deep.equal({ pending: {} });
+  });
+
+  it("Should fund the vault from a PDA funder", async () => {
+    await tester.methods
+      .fundViaCpi(new anchor.BN(2000))
+      .accounts({
+        agent,
+        vault,
+        agentTokenAccount,
+        vaultTokenAccount,
+        bountyProgram: bounty.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    const vaultAccount = await bounty.account.bugBountyVault.fetch(vault);
+    expect(vaultAccount.totalFunded.toNumber()).to.equal(2000);
+    const tokenAccount = await getAccount(connection, vaultTokenAccount);
+    expect(Number(tokenAccount.amount)).to.equal(2000);
+  });
+
+  it("Should pay a PDA researcher into its off-curve associated token account", async () => {
+    await bounty.methods
+      .approveReport(Array(32).fill(0), null)
+      .accounts({ governanceAuthority: governanceAuthority.publicKey, vault, report, role: null, reportSummary: null })
+      .signers([governanceAuthority])
+      .rpc();
+
+    // The agent has no keypair, so someone else creates its ATA; the agent only signs the payout
+    const agentAta = await createAssociatedTokenAccount(connection, team, mint, agent, undefined, undefined, undefined, true);
+    const agentLamports = await connection.getBalance(agent);
+    await tester.methods
+      .payoutViaCpi()
+      .accounts({
+        agent,
+        vault,
+        report,
+        vaultTokenAccount,
+        agentTokenAccount: agentAta,
+        vaultAuthority: vault,
+        bountyProgram: bounty.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    const reportAccount = await bounty.account.vulnerabilityReport.fetch(report);
+    expect(reportAccount.status).to.deep.equal({ paid: {} });
+    expect(Number((await getAccount(connection, agentAta)).amount)).to.equal(500);
+    expect(await connection.getBalance(agent)).to.equal(agentLamports);
+  });
+
+  it("Should gate an admin action on an active, verified and funded vault", async () => {
+    const guarded = (minBalance: number) =>
+      tester.methods
+        .guardedAdminAction(new anchor.BN(minBalance))
+        .accounts({ vault, bountyProgram: bounty.programId })
+        .rpc();
+
+    try {
+      await guarded(0);
+      expect.fail("Should have thrown error for an unverified vault");
+    } catch (error) {
+      expect(error.message).to.include("VaultNotVerifiedForProgram");
+    }
+
+    // Hand the tester program's upgrade authority to the vault's team so it can earn the badge
+    const loader = new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+    const [programData] = anchor.web3.PublicKey.findProgramAddressSync([tester.programId.toBuffer()], loader);
+    const setAuthority = new anchor.web3.TransactionInstruction({
+      programId: loader,
+      keys: [
+        { pubkey: programData, isSigner: false, isWritable: true },
+        { pubkey: provider.wallet.publicKey, isSigner: true, isWritable: false },
+        { pubkey: team.publicKey, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.from([4, 0, 0, 0]), // UpgradeableLoaderInstruction::SetAuthority
+    });
+    await provider.sendAndConfirm(new anchor.web3.Transaction().add(setAuthority));
+    await bounty.methods
+      .verifyVaultOwnership(tester.programId)
+      .accounts({ programTeam: team.publicKey, vault, programData })
+      .signers([team])
+      .rpc();
+
+    // 2000 funded, 500 paid out above
+    await guarded(1500);
+    try {
+      await guarded(1501);
+      expect.fail("Should have thrown error for an underfunded vault");
+    } catch (error) {
+      expect(error.message).to.include("InsufficientVaultFunds");
+    }
+  });
+});
@@ -0,0 +1,315 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { BugBountyPlatform } from "../target/types/bug_bounty_platform";
+import {
+  TOKEN_PROGRAM_ID,
+  createAccount,
+  createAssociatedTokenAccount,
+  createMint,
+  mintTo,
+} from "@solana/spl-token";
+import { expect } from "chai";
+
+// Compute units each instruction may consume on its heaviest tested path.
+// A failure here means an instruction got more expensive: optimize it, or bump
+// the budget in the same change so reviewers see the cost.
+const CU_BUDGETS = {
+  createBountyVault: 40_000,
+  fundVault: 30_000,
+  submitReport: 50_000,
+  setDonation: 15_000,
+  approveReport: 40_000,
+  rejectReport: 40_000,
+  executePayout: 80_000,
+  // Donation split, sponsor boost, submission counter and researcher profile stats
+  executePayoutFullPath: 150_000,
+};
+
+// The runtime's default per-instruction limit; every budget must fit under it
+const DEFAULT_COMPUTE_UNIT_LIMIT = 200_000;
+
+describe("compute-budget", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.bugBountyPlatform as Program<BugBountyPlatform>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const connection = provider.connection;
+
+  let team: anchor.web3.Keypair;
+  let governanceAuthority: anchor.web3.Keypair;
+  let researcher: anchor.web3.Keypair;
+  let sponsor: anchor.web3.Keypair;
+  let vault: anchor.web3.PublicKey;
+  let mint: anchor.web3.PublicKey;
+  let vaultTokenAccount: anchor.web3.PublicKey;
+  let researcherTokenAccount: anchor.web3.PublicKey;
+
+  const measured: Record<string, number> = {};
+  const noReason = () => Array(32).fill(0);
+
+  // Reads the consumed units back from the confirmed transaction and checks them against the budget
+  async function expectWithinBudget(name: keyof typeof CU_BUDGETS, signature: string) {
+    const tx = await connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const consumed = tx.meta.computeUnitsConsumed;
+    measured[name] = consumed;
+    expect(
+      consumed,
+      `${name} consumed ${consumed} CU, over its budget of ${CU_BUDGETS[name]}. ` +
+        `Optimize the instruction, or consciously bump CU_BUDGETS.${name} in tests/compute-budget.ts.`
+    ).to.be.at.most(CU_BUDGETS[name]);
+  }
+
+  async function airdrop(to: anchor.web3.PublicKey, sol: number) {
+    const sig = await connection.requestAirdrop(to, sol * anchor.web3.LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(sig);
+  }
+
+  async function nextReportAddress(by: anchor.web3.PublicKey) {
+    const vaultAccount = await program.account.bugBountyVault.fetch(vault);
+    const [report] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("report"), vault.toBuffer(), by.toBuffer(), vaultAccount.totalReports.toBuffer("le", 8)],
+      program.programId
+    );
+    return report;
+  }
+
+  async function submit(severity: object, tag: string, submissionCounter: anchor.web3.PublicKey | null = null) {
+    const report = await nextReportAddress(researcher.publicKey);
+    const signature = await program.methods
+      .submitReport(severity as any, Buffer.alloc(32, tag), false, null)
+      .accounts({
+        researcher: researcher.publicKey,
+        vault,
+        report,
+        submissionCounter,
+        linkedReport: null,
+        trustedResearcher: null,
+        researcherProfile: null,
+        externalAttestation: null,
+        reportSummary: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([researcher])
+      .rpc({ commitment: "confirmed" });
+    return { report, signature };
+  }
+
+  const approve = (report: anchor.web3.PublicKey) =>
+    program.methods
+      .approveReport(noReason(), null)
+      .accounts({ governanceAuthority: governanceAuthority.publicKey, vault, report, role: null, reportSummary: null })
+      .signers([governanceAuthority])
+      .rpc({ commitment: "confirmed" });
+
+  before(async () => {
+    team = anchor.web3.Keypair.generate();
+    governanceAuthority = anchor.web3.Keypair.generate();
+    researcher = anchor.web3.Keypair.generate();
+    sponsor = anchor.web3.Keypair.generate();
+    await airdrop(team.publicKey, 10);
+    await airdrop(governanceAuthority.publicKey, 2);
+    await airdrop(researcher.publicKey, 2);
+    await airdrop(sponsor.publicKey, 2);
+
+    [vault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), team.publicKey.toBuffer()],
+      program.programId
+    );
+    mint = await createMint(connection, team, team.publicKey, null, 6);
+    vaultTokenAccount = await createAccount(connection, team, mint, vault, anchor.web3.Keypair.generate());
+    researcherTokenAccount = await createAssociatedTokenAccount(connection, researcher, mint, researcher.publicKey);
+  });
+
+  it("Keeps every budget under the default per-instruction limit", () => {
+    for (const [name, budget] of Object.entries(CU_BUDGETS)) {
+      expect(budget, `${name} budget exceeds the default compute unit limit`).to.be.at.most(DEFAULT_COMPUTE_UNIT_LIMIT);
+    }
+  });
+
+  it("createBountyVault", async () => {
+    const signature = await program.methods
+      .createBountyVault(
+        new anchor.BN(1000),
+        new anchor.BN(500),
+        new anchor.BN(250),
+        new anchor.BN(100),
+        new anchor.BN(0),
+        mint,
+        false,
+        false,
+        new anchor.BN(0),
+        { atSubmission: {} }
+      )
+      .accounts({
+        programTeam: team.publicKey,
+        governanceAuthority: governanceAuthority.publicKey,
+        vault,
+        vaultTokenAccount,
+        rewardMint: mint,
+        vaultRegistry: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([team])
+      .rpc({ commitment: "confirmed" });
+    await expectWithinBudget("createBountyVault", signature);
+  });
+
+  it("fundVault", async () => {
+    const funderTokenAccount = await createAccount(connection, team, mint, team.publicKey, anchor.web3.Keypair.generate());
+    await mintTo(connection, team, mint, funderTokenAccount, team, 10_000);
+    const signature = await program.methods
+      .fundVault(new anchor.BN(10_000))
+      .accounts({
+        funder: team.publicKey,
+        vault,
+        funderTokenAccount,
+        vaultTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([team])
+      .rpc({ commitment: "confirmed" });
+    await expectWithinBudget("fundVault", signature);
+  });
+
+  it("submitReport, approveReport and executePayout on the plain path", async () => {
+    const { report, signature } = await submit({ low: {} }, "plain");
+    await expectWithinBudget("submitReport", signature);
+    await expectWithinBudget("approveReport", await approve(report));
+
+    const payout = await program.methods
+      .executePayout()
+      .accounts({
+        researcher: researcher.publicKey,
+        vault,
+        report,
+        vaultTokenAccount,
+        researcherTokenAccount,
+        vaultAuthority: vault,
+        donationTokenAccount: null,
+        claimEscrow: null,
+        submissionCounter: null,
+        researcherProfile: null,
+        firmMember: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        reportSummary: null,
+      })
+      .signers([researcher])
+      .rpc({ commitment: "confirmed" });
+    await expectWithinBudget("executePayout", payout);
+  });
+
+  it("rejectReport", async () => {
+    const { report } = await submit({ medium: {} }, "rejected");
+    const signature = await program.methods
+      .rejectReport(Array(32).fill(1), null)
+      .accounts({ governanceAuthority: governanceAuthority.publicKey, vault, report, role: null, submissionCounter: null, reportSummary: null })
+      .signers([governanceAuthority])
+      .rpc({ commitment: "confirmed" });
+    await expectWithinBudget("rejectReport", signature);
+  });
+
+  it("executePayout with a donation split, a boost and profile stats", async () => {
+    // Boosts are behind a feature flag; enable it so the boost leg runs
+    await program.methods
+      .setFeatureFlags(new anchor.BN(1 << 0), true)
+      .accounts({ programTeam: team.publicKey, vault, role: null, auditLog: null })
+      .signers([team])
+      .rpc();
+
+    const boostMint = await createMint(connection, sponsor, sponsor.publicKey, null, 6);
+    const sponsorTokenAccount = await createAccount(connection, sponsor, boostMint, sponsor.publicKey, anchor.web3.Keypair.generate());
+    await mintTo(connection, sponsor, boostMint, sponsorTokenAccount, sponsor, 1000);
+    const [boost] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("bounty_boost"), vault.toBuffer(), sponsor.publicKey.toBuffer(), new anchor.BN(1).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [boostEscrow] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("boost_escrow"), boost.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .createBountyBoost(new anchor.BN(1), new anchor.BN(1000), 0b00011, new anchor.BN(0), new anchor.BN(Math.floor(Date.now() / 1000) + 3600))
+      .accounts({
+        sponsor: sponsor.publicKey,
+        vault,
+        boost,
+        mint: boostMint,
+        boostEscrow,
+        sponsorTokenAccount,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([sponsor])
+      .rpc();
+    const researcherBoostAccount = await createAssociatedTokenAccount(connection, researcher, boostMint, researcher.publicKey);
+
+    const [submissionCounter] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("submission_counter"), vault.toBuffer(), researcher.publicKey.toBuffer()],
+      program.programId
+    );
+    const [researcherProfile] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("researcher_profile"), researcher.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .ensureResearcherAccounts()
+      .accounts({
+        researcher: researcher.publicKey,
+        vault,
+        submissionCounter,
+        researcherProfile,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([researcher])
+      .rpc();
+
+    const { report } = await submit({ critical: {} }, "full-path", submissionCounter);
+    const donationTokenAccount = await createAccount(connection, team, mint, sponsor.publicKey, anchor.web3.Keypair.generate());
+    const donation = await program.methods
+      .setDonation(2500, sponsor.publicKey)
+      .accounts({ researcher: researcher.publicKey, report })
+      .signers([researcher])
+      .rpc({ commitment: "confirmed" });
+    await expectWithinBudget("setDonation", donation);
+    await approve(report);
+
+    const signature = await program.methods
+      .executePayout()
+      .accounts({
+        researcher: researcher.publicKey,
+        vault,
+        report,
+        vaultTokenAccount,
+        researcherTokenAccount,
+        vaultAuthority: vault,
+        donationTokenAccount,
+        claimEscrow: null,
+        submissionCounter,
+        researcherProfile,
+        firmMember: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        reportSummary: null,
+      })
+      .remainingAccounts([
+        { pubkey: boost, isWritable: true, isSigner: false },
+        { pubkey: boostEscrow, isWritable: true, isSigner: false },
+        { pubkey: researcherBoostAccount, isWritable: true, isSigner: false },
+      ])
+      .signers([researcher])
+      .rpc({ commitment: "confirmed" });
+    await expectWithinBudget("executePayoutFullPath", signature);
+
+    const reportAccount = await program.account.vulnerabilityReport.fetch(report);
+    expect(reportAccount.status).to.deep.equal({ paid: {} });
+  });
+
+  after(() => {
+    for (const [name, consumed] of Object.entries(measured)) {
+      console.log(`      ${name}: ${consumed} / ${CU_BUDGETS[name]} CU`);
+    }
+  });
+});
@@ -0,0 +1,148 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { BugBountyPlatform } from "../target/types/bug_bounty_platform";
+import { TOKEN_PROGRAM_ID, createAccount, createMint, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import * as fs from "fs";
+import * as path from "path";
+
+// Only meaningful against a build with the test-clock feature:
+//   anchor build -- --features test-clock
+// Other builds don't contain set_test_clock, so the suite skips itself.
+describe("test-clock", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.bugBountyPlatform as Program<BugBountyPlatform>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const connection = provider.connection;
+
+  // Matches TEST_CLOCK_AUTHORITY in the program
+  const testAuthority = anchor.web3.Keypair.fromSecretKey(
+    Uint8Array.from(JSON.parse(fs.readFileSync(path.join(__dirname, "keys", "test-clock-authority.json"), "utf8")))
+  );
+
+  let team: anchor.web3.Keypair;
+  let vault: anchor.web3.PublicKey;
+  let vaultTokenAccount: anchor.web3.PublicKey;
+  let teamTokenAccount: anchor.web3.PublicKey;
+
+  async function airdrop(to: anchor.web3.PublicKey, sol: number) {
+    const sig = await connection.requestAirdrop(to, sol * anchor.web3.LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(sig);
+  }
+
+  // Typed loosely: the generated client only has the method in test-clock builds
+  const setTestClock = (clockOverride: number, signer: anchor.web3.Keypair = testAuthority) =>
+    (program.methods as any)
+      .setTestClock(new anchor.BN(clockOverride))
+      .accounts({ testAuthority: signer.publicKey, vault })
+      .signers([signer])
+      .rpc();
+
+  const executeWithdrawal = () =>
+    program.methods
+      .executeWithdrawal()
+      .accounts({
+        programTeam: team.publicKey,
+        vault,
+        role: null,
+        auditLog: null,
+        vaultTokenAccount,
+        programTeamTokenAccount: teamTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([team])
+      .rpc();
+
+  before(async function () {
+    if (!program.idl.instructions.some((ix) => ix.name === "setTestClock")) {
+      this.skip();
+    }
+
+    team = anchor.web3.Keypair.generate();
+    const governanceAuthority = anchor.web3.Keypair.generate();
+    await airdrop(team.publicKey, 10);
+    await airdrop(testAuthority.publicKey, 1);
+
+    [vault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), team.publicKey.toBuffer()],
+      program.programId
+    );
+    const mint = await createMint(connection, team, team.publicKey, null, 6);
+    vaultTokenAccount = await createAccount(connection, team, mint, vault, anchor.web3.Keypair.generate());
+    teamTokenAccount = await createAccount(connection, team, mint, team.publicKey, anchor.web3.Keypair.generate());
+
+    await program.methods
+      .createBountyVault(
+        new anchor.BN(1000),
+        new anchor.BN(500),
+        new anchor.BN(250),
+        new anchor.BN(100),
+        new anchor.BN(0),
+        mint,
+        false,
+        false,
+        new anchor.BN(0),
+        { atSubmission: {} }
+      )
+      .accounts({
+        programTeam: team.publicKey,
+        governanceAuthority: governanceAuthority.publicKey,
+        vault,
+        vaultTokenAccount,
+        rewardMint: mint,
+        vaultRegistry: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([team])
+      .rpc();
+
+    await mintTo(connection, team, mint, vaultTokenAccount, team, 1000);
+    await program.methods.syncVaultBalance().accounts({ vault, vaultTokenAccount }).rpc();
+  });
+
+  it("Should only let the test authority set the clock", async () => {
+    try {
+      await setTestClock(1_800_000_000, team);
+      expect.fail("Should have thrown error for a non-test signer");
+    } catch (error) {
+      expect(error.message).to.include("NotTestClockAuthority");
+    }
+  });
+
+  it("Should run a withdrawal timelock on the pinned clock", async () => {
+    const start = 1_800_000_000;
+    await setTestClock(start);
+
+    await program.methods
+      .setWithdrawalDelay(new anchor.BN(7 * 24 * 60 * 60))
+      .accounts({ programTeam: team.publicKey, vault, auditLog: null })
+      .signers([team])
+      .rpc();
+    await program.methods
+      .requestWithdrawal(new anchor.BN(300))
+      .accounts({ programTeam: team.publicKey, vault, role: null, auditLog: null })
+      .signers([team])
+      .rpc();
+
+    const vaultAccount = await program.account.bugBountyVault.fetch(vault);
+    expect(vaultAccount.withdrawalExecutableAt.toNumber()).to.equal(start + 7 * 24 * 60 * 60);
+
+    // One second short of the delay
+    await setTestClock(start + 7 * 24 * 60 * 60 - 1);
+    try {
+      await executeWithdrawal();
+      expect.fail("Should have thrown error for active timelock");
+    } catch (error) {
+      expect(error.message).to.include("WithdrawalTimelockActive");
+    }
+
+    await setTestClock(start + 7 * 24 * 60 * 60);
+    await executeWithdrawal();
+
+    // Back on the cluster clock
+    await setTestClock(0);
+    expect((await program.account.bugBountyVault.fetch(vault)).clockOverride.toNumber()).to.equal(0);
+  });
+});